@@ -0,0 +1,67 @@
+//! Client setup shared by the examples.
+//!
+//! With `YTM_FIXTURE_DIR` set, the client answers requests from the recorded
+//! responses in that directory and authenticates with its `headers.json`, so
+//! an example runs offline against the bundled fixtures:
+//!
+//! ```text
+//! YTM_FIXTURE_DIR=examples/fixtures cargo run --example list_playlists
+//! ```
+//!
+//! In that mode, failing to load the headers or to parse the arguments is an
+//! error, so scripted runs against the fixtures exit non-zero.
+
+use std::env;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use ytmusicapi::{BrowserAuth, Error, YTMusicClient};
+
+/// Build a client authenticated with `headers.json`, or print how to create
+/// the file and return `None` if it cannot be loaded. Loading the fixture
+/// headers fails with the load error instead.
+pub fn authenticated_client() -> ytmusicapi::Result<Option<YTMusicClient>> {
+    let fixture_dir = env::var_os("YTM_FIXTURE_DIR").map(PathBuf::from);
+    let headers = match &fixture_dir {
+        Some(dir) => dir.join("headers.json"),
+        None => PathBuf::from("headers.json"),
+    };
+
+    let auth = match BrowserAuth::from_file(&headers) {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("Error loading {}: {}", headers.display(), e);
+            if fixture_dir.is_some() {
+                return Err(e);
+            }
+            eprintln!("\nTo create headers.json:");
+            eprintln!("1. Open YouTube Music in your browser and log in");
+            eprintln!("2. Open Developer Tools (F12) -> Network tab");
+            eprintln!("3. Find any request to music.youtube.com");
+            eprintln!("4. Copy the request headers and save as JSON");
+            eprintln!("\nExample headers.json:");
+            eprintln!("{{\"cookie\": \"...\", \"x-goog-authuser\": \"0\"}}");
+            eprintln!("\nOr set YTM_FIXTURE_DIR=examples/fixtures to run offline.");
+            return Ok(None);
+        }
+    };
+
+    let mut builder = YTMusicClient::builder().with_browser_auth(auth);
+    if let Some(dir) = fixture_dir {
+        builder = builder.with_fixture_dir(dir);
+    }
+    builder.build().map(Some)
+}
+
+/// Report an invalid command line with `usage`. Returns an error in fixture
+/// mode and `Ok` otherwise.
+#[allow(dead_code)] // Not every example takes arguments.
+pub fn usage_error(message: impl Display, usage: fn()) -> ytmusicapi::Result<()> {
+    eprintln!("{message}");
+    usage();
+    if env::var_os("YTM_FIXTURE_DIR").is_some() {
+        Err(Error::InvalidInput(message.to_string()))
+    } else {
+        Ok(())
+    }
+}
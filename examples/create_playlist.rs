@@ -3,9 +3,11 @@
 //! Export browser headers to `headers.json`, then run:
 //! cargo run --example create_playlist -- --title "My Playlist" [--description "Created via ytmusicapi-rs"] [--privacy private]
 
+mod common;
+
 use std::env;
 
-use ytmusicapi::{BrowserAuth, Privacy, YTMusicClient};
+use ytmusicapi::Privacy;
 
 #[derive(Default)]
 struct Args {
@@ -81,29 +83,13 @@ fn print_usage() {
 
 #[tokio::main]
 async fn main() -> ytmusicapi::Result<()> {
-    // Load auth from headers.json file
-    let auth = match BrowserAuth::from_file("headers.json") {
-        Ok(a) => a,
-        Err(e) => {
-            eprintln!("Error loading headers.json: {}", e);
-            eprintln!("\nTo create headers.json:");
-            eprintln!("1. Open YouTube Music in your browser and log in");
-            eprintln!("2. Open Developer Tools (F12) -> Network tab");
-            eprintln!("3. Find any request to music.youtube.com");
-            eprintln!("4. Copy the request headers and save as JSON");
-            eprintln!("\nExample headers.json:");
-            eprintln!("{{\"cookie\": \"...\", \"x-goog-authuser\": \"0\"}}");
-            return Ok(());
-        }
+    let Some(client) = common::authenticated_client()? else {
+        return Ok(());
     };
 
     let args = match parse_args() {
         Ok(parsed) => parsed,
-        Err(err) => {
-            eprintln!("{}", err);
-            print_usage();
-            return Ok(());
-        }
+        Err(err) => return common::usage_error(err, print_usage),
     };
 
     if args.show_help {
@@ -113,27 +99,22 @@ async fn main() -> ytmusicapi::Result<()> {
 
     let title = match args.title {
         Some(value) => value,
-        None => {
-            eprintln!("Missing --title.");
-            print_usage();
-            return Ok(());
-        }
+        None => return common::usage_error("Missing --title.", print_usage),
     };
 
     let privacy = match args.privacy {
         Some(value) => match parse_privacy(&value) {
             Some(parsed) => parsed,
             None => {
-                eprintln!("Unknown privacy value: {}", value);
-                print_usage();
-                return Ok(());
+                return common::usage_error(
+                    format!("Unknown privacy value: {}", value),
+                    print_usage,
+                );
             }
         },
         None => Privacy::Private,
     };
 
-    let client = YTMusicClient::builder().with_browser_auth(auth).build()?;
-
     println!("Creating playlist '{}'...", title);
     let response = client
         .create_playlist(&title, args.description.as_deref(), privacy)
@@ -3,10 +3,9 @@
 //! Export browser headers to `headers.json`, then run:
 //! cargo run --example delete_playlist -- --playlist-id PLAYLIST_ID
 
-use std::env;
+mod common;
 
-use ytmusicapi::BrowserAuth;
-use ytmusicapi::YTMusicClient;
+use std::env;
 
 #[derive(Default)]
 struct Args {
@@ -53,29 +52,13 @@ fn print_usage() {
 
 #[tokio::main]
 async fn main() -> ytmusicapi::Result<()> {
-    // Load auth from headers.json file
-    let auth = match BrowserAuth::from_file("headers.json") {
-        Ok(a) => a,
-        Err(e) => {
-            eprintln!("Error loading headers.json: {}", e);
-            eprintln!("\nTo create headers.json:");
-            eprintln!("1. Open YouTube Music in your browser and log in");
-            eprintln!("2. Open Developer Tools (F12) -> Network tab");
-            eprintln!("3. Find any request to music.youtube.com");
-            eprintln!("4. Copy the request headers and save as JSON");
-            eprintln!("\nExample headers.json:");
-            eprintln!("{{\"cookie\": \"...\", \"x-goog-authuser\": \"0\"}}");
-            return Ok(());
-        }
+    let Some(client) = common::authenticated_client()? else {
+        return Ok(());
     };
 
     let args = match parse_args() {
         Ok(parsed) => parsed,
-        Err(err) => {
-            eprintln!("{}", err);
-            print_usage();
-            return Ok(());
-        }
+        Err(err) => return common::usage_error(err, print_usage),
     };
 
     if args.show_help {
@@ -85,15 +68,9 @@ async fn main() -> ytmusicapi::Result<()> {
 
     let playlist_id = match args.playlist_id {
         Some(value) => value,
-        None => {
-            eprintln!("Missing --playlist-id.");
-            print_usage();
-            return Ok(());
-        }
+        None => return common::usage_error("Missing --playlist-id.", print_usage),
     };
 
-    let client = YTMusicClient::builder().with_browser_auth(auth).build()?;
-
     println!("Deleting playlist '{}'...", playlist_id);
     client.delete_playlist(&playlist_id).await?;
     println!("Deleted.");
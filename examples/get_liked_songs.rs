@@ -5,9 +5,9 @@
 //! 2. Run:
 //!    cargo run --example get_liked_songs -- [--limit 50]
 
-use std::env;
+mod common;
 
-use ytmusicapi::{BrowserAuth, YTMusicClient};
+use std::env;
 
 #[derive(Default)]
 struct Args {
@@ -49,29 +49,13 @@ fn print_usage() {
 
 #[tokio::main]
 async fn main() -> ytmusicapi::Result<()> {
-    // Load auth from headers.json file
-    let auth = match BrowserAuth::from_file("headers.json") {
-        Ok(a) => a,
-        Err(e) => {
-            eprintln!("Error loading headers.json: {}", e);
-            eprintln!("\nTo create headers.json:");
-            eprintln!("1. Open YouTube Music in your browser and log in");
-            eprintln!("2. Open Developer Tools (F12) -> Network tab");
-            eprintln!("3. Find any request to music.youtube.com");
-            eprintln!("4. Copy the request headers and save as JSON");
-            eprintln!("\nExample headers.json:");
-            eprintln!("{{\"cookie\": \"...\", \"x-goog-authuser\": \"0\"}}");
-            return Ok(());
-        }
+    let Some(client) = common::authenticated_client()? else {
+        return Ok(());
     };
 
     let args = match parse_args() {
         Ok(parsed) => parsed,
-        Err(err) => {
-            eprintln!("{}", err);
-            print_usage();
-            return Ok(());
-        }
+        Err(err) => return common::usage_error(err, print_usage),
     };
 
     if args.show_help {
@@ -79,8 +63,6 @@ async fn main() -> ytmusicapi::Result<()> {
         return Ok(());
     }
 
-    let client = YTMusicClient::builder().with_browser_auth(auth).build()?;
-
     println!("Fetching liked songs...");
     let playlist = client.get_liked_songs(args.limit).await?;
 
@@ -4,28 +4,14 @@
 //! 1. Export your browser headers to `headers.json` (see README)
 //! 2. Run: cargo run --example list_playlists
 
-use ytmusicapi::{BrowserAuth, YTMusicClient};
+mod common;
 
 #[tokio::main]
 async fn main() -> ytmusicapi::Result<()> {
-    // Load auth from headers.json file
-    let auth = match BrowserAuth::from_file("headers.json") {
-        Ok(a) => a,
-        Err(e) => {
-            eprintln!("Error loading headers.json: {}", e);
-            eprintln!("\nTo create headers.json:");
-            eprintln!("1. Open YouTube Music in your browser and log in");
-            eprintln!("2. Open Developer Tools (F12) → Network tab");
-            eprintln!("3. Find any request to music.youtube.com");
-            eprintln!("4. Copy the request headers and save as JSON");
-            eprintln!("\nExample headers.json:");
-            eprintln!(r#"{{"cookie": "...", "x-goog-authuser": "0"}}"#);
-            return Ok(());
-        }
+    let Some(client) = common::authenticated_client()? else {
+        return Ok(());
     };
 
-    let client = YTMusicClient::builder().with_browser_auth(auth).build()?;
-
     println!("Fetching your playlists...\n");
 
     let playlists = client.get_library_playlists(None).await?;
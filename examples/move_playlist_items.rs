@@ -3,10 +3,12 @@
 //! Export browser headers to `headers.json`, then run:
 //! cargo run --example move_playlist_items -- --source PLAYLIST_ID --dest PLAYLIST_ID --video-ids VIDEO_ID_1,VIDEO_ID_2 [--allow-duplicates]
 
+mod common;
+
 use std::collections::HashSet;
 use std::env;
 
-use ytmusicapi::{BrowserAuth, PlaylistTrack, YTMusicClient};
+use ytmusicapi::{DuplicateHandling, PlaylistTrack};
 
 #[derive(Default)]
 struct Args {
@@ -104,29 +106,13 @@ fn collect_items(tracks: &[PlaylistTrack], video_ids: &HashSet<String>) -> Vec<P
 
 #[tokio::main]
 async fn main() -> ytmusicapi::Result<()> {
-    // Load auth from headers.json file
-    let auth = match BrowserAuth::from_file("headers.json") {
-        Ok(a) => a,
-        Err(e) => {
-            eprintln!("Error loading headers.json: {}", e);
-            eprintln!("\nTo create headers.json:");
-            eprintln!("1. Open YouTube Music in your browser and log in");
-            eprintln!("2. Open Developer Tools (F12) -> Network tab");
-            eprintln!("3. Find any request to music.youtube.com");
-            eprintln!("4. Copy the request headers and save as JSON");
-            eprintln!("\nExample headers.json:");
-            eprintln!("{{\"cookie\": \"...\", \"x-goog-authuser\": \"0\"}}");
-            return Ok(());
-        }
+    let Some(client) = common::authenticated_client()? else {
+        return Ok(());
     };
 
     let args = match parse_args() {
         Ok(parsed) => parsed,
-        Err(err) => {
-            eprintln!("{}", err);
-            print_usage();
-            return Ok(());
-        }
+        Err(err) => return common::usage_error(err, print_usage),
     };
 
     if args.show_help {
@@ -136,44 +122,33 @@ async fn main() -> ytmusicapi::Result<()> {
 
     let source_playlist_id = match args.source_playlist_id {
         Some(value) => value,
-        None => {
-            eprintln!("Missing --source PLAYLIST_ID.");
-            print_usage();
-            return Ok(());
-        }
+        None => return common::usage_error("Missing --source PLAYLIST_ID.", print_usage),
     };
 
     let dest_playlist_id = match args.dest_playlist_id {
         Some(value) => value,
-        None => {
-            eprintln!("Missing --dest PLAYLIST_ID.");
-            print_usage();
-            return Ok(());
-        }
+        None => return common::usage_error("Missing --dest PLAYLIST_ID.", print_usage),
     };
 
     let raw_video_ids = match args.video_ids {
         Some(value) => value,
         None => {
-            eprintln!("Missing --video-ids VIDEO_ID_1,VIDEO_ID_2.");
-            print_usage();
-            return Ok(());
+            return common::usage_error("Missing --video-ids VIDEO_ID_1,VIDEO_ID_2.", print_usage);
         }
     };
 
     if source_playlist_id == dest_playlist_id {
-        eprintln!("Source and destination playlist IDs must be different.");
-        return Ok(());
+        return common::usage_error(
+            "Source and destination playlist IDs must be different.",
+            print_usage,
+        );
     }
 
     let video_ids = parse_video_ids(&raw_video_ids);
     if video_ids.is_empty() {
-        eprintln!("Provide at least one video ID.");
-        return Ok(());
+        return common::usage_error("Provide at least one video ID.", print_usage);
     }
 
-    let client = YTMusicClient::builder().with_browser_auth(auth).build()?;
-
     println!("Fetching source playlist to locate items...");
     let playlist = client.get_playlist(&source_playlist_id, None).await?;
 
@@ -185,18 +160,19 @@ async fn main() -> ytmusicapi::Result<()> {
         return Ok(());
     }
 
+    let duplicates = if args.allow_duplicates {
+        DuplicateHandling::Allow
+    } else {
+        DuplicateHandling::Skip
+    };
+
     println!(
         "Moving {} items to destination playlist {}...",
         items.len(),
         dest_playlist_id
     );
     client
-        .move_playlist_items(
-            &source_playlist_id,
-            &dest_playlist_id,
-            &items,
-            args.allow_duplicates,
-        )
+        .move_playlist_items(&source_playlist_id, &dest_playlist_id, &items, duplicates)
         .await?;
     println!("Moved.");
 
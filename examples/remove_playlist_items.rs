@@ -3,10 +3,12 @@
 //! Export browser headers to `headers.json`, then run:
 //! cargo run --example remove_playlist_items -- --playlist-id PLAYLIST_ID --video-ids VIDEO_ID_1,VIDEO_ID_2
 
+mod common;
+
 use std::collections::HashSet;
 use std::env;
 
-use ytmusicapi::{BrowserAuth, PlaylistTrack, YTMusicClient};
+use ytmusicapi::PlaylistTrack;
 
 #[derive(Default)]
 struct Args {
@@ -85,29 +87,13 @@ fn collect_items(tracks: &[PlaylistTrack], video_ids: &HashSet<String>) -> Vec<P
 
 #[tokio::main]
 async fn main() -> ytmusicapi::Result<()> {
-    // Load auth from headers.json file
-    let auth = match BrowserAuth::from_file("headers.json") {
-        Ok(a) => a,
-        Err(e) => {
-            eprintln!("Error loading headers.json: {}", e);
-            eprintln!("\nTo create headers.json:");
-            eprintln!("1. Open YouTube Music in your browser and log in");
-            eprintln!("2. Open Developer Tools (F12) -> Network tab");
-            eprintln!("3. Find any request to music.youtube.com");
-            eprintln!("4. Copy the request headers and save as JSON");
-            eprintln!("\nExample headers.json:");
-            eprintln!("{{\"cookie\": \"...\", \"x-goog-authuser\": \"0\"}}");
-            return Ok(());
-        }
+    let Some(client) = common::authenticated_client()? else {
+        return Ok(());
     };
 
     let args = match parse_args() {
         Ok(parsed) => parsed,
-        Err(err) => {
-            eprintln!("{}", err);
-            print_usage();
-            return Ok(());
-        }
+        Err(err) => return common::usage_error(err, print_usage),
     };
 
     if args.show_help {
@@ -117,30 +103,21 @@ async fn main() -> ytmusicapi::Result<()> {
 
     let playlist_id = match args.playlist_id {
         Some(value) => value,
-        None => {
-            eprintln!("Missing --playlist-id.");
-            print_usage();
-            return Ok(());
-        }
+        None => return common::usage_error("Missing --playlist-id.", print_usage),
     };
 
     let raw_video_ids = match args.video_ids {
         Some(value) => value,
         None => {
-            eprintln!("Missing --video-ids VIDEO_ID_1,VIDEO_ID_2.");
-            print_usage();
-            return Ok(());
+            return common::usage_error("Missing --video-ids VIDEO_ID_1,VIDEO_ID_2.", print_usage);
         }
     };
 
     let video_ids = parse_video_ids(&raw_video_ids);
     if video_ids.is_empty() {
-        eprintln!("Provide at least one video ID.");
-        return Ok(());
+        return common::usage_error("Provide at least one video ID.", print_usage);
     }
 
-    let client = YTMusicClient::builder().with_browser_auth(auth).build()?;
-
     println!("Fetching playlist to locate items...");
     let playlist = client.get_playlist(&playlist_id, None).await?;
 
@@ -154,11 +131,7 @@ async fn main() -> ytmusicapi::Result<()> {
 
     println!("Removing {} items...", items.len());
     let response = client.remove_playlist_items(&playlist_id, &items).await?;
-    let status = response
-        .get("status")
-        .and_then(|value| value.as_str())
-        .unwrap_or("UNKNOWN");
-    println!("Remove status: {}", status);
+    println!("Remove status: {:?}", response.status);
 
     Ok(())
 }
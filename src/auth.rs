@@ -71,6 +71,15 @@ impl BrowserAuth {
         Self::from_json(&content)
     }
 
+    /// Create `BrowserAuth` from a headers JSON file without blocking the runtime.
+    ///
+    /// Async counterpart of [`from_file`](Self::from_file), reading the file with
+    /// `tokio::fs`.
+    pub async fn from_file_async<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = tokio::fs::read_to_string(path).await?;
+        Self::from_json(&content)
+    }
+
     /// Create `BrowserAuth` from a JSON string.
     ///
     /// Accepts `cookie`, `x-goog-authuser`, and `origin` (case-insensitive).
@@ -122,13 +131,19 @@ impl BrowserAuth {
     /// Generate the `SAPISIDHASH` authorization header.
     ///
     /// This is a time-based hash that YouTube uses for browser authentication.
+    /// It embeds the current UTC time from the local clock; Google rejects it
+    /// with a 401 if that clock is a few minutes off.
     pub fn get_authorization(&self) -> Result<String> {
-        let sapisid = self.sapisid()?;
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+            .map_or(0, |elapsed| elapsed.as_secs());
+        self.get_authorization_at(timestamp)
+    }
 
+    /// Generate the `SAPISIDHASH` authorization header for `timestamp`, in
+    /// seconds since the Unix epoch, such as a server-corrected time.
+    pub fn get_authorization_at(&self, timestamp: u64) -> Result<String> {
+        let sapisid = self.sapisid()?;
         let auth_string = format!("{} {} {}", timestamp, sapisid, self.origin);
 
         let mut hasher = Sha1::new();
@@ -154,6 +169,19 @@ mod tests {
         assert_eq!(auth.sapisid().unwrap(), "abc123def");
     }
 
+    #[test]
+    fn test_authorization_embeds_timestamp() {
+        let auth = BrowserAuth {
+            cookie: "__Secure-3PAPISID=abc".to_string(),
+            x_goog_authuser: "0".to_string(),
+            origin: "https://music.youtube.com".to_string(),
+        };
+        let header = auth.get_authorization_at(1_700_000_000).unwrap();
+        assert!(header.starts_with("SAPISIDHASH 1700000000_"));
+        assert_eq!(header, auth.get_authorization_at(1_700_000_000).unwrap());
+        assert_ne!(header, auth.get_authorization_at(1_700_000_001).unwrap());
+    }
+
     #[test]
     fn test_from_json() {
         let json = r#"{"cookie": "test=1; __Secure-3PAPISID=xyz", "x-goog-authuser": "0"}"#;
@@ -181,6 +209,25 @@ mod tests {
         assert_eq!(auth.x_goog_authuser, "0");
     }
 
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_from_file_async() {
+        let path = std::env::temp_dir().join(format!(
+            "ytmusicapi-auth-{}-{}.json",
+            std::process::id(),
+            line!()
+        ));
+        std::fs::write(&path, r#"{"cookie": "__Secure-3PAPISID=xyz"}"#).unwrap();
+
+        let auth = BrowserAuth::from_file_async(&path).await;
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(auth.unwrap().sapisid().unwrap(), "xyz");
+
+        assert!(matches!(
+            BrowserAuth::from_file_async(&path).await,
+            Err(Error::Io(_))
+        ));
+    }
+
     #[test]
     fn test_from_json_requires_cookie() {
         assert!(matches!(
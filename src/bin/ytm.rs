@@ -0,0 +1,313 @@
+//! `ytm`: a small command-line front end for the library.
+//!
+//! Build with the `cli` feature:
+//! cargo run --features cli --bin ytm -- --help
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use ytmusicapi::{
+    BrowserAuth, DuplicateHandling, EditStatus, Error, Privacy, SearchFilter, YTMusicClient,
+};
+
+#[derive(Parser)]
+#[command(
+    name = "ytm",
+    version,
+    about = "YouTube Music playlist and library tool"
+)]
+struct Cli {
+    /// Path to the exported browser headers.
+    #[arg(long, global = true, default_value = "headers.json")]
+    headers: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List library playlists.
+    List {
+        /// Maximum number of playlists to list.
+        #[arg(long)]
+        limit: Option<u32>,
+    },
+    /// Show a playlist and its tracks.
+    Get {
+        playlist_id: String,
+        /// Maximum number of tracks to fetch.
+        #[arg(long)]
+        limit: Option<u32>,
+    },
+    /// Create a playlist.
+    Create {
+        title: String,
+        #[arg(long)]
+        description: Option<String>,
+        #[arg(long, value_enum, default_value_t = PrivacyArg::Private)]
+        privacy: PrivacyArg,
+    },
+    /// Delete a playlist.
+    Delete { playlist_id: String },
+    /// Add videos to a playlist.
+    Add {
+        playlist_id: String,
+        #[arg(required = true)]
+        video_ids: Vec<String>,
+        #[arg(long)]
+        allow_duplicates: bool,
+    },
+    /// Remove videos from a playlist.
+    Remove {
+        playlist_id: String,
+        #[arg(required = true)]
+        video_ids: Vec<String>,
+    },
+    /// Move videos from one playlist to another.
+    Move {
+        source: String,
+        dest: String,
+        #[arg(required = true)]
+        video_ids: Vec<String>,
+        #[arg(long)]
+        allow_duplicates: bool,
+    },
+    /// Like a song.
+    Like { video_id: String },
+    /// Remove the rating from a song.
+    Unlike { video_id: String },
+    /// Show song metadata (no authentication needed).
+    Song { video_id: String },
+    /// Search YouTube Music (no authentication needed).
+    Search {
+        query: String,
+        #[arg(long, value_enum)]
+        filter: Option<FilterArg>,
+        #[arg(long)]
+        limit: Option<u32>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum PrivacyArg {
+    Public,
+    Private,
+    Unlisted,
+}
+
+impl From<PrivacyArg> for Privacy {
+    fn from(value: PrivacyArg) -> Self {
+        match value {
+            PrivacyArg::Public => Privacy::Public,
+            PrivacyArg::Private => Privacy::Private,
+            PrivacyArg::Unlisted => Privacy::Unlisted,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FilterArg {
+    Songs,
+    Videos,
+    Albums,
+    Artists,
+    CommunityPlaylists,
+    FeaturedPlaylists,
+    Podcasts,
+    Episodes,
+}
+
+impl From<FilterArg> for SearchFilter {
+    fn from(value: FilterArg) -> Self {
+        match value {
+            FilterArg::Songs => SearchFilter::Songs,
+            FilterArg::Videos => SearchFilter::Videos,
+            FilterArg::Albums => SearchFilter::Albums,
+            FilterArg::Artists => SearchFilter::Artists,
+            FilterArg::CommunityPlaylists => SearchFilter::CommunityPlaylists,
+            FilterArg::FeaturedPlaylists => SearchFilter::FeaturedPlaylists,
+            FilterArg::Podcasts => SearchFilter::Podcasts,
+            FilterArg::Episodes => SearchFilter::Episodes,
+        }
+    }
+}
+
+fn duplicates(allow: bool) -> DuplicateHandling {
+    if allow {
+        DuplicateHandling::Allow
+    } else {
+        DuplicateHandling::Skip
+    }
+}
+
+/// Fail unless the server reported the edit as applied. Rejected edits still
+/// come with a 200 response, so they are not errors in the library.
+fn ensure_applied(action: &str, status: &EditStatus) -> ytmusicapi::Result<()> {
+    let reported = match status {
+        EditStatus::Succeeded => return Ok(()),
+        EditStatus::Failed => "STATUS_FAILED",
+        EditStatus::Other(status) if status.is_empty() => "no status",
+        EditStatus::Other(status) => status,
+    };
+    Err(Error::NotApplied(format!(
+        "{action}: the server reported {reported}"
+    )))
+}
+
+fn client(cli: &Cli, authenticated: bool) -> ytmusicapi::Result<YTMusicClient> {
+    let builder = YTMusicClient::builder();
+    if !authenticated {
+        return builder.build();
+    }
+    let auth = BrowserAuth::from_file(&cli.headers)?;
+    builder.with_browser_auth(auth).build()
+}
+
+async fn run(cli: Cli) -> ytmusicapi::Result<()> {
+    let authenticated = !matches!(cli.command, Command::Song { .. } | Command::Search { .. });
+    let client = client(&cli, authenticated)?;
+
+    match cli.command {
+        Command::List { limit } => {
+            for playlist in client.get_library_playlists(limit).await? {
+                let count = playlist
+                    .count
+                    .map(|c| format!(" ({c} tracks)"))
+                    .unwrap_or_default();
+                println!("{}\t{}{}", playlist.playlist_id, playlist.title, count);
+            }
+        }
+        Command::Get { playlist_id, limit } => {
+            let playlist = client.get_playlist(&playlist_id, limit).await?;
+            println!("{} ({})", playlist.title, playlist.id);
+            for track in &playlist.tracks {
+                let artists: Vec<&str> = track.artists.iter().map(|a| a.name.as_str()).collect();
+                println!(
+                    "{}\t[{}] {} - {}",
+                    track.video_id.as_deref().unwrap_or("-"),
+                    track.duration.as_deref().unwrap_or("--:--"),
+                    artists.join(", "),
+                    track.title.as_deref().unwrap_or("Unknown")
+                );
+            }
+        }
+        Command::Create {
+            title,
+            description,
+            privacy,
+        } => {
+            let created = client
+                .create_playlist(&title, description.as_deref(), privacy.into())
+                .await?;
+            println!("{}", created.playlist_id);
+        }
+        Command::Delete { playlist_id } => {
+            client.delete_playlist(&playlist_id).await?;
+            println!("Deleted {playlist_id}");
+        }
+        Command::Add {
+            playlist_id,
+            video_ids,
+            allow_duplicates,
+        } => {
+            let result = client
+                .add_playlist_items(&playlist_id, &video_ids, duplicates(allow_duplicates))
+                .await?;
+            ensure_applied("Add", &result.status)?;
+            println!("Added {} items", video_ids.len() - result.skipped.len());
+            if !result.skipped.is_empty() {
+                println!(
+                    "Skipped (already in playlist): {}",
+                    result.skipped.join(", ")
+                );
+            }
+        }
+        Command::Remove {
+            playlist_id,
+            video_ids,
+        } => {
+            let wanted: HashSet<String> = video_ids.into_iter().collect();
+            let playlist = client.get_playlist(&playlist_id, None).await?;
+            let items: Vec<_> = playlist
+                .tracks
+                .into_iter()
+                .filter(|t| t.video_id.as_ref().is_some_and(|id| wanted.contains(id)))
+                .collect();
+            let response = client.remove_playlist_items(&playlist_id, &items).await?;
+            ensure_applied("Remove", &response.status)?;
+            println!("Removed {} items", items.len());
+        }
+        Command::Move {
+            source,
+            dest,
+            video_ids,
+            allow_duplicates,
+        } => {
+            let wanted: HashSet<String> = video_ids.into_iter().collect();
+            let playlist = client.get_playlist(&source, None).await?;
+            let items: Vec<_> = playlist
+                .tracks
+                .into_iter()
+                .filter(|t| t.video_id.as_ref().is_some_and(|id| wanted.contains(id)))
+                .collect();
+            let result = client
+                .move_playlist_items(&source, &dest, &items, duplicates(allow_duplicates))
+                .await?;
+            ensure_applied("Adding to the destination", &result.added.status)?;
+            ensure_applied("Removing from the source", &result.removed.status)?;
+            println!("Moved {} items", items.len());
+        }
+        Command::Like { video_id } => {
+            client.like_song(&video_id).await?;
+            println!("Liked {video_id}");
+        }
+        Command::Unlike { video_id } => {
+            client.unlike_song(&video_id).await?;
+            println!("Removed rating from {video_id}");
+        }
+        Command::Song { video_id } => {
+            let song = client.get_song(&video_id).await?;
+            let details = &song.video_details;
+            println!("{} by {}", details.title, details.author);
+            println!("Length: {}s", details.length_seconds);
+            println!("Views: {}", details.view_count);
+        }
+        Command::Search {
+            query,
+            filter,
+            limit,
+        } => {
+            let results = match filter {
+                Some(filter) => client.search_filtered(&query, filter.into(), limit).await?,
+                None => client.search(&query, limit).await?,
+            };
+            for result in results {
+                let id = result.video_id.or(result.browse_id).unwrap_or_default();
+                let artists: Vec<_> = result.artists.iter().map(|a| a.name.as_str()).collect();
+                println!(
+                    "{:?}\t{}\t{}\t{}",
+                    result.result_type,
+                    id,
+                    result.title,
+                    artists.join(", ")
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match run(Cli::parse()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -1,19 +1,87 @@
 //! YouTube Music API client.
 
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::io::Write;
+use std::ops::{ControlFlow, Range};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, RwLock, RwLockReadGuard};
+use std::time::{Duration, SystemTime};
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+use reqwest::header::{ACCEPT_LANGUAGE, CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
 use serde_json::{Value, json};
 
 use crate::auth::BrowserAuth;
-use crate::context::{YTM_BASE_API, YTM_PARAMS, YTM_PARAMS_KEY, create_context, default_headers};
-use crate::error::{Error, Result};
-use crate::nav::nav;
+use crate::clock::{server_offset, significant_skew, skew_hint, unix_now};
+use crate::context::{
+    ClientProfile, YTM_BASE_API, YTM_PARAMS, YTM_PARAMS_KEY, YTM_UPLOAD_URL, accept_language,
+    client_version, create_context, default_headers,
+};
+use crate::continuation::continuation_rows;
+use crate::error::{ConfigError, Error, ResponseMeta, Result};
+use crate::export::{ExportFormat, TrackWriter};
+use crate::matching::find_matches;
+use crate::nav::nav_str;
+use crate::pagination::{PageShape, for_each_page, paginate, paginate_with_warnings};
+use crate::parsers::account::{parse_account_info, parse_capabilities};
+use crate::parsers::album::parse_album_page;
+use crate::parsers::artist::{
+    parse_artist_page, parse_artist_releases, parse_release_items, parse_subscription_result,
+};
+use crate::parsers::explore::{
+    parse_charts, parse_mood_categories, parse_mood_playlist_rows, parse_mood_playlists,
+};
+use crate::parsers::history::parse_history;
+#[cfg(feature = "chrono")]
+use crate::parsers::history::{parse_history_buckets, parse_history_sections, truncate_history_at};
+use crate::parsers::home::{parse_home, parse_home_sections};
+use crate::parsers::library::{
+    parse_library_album_items, parse_library_albums, parse_library_artist_rows,
+    parse_library_artists, parse_library_song_rows, parse_library_songs,
+    parse_library_track_artist_rows, parse_library_track_artists,
+};
+use crate::parsers::locale::{ENGLISH, vocabulary};
+use crate::parsers::lyrics::{parse_lyrics, parse_timed_lyrics};
+use crate::parsers::playlist::{
+    parse_library_playlist_item_rows, parse_liked_music_summary, parse_playlist_suggestions,
+    parse_related_playlists, playlist_sections_continuation,
+};
+use crate::parsers::song::{parse_rate_response, parse_song};
+use crate::parsers::upload::{
+    UPLOAD_ALBUM_PREFIX, parse_upload_album_items, parse_upload_albums, parse_upload_artist_rows,
+    parse_upload_artists, parse_upload_song_rows, parse_upload_songs,
+};
+use crate::parsers::user::{parse_user_page, parse_user_playlists};
+use crate::parsers::watch::{
+    parse_queue_like_statuses, parse_song_related, parse_watch_playlist, parse_watch_rows,
+    watch_continuation_rows,
+};
 use crate::parsers::{
-    get_continuation_token, parse_library_playlists, parse_playlist_response, parse_playlist_tracks,
+    parse_library_playlists_continuation, parse_library_playlists_page, parse_playlist_response,
+    parse_playlist_track_rows, parse_search_results, parse_search_rows, parse_search_suggestions,
 };
+use crate::rate_limit::RateLimiter;
+use crate::transport::FixtureTransport;
 use crate::types::{
-    CreatePlaylistResponse, LikeStatus, MovePlaylistItemsResult, Playlist, PlaylistSummary,
-    PlaylistTrack, Privacy, Song,
+    AccountInfo, AddPlaylistItemsResult, AlbumPage, ArtistPage, ArtistRelease, AuthState,
+    Capabilities, Charts, CreatePlaylistOptions, CreatePlaylistResponse, DuplicateHandling,
+    EditPlaylistOptions, EditPlaylistResponse, EditStatus, GetPlaylistOptions, HistoryTrack,
+    HomeSection, LibraryAlbum, LibraryArtist, LibraryOrder, LibraryPlaylistsPage,
+    LibraryTrackMatch, LikeStatus, Lyrics, MoodSection, MovePlaylistItemsResult, MutationKind,
+    MutationOutcome, MutationRecord, Playlist, PlaylistSummary, PlaylistTrack, Privacy,
+    PruneCandidate, PruneOptions, PrunePlan, PruneReason, PruneReport, RateSongResponse,
+    RelatedSection, SearchFilter, SearchResult, SearchResultType, SearchResults, SearchScope,
+    SearchSuggestion, Song, SubscriptionResult, TimedLyrics, TrackFilter, TrackLike, UndoReport,
+    UndoStep, UndoToken, UploadAlbum, UploadArtist, UploadStatus, UploadTrack, UserPage,
+    WatchPlaylist,
 };
+#[cfg(feature = "chrono")]
+use crate::types::{HistoryBucket, HistorySince};
 
 fn validate_id<'a>(name: &str, value: &'a str) -> Result<&'a str> {
     let value = value.trim();
@@ -25,8 +93,13 @@ fn validate_id<'a>(name: &str, value: &'a str) -> Result<&'a str> {
     Ok(value)
 }
 
+/// Normalize a playlist ID, `VL` browse ID or playlist URL to the bare ID.
 fn validate_playlist_id(playlist_id: &str) -> Result<&str> {
-    let playlist_id = validate_id("playlist_id", playlist_id)?;
+    let mut playlist_id = validate_id("playlist_id", playlist_id)?;
+    if let Some((_, query)) = playlist_id.split_once("list=") {
+        let end = query.find(['&', '#']).unwrap_or(query.len());
+        playlist_id = validate_id("playlist_id", &query[..end])?;
+    }
     Ok(playlist_id.strip_prefix("VL").unwrap_or(playlist_id))
 }
 
@@ -34,12 +107,202 @@ fn validate_video_id(video_id: &str) -> Result<&str> {
     validate_id("video_id", video_id)
 }
 
-fn status_succeeded(response: &Value) -> bool {
+/// The status of an edit as sent, for error messages.
+fn status_label(status: &EditStatus) -> &str {
+    match status {
+        EditStatus::Succeeded => "STATUS_SUCCEEDED",
+        EditStatus::Failed => "STATUS_FAILED",
+        EditStatus::Other(status) if !status.is_empty() => status,
+        EditStatus::Other(_) => "Unknown status",
+    }
+}
+
+/// Whether a `feedback` response reports every token as processed.
+fn feedback_processed(response: &Value) -> bool {
     response
-        .get("status")
-        .and_then(|v| v.as_str())
-        .map(|s| s.contains("SUCCEEDED"))
-        .unwrap_or(false)
+        .get("feedbackResponses")
+        .and_then(|v| v.as_array())
+        .is_some_and(|responses| {
+            responses.iter().all(|r| {
+                r.get("isProcessed")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false)
+            })
+        })
+}
+
+const SNIPPET_CHARS: usize = 200;
+
+/// Decode a response body as JSON, reporting non-JSON bodies (HTML consent,
+/// captcha, or proxy pages) as [`Error::UnexpectedContent`].
+fn decode_json_body(content_type: Option<&str>, text: &str) -> Result<Value> {
+    let declared_json = content_type.is_some_and(|ct| ct.to_ascii_lowercase().contains("json"));
+
+    if !text.trim_start().starts_with('<') {
+        match serde_json::from_str(text) {
+            Ok(value) => return Ok(value),
+            Err(err) if declared_json => return Err(err.into()),
+            Err(_) => {}
+        }
+    }
+
+    Err(Error::UnexpectedContent {
+        content_type: content_type.unwrap_or("unknown").to_string(),
+        snippet: text.trim().chars().take(SNIPPET_CHARS).collect(),
+    })
+}
+
+/// Build the [`ResponseMeta`] attached to [`Error::Server`], if any identifiers exist.
+fn server_error_meta(body: &Value, served_by: Option<String>) -> Option<Box<ResponseMeta>> {
+    let meta = ResponseMeta {
+        served_by,
+        ..ResponseMeta::from_response(body)
+    };
+    (!meta.is_empty()).then(|| Box::new(meta))
+}
+
+/// Build the [`Error::Server`] for a non-2xx response. A 401 blamed on a
+/// skewed local clock (`skew` seconds behind the server) says so.
+fn unauthorized_or_server_error(
+    status: u16,
+    text: String,
+    body: &Value,
+    served_by: Option<String>,
+    skew: Option<i64>,
+) -> Error {
+    let mut meta = server_error_meta(body, served_by);
+    let message = match skew {
+        Some(skew) => {
+            meta.get_or_insert_default().clock_skew_seconds = Some(skew);
+            format!("{text} ({})", skew_hint(skew))
+        }
+        None => text,
+    };
+    Error::Server {
+        status,
+        message,
+        meta,
+    }
+}
+
+/// Whether an error payload rejects the request's `clientVersion` as
+/// unsupported or outdated, as opposed to any other bad request.
+pub(crate) fn rejects_client_version(body: &Value) -> bool {
+    let Some(error) = body.get("error") else {
+        return false;
+    };
+    let text = error.to_string().to_lowercase();
+    text.contains("unsupported_client")
+        || text.contains("unsupported client")
+        || (text.contains("client version")
+            && ["unsupported", "outdated", "no longer supported"]
+                .iter()
+                .any(|word| text.contains(word)))
+}
+
+/// Decode a successful response to `request`, surfacing any error payload.
+fn read_response(
+    request: &Value,
+    content_type: Option<&str>,
+    text: &str,
+    served_by: Option<String>,
+) -> Result<Value> {
+    let json = decode_json_body(content_type, text)?;
+    if rejects_client_version(&json) {
+        return Err(version_rejected(request));
+    }
+    match api_error(&json, served_by).or_else(|| account_challenge(&json)) {
+        Some(error) => Err(error),
+        None => Ok(json),
+    }
+}
+
+/// [`Error::ClientVersionRejected`] for a request `body` with its context.
+fn version_rejected(body: &Value) -> Error {
+    Error::ClientVersionRejected {
+        sent_version: nav_str(body, &path!["context", "client", "clientVersion"])
+            .unwrap_or_default()
+            .to_string(),
+    }
+}
+
+/// Turn an `error` payload in a successful HTTP response into [`Error::Server`].
+pub(crate) fn api_error(body: &Value, served_by: Option<String>) -> Option<Error> {
+    let error = body.get("error")?;
+    let message = error
+        .get("message")
+        .and_then(|m| m.as_str())
+        .unwrap_or("Unknown error")
+        .to_string();
+    let code = error.get("code").and_then(|c| c.as_u64()).unwrap_or(500) as u16;
+    Some(Error::Server {
+        status: code,
+        message,
+        meta: server_error_meta(body, served_by),
+    })
+}
+
+/// Top-level keys of responses that carry data; a challenge prompt has none.
+const DATA_KEYS: &[&str] = &[
+    "contents",
+    "continuationContents",
+    "header",
+    "onResponseReceivedActions",
+    "onResponseReceivedEndpoints",
+    "playabilityStatus",
+    "videoDetails",
+    "feedbackResponses",
+    "status",
+];
+
+/// Detect the account challenge prompt Google returns in place of data.
+///
+/// The prompt is a bare `actions`/`command` payload whose button leads to
+/// an `accounts.google.com` challenge page or a sign-in endpoint.
+pub(crate) fn account_challenge(body: &Value) -> Option<Error> {
+    if DATA_KEYS.iter().any(|key| body.get(key).is_some()) {
+        return None;
+    }
+    let mut found = None;
+    for key in ["actions", "command"] {
+        if let Some(command) = body.get(key) {
+            find_challenge(command, &mut found);
+        }
+    }
+    found.map(|url| Error::AccountChallenge { url })
+}
+
+/// Search a command tree for a challenge link, preferring one with a URL.
+fn find_challenge(value: &Value, found: &mut Option<Option<String>>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(url) = nav_str(value, &path!["urlEndpoint", "url"])
+                && is_challenge_url(url)
+            {
+                *found = Some(Some(url.to_string()));
+                return;
+            }
+            if map.contains_key("signInEndpoint") && found.is_none() {
+                *found = Some(None);
+            }
+            for child in map.values() {
+                find_challenge(child, found);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                find_challenge(item, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_challenge_url(url: &str) -> bool {
+    let Some(rest) = url.strip_prefix("https://accounts.google.com/") else {
+        return false;
+    };
+    rest.contains("challenge") || rest.contains("speedbump")
 }
 
 fn collect_movable_items(items: &[PlaylistTrack]) -> Result<(Vec<String>, Vec<PlaylistTrack>)> {
@@ -71,6 +334,377 @@ fn playlist_item_ids(item: &PlaylistTrack) -> Option<(&str, &str)> {
     Some((set_video_id, video_id))
 }
 
+fn track_video_ids<T: TrackLike>(tracks: &[T]) -> Vec<String> {
+    tracks
+        .iter()
+        .filter_map(|track| track.video_id())
+        .map(str::trim)
+        .filter(|video_id| !video_id.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Playlists that cannot be deleted even when empty.
+const AUTO_PLAYLIST_IDS: &[&str] = &["LM", "SE"];
+
+fn plan_library_prune(
+    playlists: &[PlaylistSummary],
+    options: &PruneOptions,
+) -> Vec<PruneCandidate> {
+    let mut planned = Vec::new();
+    if options.empty_playlists {
+        planned.extend(
+            playlists
+                .iter()
+                .filter(|p| p.count == Some(0))
+                .filter(|p| !AUTO_PLAYLIST_IDS.contains(&p.playlist_id.as_str()))
+                .map(|p| PruneCandidate {
+                    id: p.playlist_id.clone(),
+                    title: p.title.clone(),
+                    reason: PruneReason::EmptyPlaylist,
+                }),
+        );
+    }
+    planned
+}
+
+/// Subscriptions whose artist is not among the library's song artists.
+fn plan_artists_without_songs(
+    subscriptions: &[LibraryArtist],
+    library_artists: &[LibraryArtist],
+) -> Vec<PruneCandidate> {
+    let with_songs: HashSet<&str> = library_artists
+        .iter()
+        .filter(|artist| artist.songs != Some(0))
+        .map(|artist| artist.browse_id.as_str())
+        .collect();
+    subscriptions
+        .iter()
+        .filter(|artist| !with_songs.contains(artist.browse_id.as_str()))
+        .map(|artist| PruneCandidate {
+            id: artist.browse_id.clone(),
+            title: artist.name.clone(),
+            reason: PruneReason::ArtistWithoutSongs,
+        })
+        .collect()
+}
+
+/// Library albums none of whose tracks appear in `history`.
+///
+/// A history that ended before reaching the cutoff (e.g. a paused or
+/// recently cleared one) selects nothing, since it does not cover the whole
+/// period.
+#[cfg(feature = "chrono")]
+fn plan_albums_not_played(albums: &[LibraryAlbum], history: &HistorySince) -> Vec<PruneCandidate> {
+    if !history.reached_cutoff {
+        return Vec::new();
+    }
+    let played: HashSet<&str> = history
+        .tracks()
+        .filter_map(|track| track.album.as_ref()?.id.as_deref())
+        .collect();
+    albums
+        .iter()
+        .filter(|album| !played.contains(album.browse_id.as_str()))
+        .map(|album| PruneCandidate {
+            id: album.browse_id.clone(),
+            title: album.title.clone(),
+            reason: PruneReason::AlbumNotPlayed,
+        })
+        .collect()
+}
+
+/// Continuation pages of a playlist's tracks, with "Added" labels read in
+/// English; see [`playlist_track_pages`].
+const PLAYLIST_TRACK_PAGES: PageShape<PlaylistTrack> = PageShape {
+    rows: continuation_rows,
+    parse: parse_playlist_track_rows,
+    words: &ENGLISH,
+    seam_key: |track| track.set_video_id.as_deref(),
+};
+
+/// Continuation pages of a playlist's tracks requested in `language`.
+fn playlist_track_pages(language: &str) -> PageShape<PlaylistTrack> {
+    PageShape {
+        words: vocabulary(language),
+        ..PLAYLIST_TRACK_PAGES
+    }
+}
+
+/// Continuation pages of the listening history, one item per period.
+#[cfg(feature = "chrono")]
+const HISTORY_PAGES: PageShape<HistoryBucket> = PageShape {
+    rows: continuation_rows,
+    parse: |rows, _, _| parse_history_sections(rows),
+    words: &ENGLISH,
+    seam_key: |_| None,
+};
+
+/// Continuation pages of a watch queue.
+const WATCH_PAGES: PageShape<PlaylistTrack> = PageShape {
+    rows: watch_continuation_rows,
+    parse: |rows, _, _| parse_watch_rows(rows),
+    words: &ENGLISH,
+    seam_key: |track| track.set_video_id.as_deref().or(track.video_id.as_deref()),
+};
+
+/// Continuation pages of search results.
+const SEARCH_RESULT_PAGES: PageShape<SearchResult> = PageShape {
+    rows: continuation_rows,
+    parse: |rows, _, _| parse_search_rows(rows),
+    words: &ENGLISH,
+    seam_key: |result| result.video_id.as_deref().or(result.browse_id.as_deref()),
+};
+
+/// Continuation pages of an artist's release listing.
+const ARTIST_RELEASE_PAGES: PageShape<ArtistRelease> = PageShape {
+    rows: continuation_rows,
+    parse: |rows, _, _| parse_release_items(rows),
+    words: &ENGLISH,
+    seam_key: |release| Some(&release.browse_id),
+};
+
+/// Continuation pages of a library or user playlist listing, which share the
+/// grid layout of release listings.
+const PLAYLIST_GRID_PAGES: PageShape<PlaylistSummary> = PageShape {
+    rows: continuation_rows,
+    parse: |rows, _, warnings| parse_library_playlist_item_rows(rows, warnings),
+    words: &ENGLISH,
+    seam_key: |playlist| Some(&playlist.playlist_id),
+};
+
+/// Continuation pages of the home feed, which hold further carousels.
+const HOME_PAGES: PageShape<HomeSection> = PageShape {
+    rows: continuation_rows,
+    parse: |sections, _, _| parse_home_sections(sections),
+    words: &ENGLISH,
+    seam_key: |_| None,
+};
+
+/// Continuation pages of a mood or genre playlist grid.
+const MOOD_PLAYLIST_PAGES: PageShape<PlaylistSummary> = PageShape {
+    rows: continuation_rows,
+    parse: |rows, _, warnings| parse_mood_playlist_rows(rows, warnings),
+    words: &ENGLISH,
+    seam_key: |playlist| Some(&playlist.playlist_id),
+};
+
+/// Parse the first library playlists page and follow its continuations until
+/// `limit` playlists are held.
+///
+/// The "New playlist" tile only appears on the first page and is skipped by
+/// the grid item parser, so it never counts towards `limit`.
+pub(crate) async fn collect_library_playlists<F, Fut>(
+    first: &Value,
+    limit: usize,
+    fetch_next: F,
+) -> Result<Vec<PlaylistSummary>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<Value>>,
+{
+    let page = parse_library_playlists_page(first);
+    let mut playlists = page.playlists;
+    paginate(
+        &mut playlists,
+        page.next_token,
+        limit,
+        &PLAYLIST_GRID_PAGES,
+        fetch_next,
+    )
+    .await?;
+    Ok(playlists)
+}
+
+/// Hand the first playlist page and each continuation page to `on_page`
+/// without collecting them. Pages are read in `language`.
+async fn for_each_playlist_page<F, Fut, P>(
+    first: Vec<PlaylistTrack>,
+    token: Option<String>,
+    language: &str,
+    fetch_next: F,
+    on_page: P,
+) -> Result<()>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<Value>>,
+    P: FnMut(Vec<PlaylistTrack>) -> Result<()>,
+{
+    let mut on_page = on_page;
+    for_each_page(
+        first,
+        token,
+        &playlist_track_pages(language),
+        fetch_next,
+        |page| on_page(page).map(ControlFlow::Continue),
+        &mut Vec::new(),
+    )
+    .await
+}
+
+/// Collect up to `limit` suggestions for the playlist page `first`.
+///
+/// The suggestions shelf is read from `first` or, if it is not inline, from
+/// the section list continuation. Further batches are loaded with the shelf's
+/// refresh token; tracks already held are skipped, and loading stops when a
+/// batch brings nothing new.
+pub(crate) async fn collect_playlist_suggestions<F, Fut>(
+    first: &Value,
+    limit: usize,
+    fetch_next: F,
+) -> Result<Vec<PlaylistTrack>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<Value>>,
+{
+    let mut shelf = parse_playlist_suggestions(first);
+    if shelf.is_none()
+        && let Some(token) = playlist_sections_continuation(first)
+    {
+        shelf = parse_playlist_suggestions(&fetch_next(token).await?);
+    }
+
+    let mut suggestions = Vec::new();
+    let mut seen = HashSet::new();
+    while let Some((batch, reload)) = shelf.take() {
+        let before = suggestions.len();
+        suggestions.extend(batch.into_iter().filter(|track| {
+            track
+                .video_id
+                .as_ref()
+                .is_some_and(|video_id| seen.insert(video_id.clone()))
+        }));
+        if suggestions.len() >= limit || suggestions.len() == before {
+            break;
+        }
+        if let Some(token) = reload {
+            shelf = parse_playlist_suggestions(&fetch_next(token).await?);
+        }
+    }
+    suggestions.truncate(limit);
+    Ok(suggestions)
+}
+
+/// Section list continuations read below a playlist's tracks when looking
+/// for related playlists. Owned playlists show suggestions first.
+const RELATED_SECTION_PAGES: usize = 2;
+
+/// Find the related playlists of the playlist page `first`, following the
+/// section list continuations below the tracks if the page does not show
+/// them. Playlists without the carousel yield an empty list.
+pub(crate) async fn collect_related_playlists<F, Fut>(
+    first: &Value,
+    fetch_next: F,
+) -> Result<Vec<PlaylistSummary>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<Value>>,
+{
+    if let Some(related) = parse_related_playlists(first) {
+        return Ok(related);
+    }
+    let mut token = playlist_sections_continuation(first);
+    for _ in 0..RELATED_SECTION_PAGES {
+        let Some(current) = token.take() else {
+            break;
+        };
+        let page = fetch_next(current).await?;
+        if let Some(related) = parse_related_playlists(&page) {
+            return Ok(related);
+        }
+        token = playlist_sections_continuation(&page);
+    }
+    Ok(Vec::new())
+}
+
+/// Continuation pages of the library's songs.
+const LIBRARY_SONG_PAGES: PageShape<PlaylistTrack> = PageShape {
+    rows: continuation_rows,
+    parse: |rows, _, _| parse_library_song_rows(rows),
+    words: &ENGLISH,
+    seam_key: |track| track.video_id.as_deref(),
+};
+
+/// Continuation pages of the library's albums or of new releases.
+const LIBRARY_ALBUM_PAGES: PageShape<LibraryAlbum> = PageShape {
+    rows: continuation_rows,
+    parse: |rows, _, _| parse_library_album_items(rows),
+    words: &ENGLISH,
+    seam_key: |album| Some(&album.browse_id),
+};
+
+/// Continuation pages of the user's subscriptions.
+const LIBRARY_ARTIST_PAGES: PageShape<LibraryArtist> = PageShape {
+    rows: continuation_rows,
+    parse: |rows, _, _| parse_library_artist_rows(rows),
+    words: &ENGLISH,
+    seam_key: |artist| Some(&artist.browse_id),
+};
+
+/// Continuation pages of the library's Artists tab.
+const LIBRARY_TRACK_ARTIST_PAGES: PageShape<LibraryArtist> = PageShape {
+    rows: continuation_rows,
+    parse: |rows, _, _| parse_library_track_artist_rows(rows),
+    words: &ENGLISH,
+    seam_key: |artist| Some(&artist.browse_id),
+};
+
+/// Continuation pages of uploaded songs.
+const UPLOAD_SONG_PAGES: PageShape<UploadTrack> = PageShape {
+    rows: continuation_rows,
+    parse: |rows, _, _| parse_upload_song_rows(rows),
+    words: &ENGLISH,
+    seam_key: |track| Some(&track.entity_id),
+};
+
+/// Continuation pages of upload albums.
+const UPLOAD_ALBUM_PAGES: PageShape<UploadAlbum> = PageShape {
+    rows: continuation_rows,
+    parse: |rows, _, _| parse_upload_album_items(rows),
+    words: &ENGLISH,
+    seam_key: |album| Some(&album.entity_id),
+};
+
+/// Continuation pages of upload artists.
+const UPLOAD_ARTIST_PAGES: PageShape<UploadArtist> = PageShape {
+    rows: continuation_rows,
+    parse: |rows, _, _| parse_upload_artist_rows(rows),
+    words: &ENGLISH,
+    seam_key: |artist| Some(&artist.browse_id),
+};
+
+/// Normalize an artist ID to the channel ID to browse.
+///
+/// Library artist links use an `MPLA` prefix in front of the channel ID.
+fn artist_browse_id(channel_id: &str) -> Result<&str> {
+    let channel_id = validate_id("channel_id", channel_id)?;
+    Ok(channel_id.strip_prefix("MPLA").unwrap_or(channel_id))
+}
+
+/// Normalize the channel IDs of a subscription change, rejecting empty input.
+fn subscription_channel_ids(channel_ids: &[String]) -> Result<Vec<&str>> {
+    if channel_ids.is_empty() {
+        return Err(Error::InvalidInput(
+            "channel_ids cannot be empty".to_string(),
+        ));
+    }
+    channel_ids.iter().map(|id| artist_browse_id(id)).collect()
+}
+
+/// Browse ID of an artist's release listing.
+///
+/// Accepts the listing's own `MPAD` browse ID from
+/// [`ArtistSection::browse_id`](crate::ArtistSection::browse_id)
+/// or the artist's channel ID, to which the `MPAD` prefix is added.
+fn artist_releases_browse_id(browse_id: &str) -> Result<String> {
+    let browse_id = artist_browse_id(browse_id)?;
+    Ok(if browse_id.starts_with("UC") {
+        format!("MPAD{browse_id}")
+    } else {
+        browse_id.to_string()
+    })
+}
+
 fn song_request_body(video_id: &str) -> Result<Value> {
     let video_id = validate_video_id(video_id)?;
     Ok(json!({
@@ -83,6 +717,122 @@ fn song_request_body(video_id: &str) -> Result<Value> {
     }))
 }
 
+/// Body of a `next` request for the queue of a song or playlist.
+///
+/// Without a playlist, the song's radio (`RDAMVM` + video ID) is queued.
+fn watch_request_body(
+    video_id: Option<&str>,
+    playlist_id: Option<&str>,
+    radio: bool,
+) -> Result<Value> {
+    let video_id = video_id.map(validate_video_id).transpose()?;
+    let playlist_id = match (playlist_id, video_id) {
+        (Some(playlist_id), _) => validate_playlist_id(playlist_id)?.to_string(),
+        (None, Some(video_id)) => format!("RDAMVM{video_id}"),
+        (None, None) => {
+            return Err(Error::InvalidInput(
+                "video_id or playlist_id is required".to_string(),
+            ));
+        }
+    };
+
+    let mut body = json!({
+        "enablePersistentPlaylistPanel": true,
+        "isAudioOnly": true,
+        "tunerSettingValue": "AUTOMIX_SETTING_NORMAL",
+        "playlistId": playlist_id,
+    });
+    if let Some(video_id) = video_id {
+        body["videoId"] = json!(video_id);
+        if !radio {
+            body["watchEndpointMusicSupportedConfigs"] = json!({
+                "watchEndpointMusicConfig": {
+                    "hasPersistentPlaylistPanel": true,
+                    "musicVideoType": "MUSIC_VIDEO_TYPE_ATV"
+                }
+            });
+        }
+    }
+    if radio {
+        body["params"] = json!("wAEB");
+    }
+    Ok(body)
+}
+
+fn search_request_body(
+    query: &str,
+    filter: Option<SearchFilter>,
+    scope: Option<SearchScope>,
+) -> Result<Value> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Err(Error::InvalidInput(
+            "search query cannot be empty".to_string(),
+        ));
+    }
+    let mut body = json!({ "query": query });
+    if let Some(params) = search_params(filter, scope)? {
+        body["params"] = json!(params);
+    }
+    Ok(body)
+}
+
+/// Build the search `params` value, as the Python ytmusicapi does.
+fn search_params(
+    filter: Option<SearchFilter>,
+    scope: Option<SearchScope>,
+) -> Result<Option<String>> {
+    let params = match (scope, filter) {
+        (None, None) => None,
+        (None, Some(filter)) => Some(filter.params().to_string()),
+        (Some(SearchScope::Uploads), None) => Some("agIYAw%3D%3D".to_string()),
+        (Some(SearchScope::Uploads), Some(_)) => {
+            return Err(Error::InvalidInput(
+                "search filters cannot be combined with the uploads scope".to_string(),
+            ));
+        }
+        (Some(SearchScope::Library), None) => Some("agIYBA%3D%3D".to_string()),
+        (Some(SearchScope::Library), Some(filter)) => {
+            let code = filter.library_code().ok_or_else(|| {
+                Error::InvalidInput(format!(
+                    "search filter {filter:?} is not available in the library scope"
+                ))
+            })?;
+            Some(format!("EgWKAQ{code}AWoKEAUQCRADEAoYBA%3D%3D"))
+        }
+    };
+    Ok(params)
+}
+
+fn feedback_request_body(tokens: &[String]) -> Result<Value> {
+    if tokens.is_empty() {
+        return Err(Error::InvalidInput(
+            "feedback tokens cannot be empty".to_string(),
+        ));
+    }
+    Ok(json!({ "feedbackTokens": tokens }))
+}
+
+/// Per-token `isProcessed` flags of a `feedback` response for `count` tokens.
+///
+/// Tokens the response does not answer count as unprocessed.
+fn feedback_flags(response: &Value, count: usize) -> Vec<bool> {
+    let responses = response
+        .get("feedbackResponses")
+        .and_then(|v| v.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    (0..count)
+        .map(|i| {
+            responses
+                .get(i)
+                .and_then(|r| r.get("isProcessed"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
 fn rating_request_body(video_id: &str) -> Result<Value> {
     let video_id = validate_video_id(video_id)?;
     Ok(json!({
@@ -92,10 +842,24 @@ fn rating_request_body(video_id: &str) -> Result<Value> {
     }))
 }
 
+fn playlist_rating_request_body(playlist_id: &str, rating: LikeStatus) -> Result<Value> {
+    if rating == LikeStatus::Dislike {
+        return Err(Error::InvalidInput(
+            "playlists can only be liked or unliked, not disliked".to_string(),
+        ));
+    }
+    let playlist_id = validate_playlist_id(playlist_id)?;
+    Ok(json!({
+        "target": {
+            "playlistId": playlist_id
+        }
+    }))
+}
+
 fn add_playlist_items_body(
     playlist_id: &str,
     video_ids: &[String],
-    allow_duplicates: bool,
+    duplicates: DuplicateHandling,
 ) -> Result<Value> {
     let playlist_id = validate_playlist_id(playlist_id)?;
     if video_ids.is_empty() {
@@ -107,14 +871,94 @@ fn add_playlist_items_body(
     let mut actions = Vec::new();
     for video_id in video_ids {
         let video_id = validate_video_id(video_id)?;
-        let mut action = json!({
+        actions.push(json!({
             "action": "ACTION_ADD_VIDEO",
-            "addedVideoId": video_id
+            "addedVideoId": video_id,
+            "dedupeOption": duplicates.dedupe_option()
+        }));
+    }
+
+    Ok(json!({
+        "playlistId": playlist_id,
+        "actions": actions
+    }))
+}
+
+/// Whether the server refused to serve a playlist ID, as opposed to failing
+/// for another reason.
+fn is_rejected_playlist(err: &Error) -> bool {
+    matches!(
+        err,
+        Error::Server {
+            status: 400 | 403 | 404,
+            ..
+        }
+    )
+}
+
+/// Turn skipped videos into [`Error::Duplicates`], carrying the whole
+/// result, if `duplicates` asks for it.
+fn check_duplicates(
+    result: AddPlaylistItemsResult,
+    duplicates: DuplicateHandling,
+) -> Result<AddPlaylistItemsResult> {
+    if duplicates == DuplicateHandling::Fail && !result.skipped.is_empty() {
+        return Err(Error::Duplicates {
+            result: Box::new(result),
         });
-        if !allow_duplicates {
-            action["dedupeOption"] = json!("DEDUPE_OPTION_SKIP");
+    }
+    Ok(result)
+}
+
+fn add_playlist_to_playlist_body(playlist_id: &str, source_playlist_id: &str) -> Result<Value> {
+    let playlist_id = validate_playlist_id(playlist_id)?;
+    let source_playlist_id = validate_playlist_id(source_playlist_id)?;
+    if playlist_id == source_playlist_id {
+        return Err(Error::InvalidInput(
+            "source and destination playlists must differ".to_string(),
+        ));
+    }
+    Ok(json!({
+        "playlistId": playlist_id,
+        "actions": [
+            { "action": "ACTION_ADD_PLAYLIST", "addedFullListId": source_playlist_id },
+            // Without a video action the response omits the per-item results.
+            { "action": "ACTION_ADD_VIDEO", "addedVideoId": null }
+        ]
+    }))
+}
+
+fn edit_playlist_body(playlist_id: &str, options: &EditPlaylistOptions) -> Result<Value> {
+    let playlist_id = validate_playlist_id(playlist_id)?;
+    if options.is_empty() {
+        return Err(Error::InvalidInput(
+            "options must set at least one of title, description or privacy".to_string(),
+        ));
+    }
+
+    let mut actions = Vec::new();
+    if let Some(title) = &options.title {
+        if title.trim().is_empty() {
+            return Err(Error::InvalidInput(
+                "title must include at least one character".to_string(),
+            ));
         }
-        actions.push(action);
+        actions.push(json!({
+            "action": "ACTION_SET_PLAYLIST_NAME",
+            "playlistName": title.trim()
+        }));
+    }
+    if let Some(description) = &options.description {
+        actions.push(json!({
+            "action": "ACTION_SET_PLAYLIST_DESCRIPTION",
+            "playlistDescription": description
+        }));
+    }
+    if let Some(privacy) = options.privacy {
+        actions.push(json!({
+            "action": "ACTION_SET_PLAYLIST_PRIVACY",
+            "playlistPrivacy": privacy.as_api_str()
+        }));
     }
 
     Ok(json!({
@@ -123,6 +967,46 @@ fn add_playlist_items_body(
     }))
 }
 
+fn playlist_item_note_body(playlist_id: &str, set_video_id: &str, note: &str) -> Result<Value> {
+    let playlist_id = validate_playlist_id(playlist_id)?;
+    let set_video_id = validate_id("set_video_id", set_video_id)?;
+    Ok(json!({
+        "playlistId": playlist_id,
+        "actions": [{
+            "action": "ACTION_SET_VIDEO_NOTE",
+            "setVideoId": set_video_id,
+            "videoNote": note.trim()
+        }]
+    }))
+}
+
+/// Map the result of an edit `action` with limited rollout to
+/// [`Error::Unsupported`] when the backend does not know the action.
+///
+/// Only a 501, or a 400 naming `action` as an invalid value, means the
+/// action is not rolled out. Other rejections, such as a stale
+/// `setVideoId`, stay [`Error::Server`], and a `STATUS_FAILED` response is
+/// returned as is.
+fn classify_rollout_action(
+    feature: &str,
+    action: &str,
+    result: Result<Value>,
+) -> Result<EditPlaylistResponse> {
+    match result {
+        Ok(response) => Ok(EditPlaylistResponse::from_response(response)),
+        Err(Error::Server {
+            status, message, ..
+        }) if status == 501
+            || (status == 400
+                && message.starts_with("Invalid value at 'actions[")
+                && message.contains(&format!("\"{action}\""))) =>
+        {
+            Err(Error::Unsupported(format!("{feature}: {message}")))
+        }
+        Err(err) => Err(err),
+    }
+}
+
 fn remove_playlist_items_body(playlist_id: &str, items: &[PlaylistTrack]) -> Result<Value> {
     let playlist_id = validate_playlist_id(playlist_id)?;
     let mut actions = Vec::new();
@@ -148,25 +1032,375 @@ fn remove_playlist_items_body(playlist_id: &str, items: &[PlaylistTrack]) -> Res
     }))
 }
 
-/// The main YouTube Music API client.
-///
-/// Construct with [`YTMusicClient::builder()`]. Methods that require
-/// authentication return [`Error::AuthRequired`](crate::Error::AuthRequired) if
-/// no [`BrowserAuth`] is configured.
-pub struct YTMusicClient {
-    http: reqwest::Client,
-    auth: Option<BrowserAuth>,
-    language: String,
-    location: Option<String>,
-    user: Option<String>,
+/// Characters of a client playback nonce.
+const CPN_ALPHABET: &[u8; 64] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789-_";
+
+/// A random 16-character client playback nonce (`cpn`), which identifies one
+/// playback in reporting requests. Not suitable for anything secret.
+fn client_playback_nonce() -> String {
+    let mut bits = 0;
+    (0..16)
+        .map(|i| {
+            // Each 64-bit draw covers ten characters.
+            if i % 10 == 0 {
+                bits = RandomState::new().build_hasher().finish();
+            }
+            let c = CPN_ALPHABET[(bits & 63) as usize] as char;
+            bits >>= 6;
+            c
+        })
+        .collect()
 }
 
-/// Builder for constructing a [`YTMusicClient`].
-pub struct YTMusicClientBuilder {
-    auth: Option<BrowserAuth>,
-    language: String,
-    location: Option<String>,
-    user: Option<String>,
+/// The play-reporting URL of `song`, if it is an HTTPS URL on a YouTube host.
+///
+/// The request carries the account's cookies, so other hosts are refused.
+fn playback_url(song: &Song) -> Result<reqwest::Url> {
+    let base_url = song
+        .playback_tracking
+        .as_ref()
+        .and_then(|tracking| tracking.videostats_playback_url.as_ref())
+        .map(|url| url.base_url.as_str())
+        .ok_or_else(|| {
+            Error::InvalidInput(format!(
+                "Song {} has no playback tracking URL",
+                song.video_details.video_id
+            ))
+        })?;
+    let url = reqwest::Url::parse(base_url)
+        .map_err(|e| Error::InvalidInput(format!("Invalid playback tracking URL: {e}")))?;
+    let youtube = url
+        .host_str()
+        .is_some_and(|host| host == "youtube.com" || host.ends_with(".youtube.com"));
+    if url.scheme() != "https" || !youtube {
+        return Err(Error::InvalidInput(format!(
+            "Playback tracking URL does not point to YouTube: {base_url}"
+        )));
+    }
+    Ok(url)
+}
+
+/// File extensions accepted by [`YTMusicClient::upload_song`].
+const UPLOAD_EXTENSIONS: [&str; 5] = ["mp3", "m4a", "wma", "flac", "ogg"];
+
+/// Largest file accepted by [`YTMusicClient::upload_song`], 300 MB.
+const UPLOAD_SIZE_LIMIT: u64 = 300 * 1024 * 1024;
+
+/// Check that `path` names a file YouTube Music accepts for upload, given
+/// its size in bytes, and return the file name to send.
+fn upload_file_name(path: &Path, size: u64) -> Result<&str> {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    if !UPLOAD_EXTENSIONS.contains(&extension.as_str()) {
+        return Err(Error::InvalidInput(format!(
+            "{} is not an uploadable file; supported extensions are {}",
+            path.display(),
+            UPLOAD_EXTENSIONS.join(", ")
+        )));
+    }
+    if size >= UPLOAD_SIZE_LIMIT {
+        return Err(Error::InvalidInput(format!(
+            "{} is {size} bytes; uploads must be smaller than 300 MB",
+            path.display()
+        )));
+    }
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| Error::InvalidInput(format!("{} has no UTF-8 file name", path.display())))
+}
+
+/// The upload URL from the `x-goog-upload-url` header of a started upload,
+/// if it is an HTTPS URL on a YouTube host.
+///
+/// The file is sent with the account's cookies, so other hosts are refused.
+fn upload_url(response: &reqwest::Response) -> Result<reqwest::Url> {
+    let header = response
+        .headers()
+        .get("x-goog-upload-url")
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| Error::Server {
+            status: response.status().as_u16(),
+            message: "upload was not started: no x-goog-upload-url header".to_string(),
+            meta: server_error_meta(&Value::Null, None),
+        })?;
+    let url = reqwest::Url::parse(header)
+        .map_err(|e| Error::InvalidInput(format!("Invalid upload URL: {e}")))?;
+    let youtube = url
+        .host_str()
+        .is_some_and(|host| host == "youtube.com" || host.ends_with(".youtube.com"));
+    if url.scheme() != "https" || !youtube {
+        return Err(Error::InvalidInput(format!(
+            "Upload URL does not point to YouTube: {header}"
+        )));
+    }
+    Ok(url)
+}
+
+/// Feedback tokens sent per request by [`YTMusicClient::remove_history_items`].
+const FEEDBACK_CHUNK_SIZE: usize = 50;
+
+/// Video IDs sent per request by [`YTMusicClient::get_like_statuses`].
+const LIKE_STATUS_CHUNK_SIZE: usize = 50;
+
+/// Items removed per request by [`YTMusicClient::remove_playlist_range`].
+const REMOVE_CHUNK_SIZE: usize = 100;
+
+/// Set the `hl` preference of the `PREF` cookie in a cookie header to
+/// `language`, adding the cookie if missing.
+fn override_pref_language(cookie: &str, language: &str) -> String {
+    let mut found = false;
+    let mut parts: Vec<String> = cookie
+        .split(';')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| match part.strip_prefix("PREF=") {
+            Some(value) => {
+                found = true;
+                let mut prefs: Vec<&str> = value
+                    .split('&')
+                    .filter(|pref| !pref.is_empty() && !pref.starts_with("hl="))
+                    .collect();
+                let hl = format!("hl={language}");
+                prefs.push(&hl);
+                format!("PREF={}", prefs.join("&"))
+            }
+            None => part.to_string(),
+        })
+        .collect();
+    if !found {
+        parts.push(format!("PREF=hl={language}"));
+    }
+    parts.join("; ")
+}
+
+/// Tracks re-read by edit verification, i.e. at most 10 continuation pages.
+const VERIFY_TRACK_LIMIT: u32 = 1000;
+
+/// Check that the `touched` items are in `tracks` if `present`, or missing
+/// otherwise.
+///
+/// If `tracks` is only the start of the playlist (`complete == false`),
+/// touched items not found in it may lie further on. They are returned
+/// unchecked instead, and only removed items found in `tracks` fail.
+fn check_playlist_items(
+    tracks: &[PlaylistTrack],
+    touched: Vec<String>,
+    present: bool,
+    complete: bool,
+) -> Result<Vec<String>> {
+    let ids: HashSet<&str> = tracks
+        .iter()
+        .flat_map(|track| [track.set_video_id.as_deref(), track.video_id.as_deref()])
+        .flatten()
+        .collect();
+    let observed: Vec<String> = touched
+        .iter()
+        .filter(|id| ids.contains(id.as_str()))
+        .cloned()
+        .collect();
+    if !complete {
+        if !present && !observed.is_empty() {
+            return Err(Error::VerificationFailed {
+                expected: Vec::new(),
+                observed,
+            });
+        }
+        return Ok(touched
+            .into_iter()
+            .filter(|id| !ids.contains(id.as_str()))
+            .collect());
+    }
+    let expected = if present { touched } else { Vec::new() };
+    if observed == expected {
+        Ok(Vec::new())
+    } else {
+        Err(Error::VerificationFailed { expected, observed })
+    }
+}
+
+/// Move actions sent per request by [`YTMusicClient::reorder_playlist`].
+const MOVE_CHUNK_SIZE: usize = 100;
+
+/// An `ACTION_MOVE_VIDEO_BEFORE` edit action. Without `before`, the item moves
+/// to the end of the playlist.
+fn move_item_action(set_video_id: &str, before: Option<&str>) -> Value {
+    let mut action = json!({
+        "action": "ACTION_MOVE_VIDEO_BEFORE",
+        "setVideoId": set_video_id
+    });
+    if let Some(before) = before {
+        action["movedSetVideoIdSuccessor"] = json!(before);
+    }
+    action
+}
+
+/// The fewest move actions turning the `current` item order into `target`.
+///
+/// Both are lists of `set_video_id`s; `target` must hold exactly the items of
+/// `current`. The longest run of items already in target order stays put and
+/// every other item is moved before its target successor, from the back, so
+/// each anchor is already in place when it is used.
+pub(crate) fn reorder_actions(current: &[String], target: &[String]) -> Result<Vec<Value>> {
+    let positions: HashMap<&str, usize> = current
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (id.as_str(), i))
+        .collect();
+    if positions.len() != current.len() {
+        return Err(Error::InvalidInput(
+            "playlist holds an item more than once".to_string(),
+        ));
+    }
+    let mut seen = HashSet::new();
+    let target_positions = target
+        .iter()
+        .map(|id| match positions.get(id.as_str()) {
+            Some(&position) if seen.insert(position) => Ok(position),
+            Some(_) => Err(Error::InvalidInput(format!(
+                "set_video_id {id:?} is listed more than once"
+            ))),
+            None => Err(Error::InvalidInput(format!(
+                "set_video_id {id:?} is not in the playlist"
+            ))),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if target_positions.len() != current.len() {
+        return Err(Error::InvalidInput(format!(
+            "ordered_set_video_ids lists {} of the playlist's {} items",
+            target_positions.len(),
+            current.len()
+        )));
+    }
+
+    let keep = longest_increasing_run(&target_positions);
+    Ok((0..target.len())
+        .rev()
+        .filter(|&i| !keep[i])
+        .map(|i| move_item_action(&target[i], target.get(i + 1).map(String::as_str)))
+        .collect())
+}
+
+/// Mark one longest strictly increasing subsequence of `values`.
+fn longest_increasing_run(values: &[usize]) -> Vec<bool> {
+    // `tails[k]` is the index ending the smallest-ending run of length k + 1.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous = vec![None; values.len()];
+    for (i, &value) in values.iter().enumerate() {
+        let k = tails.partition_point(|&t| values[t] < value);
+        previous[i] = k.checked_sub(1).map(|k| tails[k]);
+        if k == tails.len() {
+            tails.push(i);
+        } else {
+            tails[k] = i;
+        }
+    }
+
+    let mut keep = vec![false; values.len()];
+    let mut next = tails.last().copied();
+    while let Some(i) = next {
+        keep[i] = true;
+        next = previous[i];
+    }
+    keep
+}
+
+/// Take the items at `range` out of a playlist's `tracks`.
+///
+/// Fails without taking anything if the range is empty or out of bounds, or if
+/// any item in it cannot be removed.
+fn take_playlist_range(
+    mut tracks: Vec<PlaylistTrack>,
+    range: &Range<usize>,
+) -> Result<Vec<PlaylistTrack>> {
+    if range.is_empty() {
+        return Err(Error::InvalidInput(format!(
+            "Empty playlist range {}..{}",
+            range.start, range.end
+        )));
+    }
+    if range.end > tracks.len() {
+        return Err(Error::InvalidInput(format!(
+            "Playlist range {}..{} exceeds the playlist's {} items",
+            range.start,
+            range.end,
+            tracks.len()
+        )));
+    }
+    tracks.truncate(range.end);
+    let items = tracks.split_off(range.start);
+    if let Some(offset) = items.iter().position(|t| playlist_item_ids(t).is_none()) {
+        return Err(Error::InvalidInput(format!(
+            "Playlist item {} has no video ID or playlist item ID and cannot be removed",
+            range.start + offset
+        )));
+    }
+    Ok(items)
+}
+
+/// The main YouTube Music API client.
+///
+/// Construct with [`YTMusicClient::builder()`]. Methods that require
+/// authentication return [`Error::AuthRequired`](crate::Error::AuthRequired) if
+/// no [`BrowserAuth`] is configured.
+pub struct YTMusicClient {
+    http: reqwest::Client,
+    /// Credentials, swapped by [`YTMusicClient::replace_auth`]. Requests read
+    /// them once, when their headers are built.
+    auth: RwLock<Option<BrowserAuth>>,
+    language: String,
+    location: Option<String>,
+    user: Option<String>,
+    capabilities: Mutex<Option<Capabilities>>,
+    audit_hook: Option<AuditHook>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    verify_edits: bool,
+    language_override: bool,
+    clock_correction: bool,
+    /// Seconds the server clock is ahead of the local one, from the `Date`
+    /// header of the latest response. Only tracked with clock correction.
+    clock_offset: AtomicI64,
+    /// Recorded responses answering requests instead of the network.
+    fixtures: Option<FixtureTransport>,
+}
+
+/// Receiver of [`MutationRecord`]s.
+type AuditHook = Arc<dyn Fn(MutationRecord) + Send + Sync>;
+
+/// Build an HTTP client sending the [`default_headers`].
+pub(crate) fn http_client() -> Result<reqwest::Client> {
+    let mut headers = HeaderMap::new();
+
+    for (key, value) in default_headers() {
+        if let Ok(header_value) = HeaderValue::from_str(&value)
+            && let Ok(header_name) = key.parse::<HeaderName>()
+        {
+            headers.insert(header_name, header_value);
+        }
+    }
+
+    Ok(reqwest::Client::builder()
+        .default_headers(headers)
+        .gzip(true)
+        .build()?)
+}
+
+/// Builder for constructing a [`YTMusicClient`].
+#[derive(Clone)]
+pub struct YTMusicClientBuilder {
+    auth: Option<BrowserAuth>,
+    language: String,
+    location: Option<String>,
+    user: Option<String>,
+    audit_hook: Option<AuditHook>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    http: Option<reqwest::Client>,
+    verify_edits: bool,
+    language_override: bool,
+    clock_correction: bool,
+    fixtures: Option<FixtureTransport>,
 }
 
 impl YTMusicClient {
@@ -176,12 +1410,21 @@ impl YTMusicClient {
     /// - language: `"en"`
     /// - location: `None`
     /// - user: `None`
+    /// - edit verification: off
+    /// - clock correction: off
     pub fn builder() -> YTMusicClientBuilder {
         YTMusicClientBuilder {
             auth: None,
             language: "en".to_string(),
             location: None,
             user: None,
+            audit_hook: None,
+            rate_limiter: None,
+            http: None,
+            verify_edits: false,
+            language_override: false,
+            clock_correction: false,
+            fixtures: None,
         }
     }
 
@@ -189,18 +1432,32 @@ impl YTMusicClient {
     ///
     /// This does not validate the cookie or perform a network request.
     pub fn is_authenticated(&self) -> bool {
-        self.auth.is_some()
+        self.auth().is_some()
+    }
+
+    /// Replace the browser credentials of a running client.
+    ///
+    /// For refreshing cookies without rebuilding the client: requests started
+    /// afterwards use `auth`, while requests already sent finish with the
+    /// credentials they were sent with. Caches, the rate limiter and the
+    /// clock offset are kept, so `auth` should be for the same account;
+    /// build a new client to switch accounts. Also authenticates a client
+    /// built without credentials.
+    pub fn replace_auth(&self, auth: BrowserAuth) {
+        *self.auth.write().unwrap_or_else(PoisonError::into_inner) = Some(auth);
     }
 
     /// Get playlists from the user's library.
     ///
-    /// Requires authentication. This currently fetches only the first page of
-    /// playlists returned by the web client and does not follow continuations.
+    /// Requires authentication. Continuation pages are followed until `limit`
+    /// playlists are held or none remain. Playlists are returned in the order
+    /// the web client lists them; no client-side sorting is applied. A new
+    /// library without playlists yields an empty list.
     ///
     /// # Arguments
     ///
     /// * `limit` - Maximum number of playlists to return. `None` returns the
-    ///   entire first page.
+    ///   whole library.
     ///
     /// # Example
     ///
@@ -222,29 +1479,76 @@ impl YTMusicClient {
         });
 
         let response = self.send_request("browse", body).await?;
-        let mut playlists = parse_library_playlists(&response);
-
-        // Handle pagination if needed
-        if let Some(lim) = limit {
-            playlists.truncate(lim as usize);
-        }
+        collect_library_playlists(
+            &response,
+            limit.map_or(usize::MAX, |n| n as usize),
+            |token| self.fetch_continuation_page(token),
+        )
+        .await
+    }
 
-        // TODO: Handle continuations for large libraries
+    /// Get a single page of library playlists.
+    ///
+    /// Requires authentication. Pass `None` for the first page, then the
+    /// returned [`LibraryPlaylistsPage::next_token`] to fetch the following
+    /// pages. The first page also carries a total count hint when the web client
+    /// shows one, so UIs can render progress while loading lazily.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ytmusicapi::YTMusicClient;
+    /// # async fn example(client: &YTMusicClient) -> ytmusicapi::Result<()> {
+    /// let mut page = client.get_library_playlists_page(None).await?;
+    /// println!("showing {} of {:?}", page.playlists.len(), page.total_hint);
+    /// while let Some(token) = page.next_token.take() {
+    ///     page = client.get_library_playlists_page(Some(&token)).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_library_playlists_page(
+        &self,
+        token: Option<&str>,
+    ) -> Result<LibraryPlaylistsPage> {
+        self.check_auth()?;
 
-        Ok(playlists)
+        match token {
+            None => {
+                let body = json!({
+                    "browseId": "FEmusic_liked_playlists"
+                });
+                let response = self.send_request("browse", body).await?;
+                Ok(parse_library_playlists_page(&response))
+            }
+            Some(token) => {
+                let body = json!({
+                    "continuation": validate_id("token", token)?
+                });
+                let response = self.send_request("browse", body).await?;
+                Ok(parse_library_playlists_continuation(&response))
+            }
+        }
     }
 
     /// Get a playlist with its tracks.
     ///
     /// Fetches metadata and tracks for a given playlist ID. The client does not
     /// enforce authentication, but private playlists may be rejected by the API.
-    /// If `limit` is `None`, the client follows continuations and returns up to
-    /// 5,000 tracks.
+    /// If `limit` is `None`, the client follows continuations until the
+    /// server sends no more, however long the playlist. See
+    /// [`Self::get_playlist_with`] for more options.
+    ///
+    /// Tracks are returned in server order across continuation pages. If a page
+    /// repeats the previous page's last item (same `set_video_id`), the repeat is
+    /// dropped.
     ///
     /// # Arguments
     ///
-    /// * `playlist_id` - The playlist ID (can be with or without `VL` prefix).
-    /// * `limit` - Maximum number of tracks to return. `None` for all (capped at 5,000).
+    /// * `playlist_id` - The playlist ID, with or without the `VL` prefix, or a
+    ///   URL with a `list=` parameter. [`Playlist::id`] holds the ID the server
+    ///   resolved and [`Playlist::requested_id`] this input as given.
+    /// * `limit` - Maximum number of tracks to return. `None` for all.
     ///
     /// # Example
     ///
@@ -260,51 +1564,90 @@ impl YTMusicClient {
     /// # }
     /// ```
     pub async fn get_playlist(&self, playlist_id: &str, limit: Option<u32>) -> Result<Playlist> {
-        let playlist_id = validate_id("playlist_id", playlist_id)?;
-        // Ensure playlist ID has VL prefix for browse endpoint
-        let browse_id = if playlist_id.starts_with("VL") {
-            playlist_id.to_string()
-        } else {
-            format!("VL{}", playlist_id)
+        let options = GetPlaylistOptions {
+            limit,
+            ..GetPlaylistOptions::default()
         };
+        self.get_playlist_with(playlist_id, &options).await
+    }
 
+    /// Get a playlist with its tracks, configured by [`GetPlaylistOptions`].
+    ///
+    /// Behaves like [`Self::get_playlist`]. With
+    /// [`include_unavailable(false)`](GetPlaylistOptions::include_unavailable),
+    /// tracks that cannot be played are dropped while paging, so `limit`
+    /// counts playable tracks only.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example(client: &ytmusicapi::YTMusicClient) -> ytmusicapi::Result<()> {
+    /// use ytmusicapi::GetPlaylistOptions;
+    ///
+    /// let options = GetPlaylistOptions::new().limit(200).include_unavailable(false);
+    /// let playlist = client.get_playlist_with("PL123456789", &options).await?;
+    /// assert!(playlist.tracks.iter().all(|track| track.is_available));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_playlist_with(
+        &self,
+        playlist_id: &str,
+        options: &GetPlaylistOptions,
+    ) -> Result<Playlist> {
+        let requested_id = playlist_id;
+        let playlist_id = validate_playlist_id(playlist_id)?;
         let body = json!({
-            "browseId": browse_id
+            "browseId": format!("VL{playlist_id}")
         });
 
         let response = self.send_request("browse", body).await?;
-        let mut playlist = parse_playlist_response(&response, playlist_id);
+        let (mut playlist, continuation) =
+            parse_playlist_response(&response, playlist_id, &self.language);
+        playlist.requested_id = requested_id.to_string();
 
         // Handle pagination for tracks
-        let track_limit = limit.unwrap_or(5000) as usize;
+        let track_limit = options.limit.map_or(usize::MAX, |limit| limit as usize);
+        let pages = playlist_track_pages(&self.language);
 
-        // Get continuation token if present and we need more tracks
-        let secondary_contents = nav(
-            &response,
-            &path![
-                "contents",
-                "twoColumnBrowseResultsRenderer",
-                "secondaryContents",
-                "sectionListRenderer",
-                "contents",
-                0,
-                "musicPlaylistShelfRenderer"
-            ],
-        );
-
-        if let Some(shelf) = secondary_contents
-            && playlist.tracks.len() < track_limit
-            && let Some(token) = get_continuation_token(shelf)
-        {
-            let more_tracks = self
-                .fetch_playlist_continuations(&token, track_limit - playlist.tracks.len())
-                .await?;
-            playlist.tracks.extend(more_tracks);
+        if options.include_unavailable {
+            paginate_with_warnings(
+                &mut playlist.tracks,
+                continuation,
+                track_limit,
+                &pages,
+                |token| self.fetch_continuation_page(token),
+                &mut playlist.warnings,
+            )
+            .await?;
+        } else {
+            // Filter page by page so that skipped tracks do not use up the
+            // limit and a page of only unavailable tracks does not end paging.
+            let first = std::mem::take(&mut playlist.tracks);
+            let tracks = &mut playlist.tracks;
+            for_each_page(
+                first,
+                continuation,
+                &pages,
+                |token| self.fetch_continuation_page(token),
+                |page| {
+                    tracks.extend(page.into_iter().filter(|track| track.is_available));
+                    Ok(if tracks.len() >= track_limit {
+                        ControlFlow::Break(())
+                    } else {
+                        ControlFlow::Continue(())
+                    })
+                },
+                &mut playlist.warnings,
+            )
+            .await?;
+            playlist.tracks.truncate(track_limit);
         }
 
-        // Apply limit
-        if let Some(lim) = limit {
-            playlist.tracks.truncate(lim as usize);
+        if options.related && playlist.related.is_empty() {
+            playlist.related =
+                collect_related_playlists(&response, |token| self.fetch_continuation_page(token))
+                    .await?;
         }
 
         // Recalculate duration
@@ -313,22 +1656,174 @@ impl YTMusicClient {
                 .tracks
                 .iter()
                 .filter_map(|t| t.duration_seconds)
+                .map(u64::from)
                 .sum(),
         );
 
         Ok(playlist)
     }
 
+    /// Get the songs YouTube Music suggests adding to an owned playlist.
+    ///
+    /// Requires authentication; suggestions are only offered on playlists the
+    /// account owns, and other playlists yield an empty list. The suggestions
+    /// shelf is refreshed until `limit` distinct tracks are held or a refresh
+    /// brings no new ones. Suggested tracks are not in the playlist, so they
+    /// have a video ID but no `set_video_id`.
+    pub async fn get_playlist_suggestions(
+        &self,
+        playlist_id: &str,
+        limit: u32,
+    ) -> Result<Vec<PlaylistTrack>> {
+        self.check_auth()?;
+        let playlist_id = validate_playlist_id(playlist_id)?;
+        let response = self
+            .send_request("browse", json!({ "browseId": format!("VL{playlist_id}") }))
+            .await?;
+        collect_playlist_suggestions(&response, limit as usize, |token| {
+            self.fetch_continuation_page(token)
+        })
+        .await
+    }
+
     /// Get the "Liked Songs" playlist.
     ///
-    /// Requires authentication.
+    /// Requires authentication. An account that has not liked anything yet
+    /// gets a playlist with no tracks.
     ///
     /// # Arguments
     ///
     /// * `limit` - Maximum number of tracks to return. `None` for all.
     pub async fn get_liked_songs(&self, limit: Option<u32>) -> Result<Playlist> {
         self.check_auth()?;
-        self.get_playlist("LM", limit).await
+        match self.get_playlist("LM", limit).await {
+            // Some brand accounts reject `LM`; their library lists the likes
+            // under another ID.
+            Err(err) if is_rejected_playlist(&err) => {
+                let summary = self.get_liked_songs_summary().await?;
+                if summary.playlist_id == "LM" {
+                    return Err(err);
+                }
+                self.get_playlist(&summary.playlist_id, limit).await
+            }
+            result => result,
+        }
+    }
+
+    /// Get the library entry of the "Liked Songs" playlist.
+    ///
+    /// Requires authentication. The entry is found by its thumbs-up icon
+    /// rather than its title, which is localized, and its ID is the one this
+    /// account uses: usually `LM`, but another auto-playlist ID on some brand
+    /// accounts. Returns [`Error::Navigation`] if the library lists no such
+    /// entry.
+    pub async fn get_liked_songs_summary(&self) -> Result<PlaylistSummary> {
+        self.check_auth()?;
+        let body = json!({
+            "browseId": "FEmusic_liked_playlists"
+        });
+        let response = self.send_request("browse", body).await?;
+        parse_liked_music_summary(&response).ok_or_else(|| Error::Navigation {
+            path: "gridRenderer.items[liked songs]".to_string(),
+        })
+    }
+
+    /// Read a playlist's tracks page by page.
+    ///
+    /// Unlike [`Self::get_playlist`], tracks are not collected: `on_page` gets
+    /// each page (first page included) as it arrives, so only one page is held
+    /// at a time. An error returned by `on_page` stops the listing and is
+    /// returned. Works without authentication for public playlists.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example(client: &ytmusicapi::YTMusicClient) -> ytmusicapi::Result<()> {
+    /// let mut count = 0;
+    /// client
+    ///     .stream_playlist_tracks("PLxxxxxxxx", |page| {
+    ///         count += page.len();
+    ///         Ok(())
+    ///     })
+    ///     .await?;
+    /// println!("{count} tracks");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stream_playlist_tracks<F>(&self, playlist_id: &str, on_page: F) -> Result<()>
+    where
+        F: FnMut(Vec<PlaylistTrack>) -> Result<()>,
+    {
+        let playlist_id = validate_playlist_id(playlist_id)?;
+        let response = self
+            .send_request("browse", json!({ "browseId": format!("VL{playlist_id}") }))
+            .await?;
+        let (playlist, continuation) =
+            parse_playlist_response(&response, playlist_id, &self.language);
+        drop(response);
+        for_each_playlist_page(
+            playlist.tracks,
+            continuation,
+            &self.language,
+            |token| self.fetch_continuation_page(token),
+            on_page,
+        )
+        .await
+    }
+
+    /// Export a playlist's tracks to `writer` as CSV or M3U.
+    ///
+    /// Pages are written and flushed as they arrive (see
+    /// [`Self::stream_playlist_tracks`]), so memory use does not grow with the
+    /// playlist length. Returns the number of tracks written; M3U exports skip
+    /// tracks without a video ID. Wrap files in a [`std::io::BufWriter`].
+    pub async fn export_playlist<W: Write>(
+        &self,
+        playlist_id: &str,
+        format: ExportFormat,
+        writer: W,
+    ) -> Result<usize> {
+        let mut out = TrackWriter::new(writer, format);
+        self.stream_playlist_tracks(playlist_id, |page| Ok(out.write_tracks(&page)?))
+            .await?;
+        let written = out.written();
+        out.finish()?;
+        Ok(written)
+    }
+
+    /// Export the "Liked Songs" playlist to `writer`, like
+    /// [`Self::export_playlist`].
+    ///
+    /// Requires authentication. Brand accounts that reject `LM` are exported
+    /// from the ID their library lists, as in [`Self::get_liked_songs`].
+    pub async fn export_liked_songs<W: Write>(
+        &self,
+        format: ExportFormat,
+        writer: W,
+    ) -> Result<usize> {
+        self.check_auth()?;
+        let mut out = TrackWriter::new(writer, format);
+        let streamed = self
+            .stream_playlist_tracks("LM", |page| Ok(out.write_tracks(&page)?))
+            .await;
+        match streamed {
+            // The ID is rejected with the first page, before anything is
+            // written.
+            Err(err) if is_rejected_playlist(&err) => {
+                let summary = self.get_liked_songs_summary().await?;
+                if summary.playlist_id == "LM" {
+                    return Err(err);
+                }
+                self.stream_playlist_tracks(&summary.playlist_id, |page| {
+                    Ok(out.write_tracks(&page)?)
+                })
+                .await?;
+            }
+            result => result?,
+        }
+        let written = out.written();
+        out.finish()?;
+        Ok(written)
     }
 
     /// Create a new playlist.
@@ -339,26 +1834,47 @@ impl YTMusicClient {
         title: &str,
         description: Option<&str>,
         privacy: Privacy,
+    ) -> Result<CreatePlaylistResponse> {
+        let options = CreatePlaylistOptions {
+            title: title.to_string(),
+            description: description.map(str::to_string),
+            privacy,
+        };
+        self.create_playlist_with(&options).await
+    }
+
+    /// Create a new playlist, configured by [`CreatePlaylistOptions`].
+    ///
+    /// Behaves like [`Self::create_playlist`].
+    ///
+    /// ```no_run
+    /// # async fn demo(client: &ytmusicapi::YTMusicClient) -> ytmusicapi::Result<()> {
+    /// use ytmusicapi::{CreatePlaylistOptions, Privacy};
+    ///
+    /// let options = CreatePlaylistOptions::new("Road Trip").privacy(Privacy::Private);
+    /// let created = client.create_playlist_with(&options).await?;
+    /// println!("created {}", created.playlist_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_playlist_with(
+        &self,
+        options: &CreatePlaylistOptions,
     ) -> Result<CreatePlaylistResponse> {
         self.check_auth()?;
-        if title.trim().is_empty() {
+        self.check_channel("playlist creation")?;
+        if options.title.trim().is_empty() {
             return Err(Error::InvalidInput(
                 "title must include at least one character".to_string(),
             ));
         }
 
-        let privacy_status = match privacy {
-            Privacy::Public => "PUBLIC",
-            Privacy::Private => "PRIVATE",
-            Privacy::Unlisted => "UNLISTED",
-        };
-
         let mut body = json!({
-            "title": title,
-            "privacyStatus": privacy_status
+            "title": options.title,
+            "privacyStatus": options.privacy.as_api_str()
         });
 
-        if let Some(desc) = description
+        if let Some(desc) = &options.description
             && !desc.trim().is_empty()
         {
             body["description"] = json!(desc);
@@ -366,410 +1882,3396 @@ impl YTMusicClient {
 
         let response = self.send_request("playlist/create", body).await?;
         let created: CreatePlaylistResponse = serde_json::from_value(response)?;
+        self.audit(
+            MutationKind::CreatePlaylist,
+            vec![created.playlist_id.clone()],
+            MutationOutcome::Succeeded,
+        );
         Ok(created)
     }
 
+    /// Change the title, description or privacy of a playlist.
+    ///
+    /// Requires authentication. Only the fields set in `options` are changed;
+    /// if none is set, or the title is blank, this returns
+    /// [`Error::InvalidInput`]. The server's status is returned rather than
+    /// turned into an error, so check [`EditStatus::is_succeeded`].
+    ///
+    /// ```no_run
+    /// # async fn demo(client: &ytmusicapi::YTMusicClient) -> ytmusicapi::Result<()> {
+    /// use ytmusicapi::{EditPlaylistOptions, Privacy};
+    ///
+    /// let options = EditPlaylistOptions::new().privacy(Privacy::Unlisted);
+    /// let status = client.edit_playlist("PL123", options).await?;
+    /// assert!(status.is_succeeded());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn edit_playlist(
+        &self,
+        playlist_id: &str,
+        options: EditPlaylistOptions,
+    ) -> Result<EditStatus> {
+        self.check_auth()?;
+        let body = edit_playlist_body(playlist_id, &options)?;
+        let response = self.send_request("browse/edit_playlist", body).await?;
+        self.audit_edit(playlist_id, Vec::new(), &response);
+        Ok(EditStatus::from_response(&response))
+    }
+
     /// Delete a playlist.
     ///
     /// Requires authentication. The ID may be provided with or without the `VL` prefix.
     pub async fn delete_playlist(&self, playlist_id: &str) -> Result<()> {
         self.check_auth()?;
+        let playlist_id = validate_playlist_id(playlist_id)?;
 
         let body = json!({
-            "playlistId": validate_playlist_id(playlist_id)?
+            "playlistId": playlist_id
         });
 
         self.send_request("playlist/delete", body).await?;
+        self.audit(
+            MutationKind::DeletePlaylist,
+            vec![playlist_id.to_string()],
+            MutationOutcome::Succeeded,
+        );
         Ok(())
     }
 
-    /// Get song metadata from the `player` endpoint.
+    /// Select unused library items for removal, without removing anything.
     ///
-    /// This does not require authentication and does not return stream URLs.
-    pub async fn get_song(&self, video_id: &str) -> Result<Song> {
-        let response = self
-            .send_request("player", song_request_body(video_id)?)
-            .await?;
-        let song: Song = serde_json::from_value(response)?;
-        Ok(song)
-    }
-
-    /// Rate a song (like/dislike/indifferent).
+    /// Requires authentication. Each enabled threshold of [`PruneOptions`]
+    /// adds one kind of item:
+    ///
+    /// - Empty playlists: library playlists whose entry reports zero tracks
+    ///   are deleted. Auto playlists such as Liked Music are never selected.
+    /// - Artists without songs: subscribed artists missing from
+    ///   [`Self::get_library_artists`] are unsubscribed from.
+    /// - Albums not played since a date: library albums with no track in
+    ///   [`Self::get_history_since`] are removed from the library. A history
+    ///   section spanning the cutoff counts as played, and a history that
+    ///   ends before the cutoff (e.g. a paused one) selects no albums.
+    ///
+    /// Each selected item is reported to the audit hook as
+    /// [`MutationOutcome::Simulated`], with the [`MutationKind`] its removal
+    /// records: [`MutationKind::DeletePlaylist`] for playlists,
+    /// [`MutationKind::Unsubscribe`] for artists and [`MutationKind::Rate`]
+    /// for albums, which are removed by un-liking them with
+    /// [`Self::rate_playlist`]. Pass the plan, possibly filtered, to
+    /// [`Self::execute_prune`] to remove the items.
+    ///
+    /// ```no_run
+    /// # async fn demo(client: &ytmusicapi::YTMusicClient) -> ytmusicapi::Result<()> {
+    /// use ytmusicapi::PruneOptions;
     ///
-    /// Requires authentication. Returns the raw API response.
-    pub async fn rate_song(&self, video_id: &str, rating: LikeStatus) -> Result<Value> {
+    /// let mut plan = client.plan_prune(&PruneOptions::new()).await?;
+    /// plan.candidates.retain(|candidate| !candidate.title.starts_with("Keep"));
+    /// let report = client.execute_prune(&plan).await?;
+    /// println!("removed {} playlists", report.removed.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn plan_prune(&self, options: &PruneOptions) -> Result<PrunePlan> {
         self.check_auth()?;
-        self.send_request(rating.endpoint(), rating_request_body(video_id)?)
-            .await
-    }
 
-    /// Like a song.
-    pub async fn like_song(&self, video_id: &str) -> Result<Value> {
-        self.rate_song(video_id, LikeStatus::Like).await
-    }
+        let mut candidates = Vec::new();
+        if options.empty_playlists {
+            let playlists = self.get_library_playlists(None).await?;
+            candidates.extend(plan_library_prune(&playlists, options));
+        }
+        if options.artists_without_songs {
+            let subscriptions = self.get_library_subscriptions(None).await?;
+            let library_artists = self.get_library_artists(None, None).await?;
+            candidates.extend(plan_artists_without_songs(&subscriptions, &library_artists));
+        }
+        #[cfg(feature = "chrono")]
+        if let Some(cutoff) = options.albums_not_played_since {
+            let albums = self.get_library_albums(None, None).await?;
+            let history = self.get_history_since(cutoff).await?;
+            candidates.extend(plan_albums_not_played(&albums, &history));
+        }
 
-    /// Remove like/dislike from a song.
-    pub async fn unlike_song(&self, video_id: &str) -> Result<Value> {
-        self.rate_song(video_id, LikeStatus::Indifferent).await
+        for candidate in &candidates {
+            let operation = match candidate.reason {
+                PruneReason::EmptyPlaylist => MutationKind::DeletePlaylist,
+                PruneReason::ArtistWithoutSongs => MutationKind::Unsubscribe,
+                PruneReason::AlbumNotPlayed => MutationKind::Rate,
+            };
+            self.audit(
+                operation,
+                vec![candidate.id.clone()],
+                MutationOutcome::Simulated,
+            );
+        }
+        Ok(PrunePlan { candidates })
     }
 
-    /// Add items to a playlist by video ID.
+    /// Remove the items of a plan from [`Self::plan_prune`].
     ///
-    /// Requires authentication. When `allow_duplicates` is `false`, the request
-    /// includes `DEDUPE_OPTION_SKIP`, which instructs the API to skip videos that
-    /// are already present in the playlist.
-    pub async fn add_playlist_items(
+    /// Requires authentication. The items are removed as planned, without
+    /// checking the library again. Removal failures are collected in the
+    /// report rather than aborting the run.
+    pub async fn execute_prune(&self, plan: &PrunePlan) -> Result<PruneReport> {
+        self.check_auth()?;
+
+        let mut report = PruneReport::default();
+        for candidate in &plan.candidates {
+            match self.remove_prune_candidate(candidate).await {
+                Ok(()) => report.removed.push(candidate.id.clone()),
+                Err(err) => report.failed.push((candidate.id.clone(), err.to_string())),
+            }
+        }
+        Ok(report)
+    }
+
+    async fn remove_prune_candidate(&self, candidate: &PruneCandidate) -> Result<()> {
+        match candidate.reason {
+            PruneReason::EmptyPlaylist => self.delete_playlist(&candidate.id).await,
+            PruneReason::ArtistWithoutSongs => {
+                let result = self
+                    .unsubscribe_artists(std::slice::from_ref(&candidate.id))
+                    .await?;
+                if result.is_complete() {
+                    Ok(())
+                } else {
+                    Err(Error::NotApplied(format!(
+                        "unsubscribing from {} was not confirmed",
+                        candidate.id
+                    )))
+                }
+            }
+            PruneReason::AlbumNotPlayed => {
+                // Albums are saved to the library by liking their audio
+                // playlist, so removing one means un-liking it.
+                let album = self.get_album(&candidate.id).await?;
+                let Some(playlist_id) = album.audio_playlist_id else {
+                    return Err(Error::InvalidInput(format!(
+                        "album {} has no playlist to remove from the library",
+                        candidate.id
+                    )));
+                };
+                self.rate_playlist(&playlist_id, LikeStatus::Indifferent)
+                    .await?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Get the songs saved to the library (the library's "Songs" tab).
+    ///
+    /// Requires authentication. Unlike [`Self::get_liked_songs`] this lists
+    /// saved songs rather than rated ones. Continuation pages are followed
+    /// until `limit` songs are held or none remain (`None` for all). `order`
+    /// defaults to the web client's order.
+    pub async fn get_library_songs(
         &self,
-        playlist_id: &str,
-        video_ids: &[String],
-        allow_duplicates: bool,
-    ) -> Result<Value> {
+        limit: Option<u32>,
+        order: Option<LibraryOrder>,
+    ) -> Result<Vec<PlaylistTrack>> {
         self.check_auth()?;
-        self.send_request(
-            "browse/edit_playlist",
-            add_playlist_items_body(playlist_id, video_ids, allow_duplicates)?,
+        let response = self.browse_library("FEmusic_liked_videos", order).await?;
+        let (mut tracks, token) = parse_library_songs(&response);
+        paginate(
+            &mut tracks,
+            token,
+            limit.map_or(usize::MAX, |limit| limit as usize),
+            &LIBRARY_SONG_PAGES,
+            |token| self.fetch_continuation_page(token),
         )
-        .await
+        .await?;
+        Ok(tracks)
     }
 
-    /// Remove items from a playlist using playlist track metadata.
+    /// Get the listening history, most recent first.
     ///
-    /// Requires authentication. Only items with both `video_id` and `set_video_id`
-    /// are removed; if none qualify, this returns [`Error::InvalidInput`].
-    pub async fn remove_playlist_items(
-        &self,
-        playlist_id: &str,
-        items: &[PlaylistTrack],
-    ) -> Result<Value> {
+    /// Requires authentication. Each track is tagged with the header of the
+    /// period it is listed under (e.g. `"Today"`). A paused or empty history
+    /// yields an empty list.
+    pub async fn get_history(&self) -> Result<Vec<HistoryTrack>> {
+        self.check_auth()?;
+        let response = self
+            .send_request("browse", json!({ "browseId": "FEmusic_history" }))
+            .await?;
+        Ok(parse_history(&response))
+    }
+
+    /// Get the listening history from `cutoff` on, most recent first.
+    ///
+    /// Requires authentication. Older periods of the history are loaded page
+    /// by page, and loading stops at the first period that ends before
+    /// `cutoff`, so a recent cutoff costs few requests. Period headers such
+    /// as `"Today"` are resolved against the local clock; periods with a
+    /// header that is not recognised are kept and do not stop loading.
+    ///
+    /// ```no_run
+    /// # async fn demo(client: &ytmusicapi::YTMusicClient) -> ytmusicapi::Result<()> {
+    /// let week_ago = chrono::Local::now().date_naive() - chrono::Days::new(7);
+    /// let history = client.get_history_since(week_ago).await?;
+    /// println!("{} plays this week", history.tracks().count());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub async fn get_history_since(&self, cutoff: NaiveDate) -> Result<HistorySince> {
         self.check_auth()?;
-        self.send_request(
-            "browse/edit_playlist",
-            remove_playlist_items_body(playlist_id, items)?,
+        let today = chrono::Local::now().date_naive();
+        let response = self
+            .send_request("browse", json!({ "browseId": "FEmusic_history" }))
+            .await?;
+        let (first, continuation) = parse_history_buckets(&response);
+
+        let mut history = HistorySince {
+            buckets: Vec::new(),
+            reached_cutoff: false,
+        };
+        for_each_page(
+            first,
+            continuation,
+            &HISTORY_PAGES,
+            |token| self.fetch_continuation_page(token),
+            |mut page| {
+                history.reached_cutoff = truncate_history_at(&mut page, cutoff, today);
+                history.buckets.extend(page);
+                Ok(if history.reached_cutoff {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                })
+            },
+            &mut Vec::new(),
         )
-        .await
+        .await?;
+        Ok(history)
     }
 
-    /// Move items from one playlist to another (add to destination, then remove from source).
+    /// Remove entries from the listening history.
     ///
-    /// Requires authentication. If the add succeeds but the remove fails, the
-    /// destination playlist is not rolled back.
-    pub async fn move_playlist_items(
+    /// Requires authentication. Takes the [`HistoryTrack::feedback_token`]s of
+    /// entries from [`Self::get_history`], sent 50 per request. Returns whether
+    /// each removal was processed, in input order. Returns
+    /// [`Error::InvalidInput`] if `feedback_tokens` is empty.
+    pub async fn remove_history_items(&self, feedback_tokens: &[String]) -> Result<Vec<bool>> {
+        self.check_auth()?;
+        feedback_request_body(feedback_tokens)?;
+
+        let mut processed = Vec::with_capacity(feedback_tokens.len());
+        for chunk in feedback_tokens.chunks(FEEDBACK_CHUNK_SIZE) {
+            let response = self
+                .send_request("feedback", feedback_request_body(chunk)?)
+                .await?;
+            processed.extend(feedback_flags(&response, chunk.len()));
+        }
+
+        let (done, failed): (Vec<_>, Vec<_>) = feedback_tokens
+            .iter()
+            .zip(&processed)
+            .partition(|(_, processed)| **processed);
+        if !done.is_empty() {
+            let outcome = if failed.is_empty() {
+                MutationOutcome::Succeeded
+            } else {
+                MutationOutcome::Partial {
+                    failed: failed.into_iter().map(|(t, _)| t.clone()).collect(),
+                }
+            };
+            let done = done.into_iter().map(|(t, _)| t.clone()).collect();
+            self.audit(MutationKind::Feedback, done, outcome);
+        }
+        Ok(processed)
+    }
+
+    /// Find library tracks whose title or artists fuzzily match `query`.
+    ///
+    /// Requires authentication. Every library playlist, Liked Music included,
+    /// is fetched in full, so this costs one request per playlist. Tracks
+    /// scoring below `threshold` (0.0 to 1.0) are dropped; a score of 1.0
+    /// means every query word was found. Results are best first, and a track
+    /// saved in several playlists is listed once with all its locations.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if `threshold` is outside 0.0 to 1.0.
+    pub async fn search_library_tracks(
         &self,
-        from_playlist_id: &str,
-        to_playlist_id: &str,
-        items: &[PlaylistTrack],
-        allow_duplicates: bool,
-    ) -> Result<MovePlaylistItemsResult> {
+        query: &str,
+        threshold: f32,
+    ) -> Result<Vec<LibraryTrackMatch>> {
         self.check_auth()?;
-        let (video_ids, removable_items) = collect_movable_items(items)?;
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(Error::InvalidInput(format!(
+                "threshold must be between 0.0 and 1.0, got {threshold}"
+            )));
+        }
 
-        let add_response = self
-            .add_playlist_items(to_playlist_id, &video_ids, allow_duplicates)
-            .await?;
-        if !status_succeeded(&add_response) {
-            let status = add_response
-                .get("status")
-                .and_then(|v| v.as_str())
-                .unwrap_or("Unknown status");
-            return Err(Error::Server {
-                status: 500,
-                message: format!("Failed to add items to destination playlist: {}", status),
-            });
+        let mut ids: Vec<String> = self
+            .get_library_playlists(None)
+            .await?
+            .into_iter()
+            .map(|summary| summary.playlist_id)
+            .collect();
+        if !ids.iter().any(|id| id == "LM") {
+            ids.insert(0, "LM".to_string());
         }
+        let mut playlists = Vec::with_capacity(ids.len());
+        for id in &ids {
+            playlists.push(self.get_playlist(id, None).await?);
+        }
+        Ok(find_matches(query, threshold, &playlists))
+    }
 
-        let remove_response = self
-            .remove_playlist_items(from_playlist_id, &removable_items)
+    /// Get the albums saved to the library (the library's "Albums" tab).
+    ///
+    /// Requires authentication. Continuation pages are followed until `limit`
+    /// albums are held or none remain (`None` for all). `order` defaults to
+    /// the web client's order.
+    pub async fn get_library_albums(
+        &self,
+        limit: Option<u32>,
+        order: Option<LibraryOrder>,
+    ) -> Result<Vec<LibraryAlbum>> {
+        self.check_auth()?;
+        let response = self.browse_library("FEmusic_liked_albums", order).await?;
+        let (mut albums, token) = parse_library_albums(&response);
+        paginate(
+            &mut albums,
+            token,
+            limit.map_or(usize::MAX, |limit| limit as usize),
+            &LIBRARY_ALBUM_PAGES,
+            |token| self.fetch_continuation_page(token),
+        )
+        .await?;
+        Ok(albums)
+    }
+
+    /// Get the artists of the library's songs (the library's "Artists" tab).
+    ///
+    /// Requires authentication. Each artist carries its song count rather
+    /// than subscribers; see [`Self::get_library_subscriptions`] for followed
+    /// artists. Continuation pages are followed until `limit` artists are held
+    /// or none remain (`None` for all). `order` defaults to the web client's
+    /// order.
+    pub async fn get_library_artists(
+        &self,
+        limit: Option<u32>,
+        order: Option<LibraryOrder>,
+    ) -> Result<Vec<LibraryArtist>> {
+        self.check_auth()?;
+        let response = self
+            .browse_library("FEmusic_library_corpus_track_artists", order)
             .await?;
+        let (mut artists, token) = parse_library_track_artists(&response);
+        paginate(
+            &mut artists,
+            token,
+            limit.map_or(usize::MAX, |limit| limit as usize),
+            &LIBRARY_TRACK_ARTIST_PAGES,
+            |token| self.fetch_continuation_page(token),
+        )
+        .await?;
+        Ok(artists)
+    }
 
-        Ok(MovePlaylistItemsResult {
-            add_response,
-            remove_response,
-        })
+    /// Get the songs the user uploaded.
+    ///
+    /// Requires authentication. Each track carries the
+    /// [`entity_id`](UploadTrack::entity_id) that deletes it. Continuation
+    /// pages are followed until `limit` songs are held or none remain (`None`
+    /// for all). `order` defaults to the web client's order.
+    pub async fn get_library_upload_songs(
+        &self,
+        limit: Option<u32>,
+        order: Option<LibraryOrder>,
+    ) -> Result<Vec<UploadTrack>> {
+        self.check_auth()?;
+        let response = self
+            .browse_library("FEmusic_library_privately_owned_tracks", order)
+            .await?;
+        let (mut tracks, token) = parse_upload_songs(&response);
+        paginate(
+            &mut tracks,
+            token,
+            limit.map_or(usize::MAX, |limit| limit as usize),
+            &UPLOAD_SONG_PAGES,
+            |token| self.fetch_continuation_page(token),
+        )
+        .await?;
+        Ok(tracks)
     }
 
-    /// Fetch additional tracks via continuation token.
-    async fn fetch_playlist_continuations(
+    /// Get the albums of the user's uploads.
+    ///
+    /// Requires authentication. Continuation pages are followed until `limit`
+    /// albums are held or none remain (`None` for all). `order` defaults to
+    /// the web client's order.
+    pub async fn get_library_upload_albums(
         &self,
-        initial_token: &str,
-        max_items: usize,
-    ) -> Result<Vec<PlaylistTrack>> {
-        let mut all_tracks = Vec::new();
-        let mut token = Some(initial_token.to_string());
+        limit: Option<u32>,
+        order: Option<LibraryOrder>,
+    ) -> Result<Vec<UploadAlbum>> {
+        self.check_auth()?;
+        let response = self
+            .browse_library("FEmusic_library_privately_owned_releases", order)
+            .await?;
+        let (mut albums, token) = parse_upload_albums(&response);
+        paginate(
+            &mut albums,
+            token,
+            limit.map_or(usize::MAX, |limit| limit as usize),
+            &UPLOAD_ALBUM_PAGES,
+            |token| self.fetch_continuation_page(token),
+        )
+        .await?;
+        Ok(albums)
+    }
 
-        while let Some(current_token) = token {
-            if all_tracks.len() >= max_items {
-                break;
-            }
+    /// Get the artists of the user's uploads.
+    ///
+    /// Requires authentication. Each artist carries the number of uploaded
+    /// songs by them. Continuation pages are followed until `limit` artists
+    /// are held or none remain (`None` for all). `order` defaults to the web
+    /// client's order.
+    pub async fn get_library_upload_artists(
+        &self,
+        limit: Option<u32>,
+        order: Option<LibraryOrder>,
+    ) -> Result<Vec<UploadArtist>> {
+        self.check_auth()?;
+        let response = self
+            .browse_library("FEmusic_library_privately_owned_artists", order)
+            .await?;
+        let (mut artists, token) = parse_upload_artists(&response);
+        paginate(
+            &mut artists,
+            token,
+            limit.map_or(usize::MAX, |limit| limit as usize),
+            &UPLOAD_ARTIST_PAGES,
+            |token| self.fetch_continuation_page(token),
+        )
+        .await?;
+        Ok(artists)
+    }
 
-            let body = json!({
-                "continuation": current_token
-            });
+    /// Upload a song file to the user's library.
+    ///
+    /// Requires authentication. The file must be an mp3, m4a, wma, flac or
+    /// ogg file smaller than 300 MB; other files are refused with
+    /// [`Error::InvalidInput`] before anything is sent. The file is read into
+    /// memory and sent in one request. Uploads are processed after the
+    /// server accepts them, so they can take a while to appear in
+    /// [`get_library_upload_songs`](Self::get_library_upload_songs).
+    ///
+    /// Uploads are not served from a fixture directory: with one set, the
+    /// file is checked and [`Error::Unsupported`] is returned.
+    pub async fn upload_song(&self, path: &Path) -> Result<UploadStatus> {
+        self.check_auth()?;
+        let size = tokio::fs::metadata(path).await?.len();
+        let file_name = upload_file_name(path, size)?;
+        if self.fixtures.is_some() {
+            return Err(Error::Unsupported(
+                "uploads cannot be answered from a fixture directory".to_string(),
+            ));
+        }
+        let content = tokio::fs::read(path).await?;
+        let authuser = self
+            .auth()
+            .as_ref()
+            .map_or_else(|| "0".to_string(), |auth| auth.x_goog_authuser.clone());
 
-            let response = self.send_request("browse", body).await?;
+        let start = self
+            .with_auth_headers(
+                self.http
+                    .post(format!("{YTM_UPLOAD_URL}?authuser={authuser}"))
+                    .form(&[("filename", file_name)]),
+            )?
+            .header("x-goog-upload-command", "start")
+            .header("x-goog-upload-header-content-length", content.len())
+            .header("x-goog-upload-protocol", "resumable");
+        self.wait_for_turn().await;
+        let response = start.send().await?;
+        let url = upload_url(&response)?;
 
-            // Parse continuation response
-            let continuation_items = nav(
-                &response,
-                &path![
-                    "continuationContents",
-                    "musicPlaylistShelfContinuation",
-                    "contents"
-                ],
+        let finish = self
+            .with_auth_headers(self.http.post(url).body(content))?
+            .header(
+                CONTENT_TYPE,
+                "application/x-www-form-urlencoded;charset=utf-8",
             )
-            .or_else(|| {
-                nav(
-                    &response,
-                    &path![
-                        "onResponseReceivedActions",
-                        0,
-                        "appendContinuationItemsAction",
-                        "continuationItems"
-                    ],
-                )
-            });
+            .header("x-goog-upload-command", "upload, finalize")
+            .header("x-goog-upload-offset", "0");
+        self.wait_for_turn().await;
+        let response = finish.send().await?;
+        let status = if response.status().is_success() {
+            UploadStatus::Succeeded
+        } else {
+            UploadStatus::Failed {
+                status: response.status().as_u16(),
+                message: response.text().await.unwrap_or_default(),
+            }
+        };
+        if status == UploadStatus::Succeeded {
+            self.audit(
+                MutationKind::Upload,
+                vec![file_name.to_string()],
+                MutationOutcome::Succeeded,
+            );
+        }
+        Ok(status)
+    }
 
-            if let Some(Value::Array(items)) = continuation_items {
-                let tracks = parse_playlist_tracks(items);
-                if tracks.is_empty() {
-                    break;
-                }
-                all_tracks.extend(tracks);
-
-                // Check for next continuation
-                let next_token = items.last().and_then(|last| {
-                    nav(
-                        last,
-                        &path![
-                            "continuationItemRenderer",
-                            "continuationEndpoint",
-                            "continuationCommand",
-                            "token"
-                        ],
-                    )
-                    .and_then(|v| v.as_str())
-                    .map(|s| s.to_string())
-                });
+    /// Delete an uploaded song or album.
+    ///
+    /// Requires authentication. Accepts the
+    /// [`entity_id`](UploadTrack::entity_id) of a song or album, or the
+    /// [`browse_id`](UploadAlbum::browse_id) of an album. Deleting an album
+    /// deletes its songs. Errors reported by the server are returned as
+    /// [`Error::Server`], so the status is [`EditStatus::Succeeded`] unless the
+    /// response carries a status of its own.
+    pub async fn delete_upload_entity(&self, entity_id: &str) -> Result<EditStatus> {
+        self.check_auth()?;
+        let entity_id = validate_id("entity_id", entity_id)?;
+        let entity_id = validate_id(
+            "entity_id",
+            entity_id
+                .strip_prefix(UPLOAD_ALBUM_PREFIX)
+                .unwrap_or(entity_id),
+        )?;
+        let response = self
+            .send_request(
+                "music/delete_privately_owned_entity",
+                json!({ "entityId": entity_id }),
+            )
+            .await?;
+        let status = match response.get("status") {
+            Some(_) => EditStatus::from_response(&response),
+            None => EditStatus::Succeeded,
+        };
+        if status.is_succeeded() {
+            self.audit(
+                MutationKind::DeleteUpload,
+                vec![entity_id.to_string()],
+                MutationOutcome::Succeeded,
+            );
+        }
+        Ok(status)
+    }
 
-                token = next_token;
-            } else {
-                break;
-            }
+    /// Get the artists the user is subscribed to.
+    ///
+    /// Requires authentication. Continuation pages are followed until `limit`
+    /// artists are held or none remain (`None` for all).
+    pub async fn get_library_subscriptions(
+        &self,
+        limit: Option<u32>,
+    ) -> Result<Vec<LibraryArtist>> {
+        self.check_auth()?;
+        let response = self
+            .send_request(
+                "browse",
+                json!({ "browseId": "FEmusic_library_corpus_artists" }),
+            )
+            .await?;
+        let (mut artists, token) = parse_library_artists(&response);
+        paginate(
+            &mut artists,
+            token,
+            limit.map_or(usize::MAX, |limit| limit as usize),
+            &LIBRARY_ARTIST_PAGES,
+            |token| self.fetch_continuation_page(token),
+        )
+        .await?;
+        Ok(artists)
+    }
+
+    /// Get the name, channel handle and avatar of the authenticated account.
+    ///
+    /// Useful to confirm which account a set of credentials belongs to.
+    /// Requires authentication. Returns
+    /// [`Error::Navigation`](crate::Error::Navigation) if the account menu
+    /// layout has changed.
+    pub async fn get_account_info(&self) -> Result<AccountInfo> {
+        self.check_auth()?;
+        let response = self.send_request("account/account_menu", json!({})).await?;
+        parse_account_info(&response)
+    }
+
+    /// Check whether the configured cookies are still accepted.
+    ///
+    /// Sends one account menu request, paced like any other. A rejected
+    /// request (401 or 403), a menu without a signed-in account, or an
+    /// account challenge yields [`AuthState::Expired`]. Returns
+    /// [`Error::AuthRequired`](crate::Error::AuthRequired) without
+    /// authentication, and passes on errors that say nothing about the
+    /// cookies, such as network failures.
+    pub async fn probe_auth(&self) -> Result<AuthState> {
+        self.check_auth()?;
+        match self.send_request("account/account_menu", json!({})).await {
+            Ok(response) => Ok(match parse_account_info(&response) {
+                Ok(_) => AuthState::Valid,
+                Err(_) => AuthState::Expired,
+            }),
+            Err(Error::Server {
+                status: 401 | 403, ..
+            })
+            | Err(Error::AccountChallenge { .. }) => Ok(AuthState::Expired),
+            Err(error) => Err(error),
         }
+    }
 
-        all_tracks.truncate(max_items);
-        Ok(all_tracks)
+    /// Get what the authenticated account can do.
+    ///
+    /// Requires authentication. The result is read from the account menu and
+    /// cached for the lifetime of the client; use
+    /// [`refresh_capabilities`](Self::refresh_capabilities) after the account
+    /// changes. Once cached, methods such as
+    /// [`create_playlist`](Self::create_playlist) fail early with
+    /// [`Error::Unsupported`](crate::Error::Unsupported) when the account lacks
+    /// what they need.
+    pub async fn get_capabilities(&self) -> Result<Capabilities> {
+        match self.cached_capabilities() {
+            Some(capabilities) => Ok(capabilities),
+            None => self.refresh_capabilities().await,
+        }
     }
 
-    /// Send a request to the YouTube Music API.
+    /// Re-read the account capabilities, replacing the cached value.
     ///
-    /// This is a low-level helper that merges a client context into `body`,
-    /// performs a `POST`, and returns the raw JSON response.
+    /// Requires authentication.
+    pub async fn refresh_capabilities(&self) -> Result<Capabilities> {
+        self.check_auth()?;
+        let response = self.send_request("account/account_menu", json!({})).await?;
+        let capabilities = parse_capabilities(&response);
+        if let Ok(mut cached) = self.capabilities.lock() {
+            *cached = Some(capabilities.clone());
+        }
+        Ok(capabilities)
+    }
+
+    /// Get song metadata from the `player` endpoint.
     ///
-    /// Error behavior:
-    /// - Surfaces network failures as [`Error::Http`](crate::Error::Http).
-    /// - Surfaces non-2xx responses or error payloads as [`Error::Server`](crate::Error::Server).
-    /// - Surfaces JSON decode failures as [`Error::Json`](crate::Error::Json).
+    /// This does not require authentication and does not return stream URLs.
+    /// [`Song::external_ids`] is filled when the response carries an ISRC.
+    /// A track the player refuses still returns its metadata, with
+    /// [`Song::unplayable`] telling why (age restriction, Premium, region).
+    pub async fn get_song(&self, video_id: &str) -> Result<Song> {
+        let response = self
+            .send_request("player", song_request_body(video_id)?)
+            .await?;
+        Ok(parse_song(&response, &self.language)?)
+    }
+
+    /// Get the up-next queue of a song or playlist from the `next` endpoint.
     ///
-    /// This crate does not configure timeouts, retries, or polling; any timeout
-    /// behavior comes from the underlying HTTP client defaults.
-    pub async fn send_request(&self, endpoint: &str, mut body: Value) -> Result<Value> {
-        // Merge context into body
-        let context = create_context(
-            &self.language,
-            self.location.as_deref(),
-            self.user.as_deref(),
-        );
-        if let Value::Object(ref mut map) = body
-            && let Value::Object(ctx) = context
-        {
-            for (k, v) in ctx {
-                map.insert(k, v);
+    /// Pass a `video_id`, a `playlist_id`, or both to start the playlist at
+    /// that song. With only a song, its radio (`RDAMVM...`) is queued. Set
+    /// `radio` for a radio based on the song or playlist instead of the
+    /// playlist itself; radios are endless, so keep `limit` small. `limit`
+    /// defaults to 25 tracks and more are loaded through continuations.
+    ///
+    /// Also returns the browse IDs of the current track's lyrics and related
+    /// content, which are only available here.
+    ///
+    /// Returns [`Error::InvalidInput`] if neither ID is given.
+    pub async fn get_watch_playlist(
+        &self,
+        video_id: Option<&str>,
+        playlist_id: Option<&str>,
+        radio: bool,
+        limit: Option<u32>,
+    ) -> Result<WatchPlaylist> {
+        let body = watch_request_body(video_id, playlist_id, radio)?;
+        let response = self.send_request("next", body.clone()).await?;
+        let (mut playlist, continuation) = parse_watch_playlist(&response);
+        paginate(
+            &mut playlist.tracks,
+            continuation,
+            limit.map_or(25, |limit| limit as usize),
+            &WATCH_PAGES,
+            |token| {
+                let mut body = body.clone();
+                body["continuation"] = json!(token);
+                self.send_request("next", body)
+            },
+        )
+        .await?;
+        Ok(playlist)
+    }
+
+    /// Get the songs, artists, albums and playlists related to a track,
+    /// behind a [`WatchPlaylist::related_browse_id`].
+    ///
+    /// Sections come in page order, each labelled with its heading as shown
+    /// in the client language (e.g. "You might also like", "Recommended
+    /// playlists"). The "About the artist" section has no items and carries
+    /// its text in [`RelatedSection::description`].
+    pub async fn get_song_related(&self, related_browse_id: &str) -> Result<Vec<RelatedSection>> {
+        let browse_id = validate_id("related_browse_id", related_browse_id)?;
+        let response = self
+            .send_request("browse", json!({ "browseId": browse_id }))
+            .await?;
+        Ok(parse_song_related(&response))
+    }
+
+    /// Get the signed-in account's [`LikeStatus`] of many tracks at once, e.g.
+    /// to show which tracks of a playlist are liked.
+    ///
+    /// Requires authentication. Reads the queue items of the
+    /// `music/get_queue` endpoint, which the player uses to fill its queue
+    /// and which carry each track's like button. IDs are sent 50 per
+    /// request, so a 300-track playlist takes six requests; repeated IDs are
+    /// sent once. IDs the response leaves out, such as deleted or
+    /// region-blocked videos, are missing from the map rather than an error.
+    pub async fn get_like_statuses(
+        &self,
+        video_ids: &[String],
+    ) -> Result<HashMap<String, LikeStatus>> {
+        self.check_auth()?;
+        let mut unique = Vec::with_capacity(video_ids.len());
+        let mut seen = HashSet::new();
+        for video_id in video_ids {
+            let video_id = validate_video_id(video_id)?;
+            if seen.insert(video_id) {
+                unique.push(video_id);
             }
         }
 
-        // Build URL
-        let params = if self.auth.is_some() {
-            format!("{}{}", YTM_PARAMS, YTM_PARAMS_KEY)
-        } else {
-            YTM_PARAMS.to_string()
-        };
-        let url = format!("{}{}{}", YTM_BASE_API, endpoint, params);
+        let mut statuses = HashMap::with_capacity(unique.len());
+        for chunk in unique.chunks(LIKE_STATUS_CHUNK_SIZE) {
+            let response = self
+                .send_request("music/get_queue", json!({ "videoIds": chunk }))
+                .await?;
+            statuses.extend(
+                parse_queue_like_statuses(&response)
+                    .into_iter()
+                    .filter(|(video_id, _)| seen.contains(video_id.as_str())),
+            );
+        }
+        Ok(statuses)
+    }
 
-        // Build request
-        let mut request = self.http.post(&url).json(&body);
+    /// Get the lyrics behind a [`WatchPlaylist::lyrics_browse_id`].
+    ///
+    /// Returns `Ok(None)` when the track has no lyrics, which is common;
+    /// [`WatchPlaylist::lyrics_browse_id`] is usually already `None` then.
+    pub async fn get_lyrics(&self, lyrics_browse_id: &str) -> Result<Option<Lyrics>> {
+        let browse_id = validate_id("lyrics_browse_id", lyrics_browse_id)?;
+        let response = self
+            .send_request("browse", json!({ "browseId": browse_id }))
+            .await?;
+        Ok(parse_lyrics(&response))
+    }
 
-        // Add auth headers if authenticated
-        if let Some(ref auth) = self.auth {
-            // Combine user cookies with required SOCS cookie
-            let combined_cookie = format!("{}; SOCS=CAI", auth.cookie);
-            request = request
-                .header("authorization", auth.get_authorization()?)
-                .header("cookie", combined_cookie)
-                .header("x-goog-authuser", &auth.x_goog_authuser);
+    /// Get line-timed lyrics behind a [`WatchPlaylist::lyrics_browse_id`],
+    /// for karaoke-style display.
+    ///
+    /// Timed lyrics are only served to the mobile apps, so this request is
+    /// sent as the Android client. When the provider has no timing for the
+    /// track, the plain lyrics are returned instead as
+    /// [`TimedLyrics::Plain`]. Returns `Ok(None)` when the track has no
+    /// lyrics at all.
+    pub async fn get_timed_lyrics(&self, lyrics_browse_id: &str) -> Result<Option<TimedLyrics>> {
+        let browse_id = validate_id("lyrics_browse_id", lyrics_browse_id)?;
+        let response = self
+            .send_request_as(
+                "browse",
+                json!({ "browseId": browse_id }),
+                ClientProfile::AndroidMusic,
+            )
+            .await?;
+        Ok(parse_timed_lyrics(&response))
+    }
+
+    /// Record a play of `song` in the listening history.
+    ///
+    /// Requires authentication. Reports the play the way the web player does,
+    /// through the song's [`PlaybackTracking`](crate::PlaybackTracking) URL, so the song must come from
+    /// [`Self::get_song`] with the same account. Returns
+    /// [`Error::InvalidInput`] if the song has no playback URL or it does not
+    /// point to YouTube.
+    ///
+    /// With a fixture directory the report is not sent, and the audit hook
+    /// sees the play as [`MutationOutcome::Simulated`].
+    pub async fn add_history_item(&self, song: &Song) -> Result<()> {
+        self.check_auth()?;
+        let mut url = playback_url(song)?;
+        url.query_pairs_mut()
+            .append_pair("ver", "2")
+            .append_pair("c", "WEB_REMIX")
+            .append_pair("cpn", &client_playback_nonce());
+
+        // Playback reports have an empty response, so there is nothing to
+        // replay from fixtures.
+        let outcome = if self.fixtures.is_none() {
+            self.report_playback(url).await?;
+            MutationOutcome::Succeeded
         } else {
-            // Add only SOCS cookie for unauthenticated requests
-            request = request.header("cookie", "SOCS=CAI");
-        }
+            MutationOutcome::Simulated
+        };
+        self.audit(
+            MutationKind::AddHistory,
+            vec![song.video_details.video_id.clone()],
+            outcome,
+        );
+        Ok(())
+    }
 
+    async fn report_playback(&self, url: reqwest::Url) -> Result<()> {
+        let request = self.with_auth_headers(self.http.get(url))?;
+        self.wait_for_turn().await;
         let response = request.send().await?;
-
         if !response.status().is_success() {
             let status = response.status().as_u16();
-            let text = response.text().await.unwrap_or_default();
+            let served_by = response
+                .headers()
+                .get(reqwest::header::SERVER)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
             return Err(Error::Server {
                 status,
-                message: text,
+                message: response.text().await.unwrap_or_default(),
+                meta: server_error_meta(&Value::Null, served_by),
             });
         }
+        Ok(())
+    }
+
+    /// Get an artist's channel page.
+    ///
+    /// Accepts the channel ID from [`Artist::id`](crate::Artist::id), with or
+    /// without the `MPLA` prefix used by library artists. Does not require
+    /// authentication; when authenticated, [`ArtistPage::subscribed`] reflects
+    /// the account. Each section's `browse_id` and `params` lead to its full
+    /// listing.
+    pub async fn get_artist(&self, channel_id: &str) -> Result<ArtistPage> {
+        let channel_id = artist_browse_id(channel_id)?;
+        let response = self
+            .send_request("browse", json!({ "browseId": channel_id }))
+            .await?;
+        Ok(parse_artist_page(&response, channel_id, &self.language))
+    }
+
+    /// Get an album, single or EP with its track listing.
+    ///
+    /// Accepts the `MPREb_...` browse ID from [`Album::id`](crate::Album::id)
+    /// or [`ArtistRelease::browse_id`](crate::ArtistRelease::browse_id). Does
+    /// not require authentication; when authenticated, each track's
+    /// [`AlbumTrack::like_status`](crate::AlbumTrack::like_status) reflects the account. Play the album
+    /// through [`AlbumPage::audio_playlist_id`].
+    pub async fn get_album(&self, browse_id: &str) -> Result<AlbumPage> {
+        let browse_id = validate_id("browse_id", browse_id)?;
+        let response = self
+            .send_request("browse", json!({ "browseId": browse_id }))
+            .await?;
+        Ok(parse_album_page(&response, browse_id, &self.language))
+    }
+
+    /// Get an artist's full album or singles listing.
+    ///
+    /// Pass the `browse_id` and `params` of [`ArtistPage::albums`] or
+    /// [`ArtistPage::singles`] from [`Self::get_artist`]; the channel ID is
+    /// accepted in place of the browse ID. Continuation pages are followed
+    /// until `limit` releases are held or none remain.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example(client: ytmusicapi::YTMusicClient) -> ytmusicapi::Result<()> {
+    /// let artist = client.get_artist("UCabc").await?;
+    /// if let Some(albums) = &artist.albums
+    ///     && let (Some(browse_id), Some(params)) = (&albums.browse_id, &albums.params)
+    /// {
+    ///     for album in client.get_artist_albums(browse_id, params, None).await? {
+    ///         println!("{} ({:?})", album.title, album.year);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_artist_albums(
+        &self,
+        channel_id: &str,
+        params: &str,
+        limit: Option<u32>,
+    ) -> Result<Vec<ArtistRelease>> {
+        let browse_id = artist_releases_browse_id(channel_id)?;
+        let params = validate_id("params", params)?;
+        let response = self
+            .send_request("browse", json!({ "browseId": browse_id, "params": params }))
+            .await?;
+        let (mut releases, token) = parse_artist_releases(&response);
+        paginate(
+            &mut releases,
+            token,
+            limit.map_or(usize::MAX, |limit| limit as usize),
+            &ARTIST_RELEASE_PAGES,
+            |token| self.fetch_continuation_page(token),
+        )
+        .await?;
+        Ok(releases)
+    }
+
+    /// Subscribe to artists.
+    ///
+    /// Requires authentication. Accepts channel IDs with or without the
+    /// `MPLA` prefix of library artists. The result lists which channels the
+    /// response confirmed as subscribed.
+    pub async fn subscribe_artists(&self, channel_ids: &[String]) -> Result<SubscriptionResult> {
+        self.change_subscriptions(channel_ids, true).await
+    }
+
+    /// Unsubscribe from artists.
+    ///
+    /// Requires authentication. Accepts the same IDs as
+    /// [`Self::subscribe_artists`]; the result lists which channels the
+    /// response confirmed as unsubscribed.
+    pub async fn unsubscribe_artists(&self, channel_ids: &[String]) -> Result<SubscriptionResult> {
+        self.change_subscriptions(channel_ids, false).await
+    }
+
+    async fn change_subscriptions(
+        &self,
+        channel_ids: &[String],
+        subscribe: bool,
+    ) -> Result<SubscriptionResult> {
+        self.check_auth()?;
+        let channel_ids = subscription_channel_ids(channel_ids)?;
+        let endpoint = if subscribe {
+            "subscription/subscribe"
+        } else {
+            "subscription/unsubscribe"
+        };
+        let response = self
+            .send_request(endpoint, json!({ "channelIds": channel_ids }))
+            .await?;
+        let result = parse_subscription_result(&response, &channel_ids, subscribe);
+        if !result.succeeded.is_empty() {
+            let outcome = if result.is_complete() {
+                MutationOutcome::Succeeded
+            } else {
+                MutationOutcome::Partial {
+                    failed: result.failed.clone(),
+                }
+            };
+            let operation = if subscribe {
+                MutationKind::Subscribe
+            } else {
+                MutationKind::Unsubscribe
+            };
+            self.audit(operation, result.succeeded.clone(), outcome);
+        }
+        Ok(result)
+    }
+
+    /// Get a user's channel page.
+    ///
+    /// Accepts a channel ID such as [`Author::id`](crate::Author::id) of a
+    /// public playlist. Does not require authentication.
+    pub async fn get_user(&self, channel_id: &str) -> Result<UserPage> {
+        let channel_id = validate_id("channel_id", channel_id)?;
+        let response = self
+            .send_request("browse", json!({ "browseId": channel_id }))
+            .await?;
+        Ok(parse_user_page(&response, channel_id))
+    }
+
+    /// Get all public playlists of a user.
+    ///
+    /// Pass the `browse_id` and `params` of [`UserPage::playlists`] from
+    /// [`Self::get_user`]. Continuation pages are followed until none remain.
+    /// A channel without public playlists yields an empty list.
+    pub async fn get_user_playlists(
+        &self,
+        channel_id: &str,
+        params: &str,
+    ) -> Result<Vec<PlaylistSummary>> {
+        let channel_id = validate_id("channel_id", channel_id)?;
+        let params = validate_id("params", params)?;
+        let response = self
+            .send_request(
+                "browse",
+                json!({ "browseId": channel_id, "params": params }),
+            )
+            .await?;
+        let (mut playlists, token) = parse_user_playlists(&response);
+        paginate(
+            &mut playlists,
+            token,
+            usize::MAX,
+            &PLAYLIST_GRID_PAGES,
+            |token| self.fetch_continuation_page(token),
+        )
+        .await?;
+        Ok(playlists)
+    }
+
+    /// Get the carousels of the home feed, such as "Listen again", "Quick
+    /// picks" and "Mixed for you".
+    ///
+    /// With authentication the feed is personalized, and personal mixes such
+    /// as "My Supermix" appear only here; without it the generic feed is
+    /// returned. Without a `limit`, only the sections of the first response
+    /// are read; with one, continuation pages are followed until `limit`
+    /// sections are held or none remain.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn demo(client: &ytmusicapi::YTMusicClient) -> ytmusicapi::Result<()> {
+    /// use ytmusicapi::HomeItem;
+    ///
+    /// for section in client.get_home(Some(10)).await? {
+    ///     for item in &section.contents {
+    ///         if let HomeItem::Playlist(mix) = item {
+    ///             println!("{}: {} ({})", section.title, mix.title, mix.playlist_id);
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_home(&self, limit: Option<u32>) -> Result<Vec<HomeSection>> {
+        let response = self
+            .send_request("browse", json!({ "browseId": "FEmusic_home" }))
+            .await?;
+        let (mut sections, token) = parse_home(&response);
+        let Some(limit) = limit else {
+            return Ok(sections);
+        };
+        paginate(&mut sections, token, limit as usize, &HOME_PAGES, |token| {
+            self.fetch_continuation_page(token)
+        })
+        .await?;
+        Ok(sections)
+    }
+
+    /// Get the mood and genre categories of the "Moods & genres" page,
+    /// grouped by section ("Moods & moments", "Genres", ...).
+    ///
+    /// Does not require authentication. Pass a category's
+    /// [`MoodCategory::params`](crate::MoodCategory::params) to [`Self::get_mood_playlists`].
+    pub async fn get_mood_categories(&self) -> Result<Vec<MoodSection>> {
+        let response = self
+            .send_request("browse", json!({ "browseId": "FEmusic_moods_and_genres" }))
+            .await?;
+        Ok(parse_mood_categories(&response))
+    }
+
+    /// Get the playlists of a mood or genre category, from the
+    /// [`MoodCategory::params`](crate::MoodCategory::params) of [`Self::get_mood_categories`].
+    ///
+    /// Does not require authentication. Grid continuations are followed, so
+    /// every playlist of the category is returned. Editorial playlists
+    /// without a channel link are credited to the name in their subtitle
+    /// (e.g. "YouTube Music • 80 songs"), with no channel ID.
+    pub async fn get_mood_playlists(&self, params: &str) -> Result<Vec<PlaylistSummary>> {
+        let params = validate_id("params", params)?;
+        let response = self
+            .send_request(
+                "browse",
+                json!({ "browseId": "FEmusic_moods_and_genres_category", "params": params }),
+            )
+            .await?;
+        let (mut playlists, token) = parse_mood_playlists(&response);
+        paginate(
+            &mut playlists,
+            token,
+            usize::MAX,
+            &MOOD_PLAYLIST_PAGES,
+            |token| self.fetch_continuation_page(token),
+        )
+        .await?;
+        Ok(playlists)
+    }
+
+    /// Get the charts of a country, or the global charts for `None` or
+    /// `"ZZ"`.
+    ///
+    /// Does not require authentication. `country` is an ISO 3166-1 alpha-2
+    /// code such as `"NL"`, as for [`YTMusicClientBuilder::with_location`];
+    /// [`Charts::countries`] lists the codes the page offers. Returns
+    /// [`Error::InvalidInput`] if `country` is not a two-letter code.
+    pub async fn get_charts(&self, country: Option<&str>) -> Result<Charts> {
+        let mut body = json!({ "browseId": "FEmusic_charts" });
+        if let Some(country) = country {
+            let country = country.trim();
+            if !is_country_code(country) {
+                return Err(Error::InvalidInput(format!(
+                    "country must be a two-letter code such as \"US\", got {country:?}"
+                )));
+            }
+            body["formData"] = json!({ "selectedValues": [country.to_ascii_uppercase()] });
+        }
+        let response = self.send_request("browse", body).await?;
+        Ok(parse_charts(&response))
+    }
+
+    /// Get newly released albums and singles.
+    ///
+    /// Does not require authentication. Releases are returned as
+    /// [`LibraryAlbum`]s, as from [`Self::get_library_albums`]; the page
+    /// rarely shows a year. Continuation pages are followed until `limit`
+    /// releases are held or none remain (`None` for all).
+    pub async fn get_new_releases(&self, limit: Option<u32>) -> Result<Vec<LibraryAlbum>> {
+        let response = self
+            .send_request(
+                "browse",
+                json!({ "browseId": "FEmusic_new_releases_albums" }),
+            )
+            .await?;
+        // The releases grid has the layout of the library's albums grid.
+        let (mut albums, token) = parse_library_albums(&response);
+        paginate(
+            &mut albums,
+            token,
+            limit.map_or(usize::MAX, |limit| limit as usize),
+            &LIBRARY_ALBUM_PAGES,
+            |token| self.fetch_continuation_page(token),
+        )
+        .await?;
+        Ok(albums)
+    }
+
+    /// Search YouTube Music.
+    ///
+    /// Works with or without authentication. Without a `limit`, only the first
+    /// page of results is read; with one, continuation pages are followed until
+    /// `limit` results are held or none remain. The returned
+    /// [`SearchResults::continuation`] resumes from where this call stopped.
+    /// Correction suggestions and the top-result card are not included.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example(client: ytmusicapi::YTMusicClient) -> ytmusicapi::Result<()> {
+    /// let results = client.search("daft punk around the world", Some(5)).await?;
+    /// if let Some(video_id) = results.first().and_then(|r| r.video_id.clone()) {
+    ///     client
+    ///         .add_playlist_items("PLAYLIST_ID", &[video_id], ytmusicapi::DuplicateHandling::Skip)
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search(&self, query: &str, limit: Option<u32>) -> Result<SearchResults> {
+        self.run_search(search_request_body(query, None, None)?, None, limit)
+            .await
+    }
+
+    /// Search YouTube Music for one kind of result.
+    ///
+    /// Works with or without authentication. Rows the parser cannot classify
+    /// are reported as the filter's [`SearchFilter::result_type`].
+    pub async fn search_filtered(
+        &self,
+        query: &str,
+        filter: SearchFilter,
+        limit: Option<u32>,
+    ) -> Result<SearchResults> {
+        self.run_search(
+            search_request_body(query, Some(filter), None)?,
+            Some(filter),
+            limit,
+        )
+        .await
+    }
+
+    /// Search within the signed-in user's library or uploads.
+    ///
+    /// Requires authentication. Library results use a different column layout
+    /// (no view counts, sometimes no album), so fields the row does not carry
+    /// are left empty.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example(client: ytmusicapi::YTMusicClient) -> ytmusicapi::Result<()> {
+    /// use ytmusicapi::SearchScope;
+    ///
+    /// for result in client.search_scoped("bohemian", SearchScope::Library, None).await? {
+    ///     println!("{}", result.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_scoped(
+        &self,
+        query: &str,
+        scope: SearchScope,
+        limit: Option<u32>,
+    ) -> Result<SearchResults> {
+        self.check_auth()?;
+        self.run_search(search_request_body(query, None, Some(scope))?, None, limit)
+            .await
+    }
+
+    /// Continue a search from [`SearchResults::continuation`].
+    ///
+    /// Follows continuation pages until `limit` results are held or none
+    /// remain; without a `limit`, reads a single page.
+    pub async fn search_continuation(
+        &self,
+        token: &str,
+        limit: Option<u32>,
+    ) -> Result<SearchResults> {
+        let Some(limit) = limit else {
+            let response = self.fetch_search_continuation(token.to_string()).await?;
+            let (rows, continuation) = continuation_rows(&response);
+            return Ok(SearchResults {
+                results: parse_search_rows(rows),
+                continuation,
+            });
+        };
+
+        let mut results = Vec::new();
+        let continuation = paginate(
+            &mut results,
+            Some(token.to_string()),
+            limit as usize,
+            &SEARCH_RESULT_PAGES,
+            |token| self.fetch_search_continuation(token),
+        )
+        .await?;
+        Ok(SearchResults {
+            results,
+            continuation,
+        })
+    }
+
+    /// Get query suggestions for partially typed search input.
+    ///
+    /// Works with or without authentication; when signed in, previous
+    /// searches are suggested too. See
+    /// [`Self::get_search_suggestions_detailed`] to tell those apart.
+    pub async fn get_search_suggestions(&self, query: &str) -> Result<Vec<String>> {
+        Ok(self
+            .get_search_suggestions_detailed(query)
+            .await?
+            .into_iter()
+            .map(|suggestion| suggestion.text)
+            .collect())
+    }
+
+    /// Get query suggestions with their history flag and the feedback token
+    /// that removes a previous search from the user's suggestions.
+    pub async fn get_search_suggestions_detailed(
+        &self,
+        query: &str,
+    ) -> Result<Vec<SearchSuggestion>> {
+        let response = self
+            .send_request("music/get_search_suggestions", json!({ "input": query }))
+            .await?;
+        Ok(parse_search_suggestions(&response))
+    }
+
+    /// Remove previous searches from the user's suggestions.
+    ///
+    /// Requires authentication. Takes the
+    /// [`SearchSuggestion::feedback_token`]s of history suggestions from
+    /// [`Self::get_search_suggestions_detailed`]. Returns whether every
+    /// removal was processed.
+    pub async fn remove_search_suggestions(&self, tokens: &[String]) -> Result<bool> {
+        self.check_auth()?;
+        let response = self
+            .send_request("feedback", feedback_request_body(tokens)?)
+            .await?;
+        let processed = feedback_processed(&response);
+        if processed {
+            self.audit(
+                MutationKind::Feedback,
+                tokens.to_vec(),
+                MutationOutcome::Succeeded,
+            );
+        }
+        Ok(processed)
+    }
+
+    async fn run_search(
+        &self,
+        body: Value,
+        filter: Option<SearchFilter>,
+        limit: Option<u32>,
+    ) -> Result<SearchResults> {
+        let response = self.send_request("search", body).await?;
+        let (mut results, token) = parse_search_results(&response);
+        let continuation = match limit {
+            Some(limit) => {
+                paginate(
+                    &mut results,
+                    token,
+                    limit as usize,
+                    &SEARCH_RESULT_PAGES,
+                    |token| self.fetch_search_continuation(token),
+                )
+                .await?
+            }
+            None => token,
+        };
+        if let Some(filter) = filter {
+            for result in &mut results {
+                if result.result_type == SearchResultType::Other {
+                    result.result_type = filter.result_type();
+                }
+            }
+        }
+        Ok(SearchResults {
+            results,
+            continuation,
+        })
+    }
+
+    async fn fetch_search_continuation(&self, token: String) -> Result<Value> {
+        self.send_request("search", json!({ "continuation": token }))
+            .await
+    }
+
+    /// Rate a song (like/dislike/indifferent).
+    ///
+    /// Requires authentication. The response carries the confirmation text
+    /// the web client would show, if any.
+    pub async fn rate_song(&self, video_id: &str, rating: LikeStatus) -> Result<RateSongResponse> {
+        self.check_auth()?;
+        let response = self
+            .send_request(rating.endpoint(), rating_request_body(video_id)?)
+            .await?;
+        self.audit(
+            MutationKind::Rate,
+            vec![video_id.trim().to_string()],
+            MutationOutcome::Succeeded,
+        );
+        Ok(parse_rate_response(response, rating))
+    }
+
+    /// Like a song.
+    pub async fn like_song(&self, video_id: &str) -> Result<RateSongResponse> {
+        self.rate_song(video_id, LikeStatus::Like).await
+    }
+
+    /// Remove like/dislike from a song.
+    pub async fn unlike_song(&self, video_id: &str) -> Result<RateSongResponse> {
+        self.rate_song(video_id, LikeStatus::Indifferent).await
+    }
+
+    /// Like or unlike a playlist.
+    ///
+    /// Requires authentication. Liking a playlist saves it to the library;
+    /// [`LikeStatus::Indifferent`] removes it again. A `VL` prefix or playlist
+    /// URL is accepted. Playlists cannot be disliked, so
+    /// [`LikeStatus::Dislike`] is rejected with [`Error::InvalidInput`].
+    /// Returns the raw API response.
+    pub async fn rate_playlist(&self, playlist_id: &str, rating: LikeStatus) -> Result<Value> {
+        self.check_auth()?;
+        let body = playlist_rating_request_body(playlist_id, rating)?;
+        let response = self.send_request(rating.endpoint(), body).await?;
+        self.audit(
+            MutationKind::Rate,
+            vec![validate_playlist_id(playlist_id)?.to_string()],
+            MutationOutcome::Succeeded,
+        );
+        Ok(response)
+    }
+
+    /// Add items to a playlist by video ID.
+    ///
+    /// Requires authentication. `duplicates` controls how videos already in the
+    /// playlist are treated; see [`DuplicateHandling`]. Skipped videos are listed
+    /// in [`AddPlaylistItemsResult::skipped`], or returned as
+    /// [`Error::Duplicates`] with [`DuplicateHandling::Fail`]; that error
+    /// still carries the result, since the other videos were added. A `bool` is still
+    /// accepted for compatibility (`true` allows duplicates, `false` skips them).
+    ///
+    /// One add action is sent per video, in input order, and the server appends
+    /// them to the end of the playlist in that order. The result lists the
+    /// set video ID of each new entry, so the items can be removed or moved
+    /// without fetching the playlist, and the videos the server skipped.
+    pub async fn add_playlist_items(
+        &self,
+        playlist_id: &str,
+        video_ids: &[String],
+        duplicates: impl Into<DuplicateHandling>,
+    ) -> Result<AddPlaylistItemsResult> {
+        self.check_auth()?;
+        let duplicates = duplicates.into();
+        let response = self
+            .send_request(
+                "browse/edit_playlist",
+                add_playlist_items_body(playlist_id, video_ids, duplicates)?,
+            )
+            .await?;
+        self.audit_edit(playlist_id, video_ids.to_vec(), &response);
+        let mut result = AddPlaylistItemsResult::from_response(response, video_ids);
+        if self.verify_edits && result.is_succeeded() {
+            // Prefer the new items' set video IDs, which are not masked by
+            // copies already in the playlist.
+            let mut added = result.set_video_ids();
+            if added.is_empty() {
+                added = video_ids.to_vec();
+            }
+            result.unverified = self.verify_playlist_items(playlist_id, added, true).await?;
+        }
+        check_duplicates(result, duplicates)
+    }
+
+    /// Append every item of `source_playlist_id` to a playlist in one request.
+    ///
+    /// Requires authentication. Both IDs may be given with or without the `VL`
+    /// prefix or as playlist URLs; if they name the same playlist, this
+    /// returns [`Error::InvalidInput`]. Items are added in source order and
+    /// duplicates are not checked. Check [`EditStatus::is_succeeded`] on the
+    /// result.
+    pub async fn add_playlist_to_playlist(
+        &self,
+        playlist_id: &str,
+        source_playlist_id: &str,
+    ) -> Result<EditStatus> {
+        self.check_auth()?;
+        let body = add_playlist_to_playlist_body(playlist_id, source_playlist_id)?;
+        let response = self.send_request("browse/edit_playlist", body).await?;
+        let source = validate_playlist_id(source_playlist_id)?.to_string();
+        self.audit_edit(playlist_id, vec![source], &response);
+        Ok(EditStatus::from_response(&response))
+    }
+
+    /// Add tracks to a playlist.
+    ///
+    /// Accepts any [`TrackLike`] type. Tracks without a video ID are skipped; if
+    /// none remain, this returns [`Error::InvalidInput`]. See
+    /// [`add_playlist_items`](Self::add_playlist_items) for `duplicates`.
+    pub async fn add_playlist_tracks<T: TrackLike>(
+        &self,
+        playlist_id: &str,
+        tracks: &[T],
+        duplicates: impl Into<DuplicateHandling>,
+    ) -> Result<AddPlaylistItemsResult> {
+        self.add_playlist_items(playlist_id, &track_video_ids(tracks), duplicates)
+            .await
+    }
+
+    /// Add the tracks passing `filter` to a playlist.
+    ///
+    /// Requires authentication. Like
+    /// [`add_playlist_tracks`](Self::add_playlist_tracks), after dropping the
+    /// tracks `filter` rejects; [`AddPlaylistItemsResult::filtered`] counts
+    /// them. If no track with a video ID remains, this returns
+    /// [`Error::InvalidInput`] without sending a request.
+    pub async fn add_playlist_tracks_filtered<T: TrackLike>(
+        &self,
+        playlist_id: &str,
+        tracks: &[T],
+        duplicates: impl Into<DuplicateHandling>,
+        filter: &TrackFilter,
+    ) -> Result<AddPlaylistItemsResult> {
+        let (kept, filtered) = filter.apply(tracks);
+        match self
+            .add_playlist_items(playlist_id, &track_video_ids(&kept), duplicates)
+            .await
+        {
+            Ok(mut result) => {
+                result.filtered = filtered;
+                Ok(result)
+            }
+            Err(Error::Duplicates { mut result }) => {
+                result.filtered = filtered;
+                Err(Error::Duplicates { result })
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Remove items from a playlist using playlist track metadata.
+    ///
+    /// Requires authentication. Only items with both `video_id` and `set_video_id`
+    /// are removed; if none qualify, this returns [`Error::InvalidInput`].
+    pub async fn remove_playlist_items(
+        &self,
+        playlist_id: &str,
+        items: &[PlaylistTrack],
+    ) -> Result<EditPlaylistResponse> {
+        self.check_auth()?;
+        let response = self
+            .send_request(
+                "browse/edit_playlist",
+                remove_playlist_items_body(playlist_id, items)?,
+            )
+            .await?;
+        let removed = items
+            .iter()
+            .filter_map(playlist_item_ids)
+            .map(|(_, video_id)| video_id.to_string())
+            .collect();
+        self.audit_edit(playlist_id, removed, &response);
+        let mut response = EditPlaylistResponse::from_response(response);
+        if self.verify_edits && response.is_succeeded() {
+            let removed = items
+                .iter()
+                .filter_map(playlist_item_ids)
+                .map(|(set_video_id, _)| set_video_id.to_string())
+                .collect();
+            response.unverified = self
+                .verify_playlist_items(playlist_id, removed, false)
+                .await?;
+        }
+        Ok(response)
+    }
+
+    /// Remove the items at positions `range` (0-based, end exclusive) from a
+    /// playlist.
+    ///
+    /// Requires authentication. Only as many pages as the range needs are
+    /// fetched. Nothing is removed if the range is empty, extends past the end
+    /// of the playlist, or holds an item that cannot be removed; these return
+    /// [`Error::InvalidInput`]. Items are removed in chunks of 100; if a chunk
+    /// fails, earlier chunks stay removed.
+    ///
+    /// Returns the removed items; pass them to [`UndoToken::for_removed`] to
+    /// be able to add them back.
+    pub async fn remove_playlist_range(
+        &self,
+        playlist_id: &str,
+        range: Range<usize>,
+    ) -> Result<Vec<PlaylistTrack>> {
+        self.check_auth()?;
+        if range.is_empty() {
+            return take_playlist_range(Vec::new(), &range);
+        }
+        let limit = u32::try_from(range.end).unwrap_or(u32::MAX);
+        let playlist = self.get_playlist(playlist_id, Some(limit)).await?;
+        let items = take_playlist_range(playlist.tracks, &range)?;
+
+        for chunk in items.chunks(REMOVE_CHUNK_SIZE) {
+            let response = self.remove_playlist_items(playlist_id, chunk).await?;
+            if !response.is_succeeded() {
+                return Err(Error::Server {
+                    status: 500,
+                    message: format!(
+                        "Failed to remove playlist items: {}",
+                        status_label(&response.status)
+                    ),
+                    meta: server_error_meta(&response.raw, None),
+                });
+            }
+        }
+        Ok(items)
+    }
+
+    /// Apply an [`UndoToken`], newest step first.
+    ///
+    /// Requires authentication. Best effort: a step that fails is recorded in
+    /// the report and the remaining steps still run. Restored items are
+    /// appended to the end of their playlist, so their original positions are
+    /// only approximated; the report flags this.
+    pub async fn undo(&self, token: &UndoToken) -> Result<UndoReport> {
+        self.check_auth()?;
+        let mut report = UndoReport::default();
+        for step in token.steps.iter().rev() {
+            let (outcome, items) = match step {
+                UndoStep::RemoveItems { playlist_id, items } => (
+                    self.remove_playlist_items(playlist_id, items)
+                        .await
+                        .map(|response| response.status),
+                    items,
+                ),
+                UndoStep::RestoreItems { playlist_id, items } => (
+                    self.add_playlist_tracks(playlist_id, items, DuplicateHandling::Allow)
+                        .await
+                        .map(|result| result.status),
+                    items,
+                ),
+            };
+            let error = match outcome {
+                Ok(status) if status.is_succeeded() => None,
+                Ok(status) => Some(status_label(&status).to_string()),
+                Err(err) => Some(err.to_string()),
+            };
+            if let Some(error) = error {
+                report.failed.push((step.clone(), error));
+                continue;
+            }
+            let video_ids = items.iter().filter_map(|item| item.video_id.clone());
+            match step {
+                UndoStep::RemoveItems { .. } => report.removed.extend(video_ids),
+                UndoStep::RestoreItems { .. } => {
+                    report.restored.extend(video_ids);
+                    report.positions_approximate = true;
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Set the note shown for a single playlist item.
+    ///
+    /// Requires authentication. Per-item notes are only rolled out to some
+    /// accounts and playlists; when the backend does not know the edit
+    /// action this returns [`Error::Unsupported`] so callers can
+    /// feature-detect. Other rejections are returned as usual: a stale
+    /// `set_video_id` as [`Error::Server`] or a response whose
+    /// [`status`](EditPlaylistResponse::status) is not
+    /// [`Succeeded`](EditStatus::Succeeded). An empty `note` clears the
+    /// existing note.
+    pub async fn set_playlist_item_note(
+        &self,
+        playlist_id: &str,
+        set_video_id: &str,
+        note: &str,
+    ) -> Result<EditPlaylistResponse> {
+        self.check_auth()?;
+        let body = playlist_item_note_body(playlist_id, set_video_id, note)?;
+        let response = classify_rollout_action(
+            "playlist item notes",
+            "ACTION_SET_VIDEO_NOTE",
+            self.send_request("browse/edit_playlist", body).await,
+        )?;
+        self.audit_edit(playlist_id, vec![set_video_id.to_string()], &response.raw);
+        Ok(response)
+    }
+
+    /// Move items from one playlist to another (add to destination, then remove from source).
+    ///
+    /// Requires authentication. If the add succeeds but the remove fails, the
+    /// destination playlist is not rolled back.
+    pub async fn move_playlist_items(
+        &self,
+        from_playlist_id: &str,
+        to_playlist_id: &str,
+        items: &[PlaylistTrack],
+        duplicates: impl Into<DuplicateHandling>,
+    ) -> Result<MovePlaylistItemsResult> {
+        self.check_auth()?;
+        let (video_ids, removable_items) = collect_movable_items(items)?;
+
+        let added = self
+            .add_playlist_items(to_playlist_id, &video_ids, duplicates)
+            .await?;
+        if !added.is_succeeded() {
+            return Err(Error::Server {
+                status: 500,
+                message: format!(
+                    "Failed to add items to destination playlist: {}",
+                    status_label(&added.status)
+                ),
+                meta: server_error_meta(&added.raw, None),
+            });
+        }
+
+        let removed = self
+            .remove_playlist_items(from_playlist_id, &removable_items)
+            .await?;
+
+        Ok(MovePlaylistItemsResult { added, removed })
+    }
+
+    /// Move one item within a playlist.
+    ///
+    /// Requires authentication. The item identified by `set_video_id` moves
+    /// directly before the item `move_before_set_video_id`, or to the end of
+    /// the playlist if that is `None`. Set video IDs are the per-item
+    /// [`PlaylistTrack::set_video_id`] values.
+    pub async fn move_item_within_playlist(
+        &self,
+        playlist_id: &str,
+        set_video_id: &str,
+        move_before_set_video_id: Option<&str>,
+    ) -> Result<EditStatus> {
+        self.check_auth()?;
+        let playlist_id = validate_playlist_id(playlist_id)?;
+        let set_video_id = validate_id("set_video_id", set_video_id)?;
+        let before = move_before_set_video_id
+            .map(|before| validate_id("move_before_set_video_id", before))
+            .transpose()?;
+        if before == Some(set_video_id) {
+            return Err(Error::InvalidInput(
+                "cannot move an item before itself".to_string(),
+            ));
+        }
+
+        let body = json!({
+            "playlistId": playlist_id,
+            "actions": [move_item_action(set_video_id, before)]
+        });
+        let response = self.send_request("browse/edit_playlist", body).await?;
+        self.audit_edit(playlist_id, vec![set_video_id.to_string()], &response);
+        Ok(EditStatus::from_response(&response))
+    }
+
+    /// Reorder a playlist to match `ordered_set_video_ids`.
+    ///
+    /// Requires authentication. The list must hold every item's
+    /// [`PlaylistTrack::set_video_id`] exactly once, or this returns
+    /// [`Error::InvalidInput`]. The playlist is fetched to find its current
+    /// order, then the fewest moves reaching the new order are sent, up to
+    /// 100 per request. Returns the status of the last request, or
+    /// [`EditStatus::Succeeded`] if nothing had to move; if a request is not
+    /// applied, its status is returned and earlier requests stay applied.
+    pub async fn reorder_playlist(
+        &self,
+        playlist_id: &str,
+        ordered_set_video_ids: &[String],
+    ) -> Result<EditStatus> {
+        self.check_auth()?;
+        let playlist_id = validate_playlist_id(playlist_id)?;
+        let playlist = self.get_playlist(playlist_id, None).await?;
+        let current: Vec<String> = playlist
+            .tracks
+            .into_iter()
+            .filter_map(|track| track.set_video_id)
+            .collect();
+        let actions = reorder_actions(&current, ordered_set_video_ids)?;
+
+        let mut status = EditStatus::Succeeded;
+        for chunk in actions.chunks(MOVE_CHUNK_SIZE) {
+            let body = json!({ "playlistId": playlist_id, "actions": chunk });
+            let response = self.send_request("browse/edit_playlist", body).await?;
+            let moved = chunk
+                .iter()
+                .filter_map(|action| action["setVideoId"].as_str().map(str::to_string))
+                .collect();
+            self.audit_edit(playlist_id, moved, &response);
+            status = EditStatus::from_response(&response);
+            if !status.is_succeeded() {
+                break;
+            }
+        }
+        Ok(status)
+    }
+
+    /// Fetch a playlist and check that the `touched` items are present (or
+    /// absent) after an edit. IDs may be set video IDs or video IDs.
+    ///
+    /// Only the first [`VERIFY_TRACK_LIMIT`] tracks are read; touched items
+    /// that may lie beyond them are returned unchecked.
+    async fn verify_playlist_items(
+        &self,
+        playlist_id: &str,
+        touched: Vec<String>,
+        present: bool,
+    ) -> Result<Vec<String>> {
+        let playlist = self
+            .get_playlist(playlist_id, Some(VERIFY_TRACK_LIMIT))
+            .await?;
+        let complete = playlist.tracks.len() < VERIFY_TRACK_LIMIT as usize;
+        check_playlist_items(&playlist.tracks, touched, present, complete)
+    }
+
+    /// Browse a library tab, sorted by `order` if given.
+    async fn browse_library(&self, browse_id: &str, order: Option<LibraryOrder>) -> Result<Value> {
+        let mut body = json!({ "browseId": browse_id });
+        if let Some(order) = order {
+            body["params"] = json!(order.params());
+        }
+        self.send_request("browse", body).await
+    }
+
+    /// Fetch the page after a `browse` response, given its continuation token.
+    ///
+    /// Returns the raw response; read it with
+    /// [`continuation::extract_items`](crate::continuation::extract_items) and
+    /// [`continuation::extract_next_token`](crate::continuation::extract_next_token).
+    /// This works for any browse endpoint, including ones the crate does not
+    /// parse. Search results page through the `search` endpoint instead; use
+    /// [`search_continuation`](Self::search_continuation) for those.
+    pub async fn fetch_continuation(&self, token: &str) -> Result<Value> {
+        let token = validate_id("token", token)?;
+        self.fetch_continuation_page(token.to_string()).await
+    }
+
+    /// Request one continuation page.
+    async fn fetch_continuation_page(&self, token: String) -> Result<Value> {
+        let body = json!({
+            "continuation": token
+        });
+        self.send_request("browse", body).await
+    }
+
+    /// Send a request to the YouTube Music API.
+    ///
+    /// This is a low-level helper that merges a client context into `body`,
+    /// performs a `POST`, and returns the raw JSON response.
+    ///
+    /// Error behavior:
+    /// - Surfaces network failures as [`Error::Http`](crate::Error::Http).
+    /// - Surfaces non-2xx responses or error payloads as [`Error::Server`](crate::Error::Server),
+    ///   with any trace identifiers from the response attached as [`ResponseMeta`].
+    /// - Surfaces non-JSON bodies (e.g. HTML consent pages) as
+    ///   [`Error::UnexpectedContent`](crate::Error::UnexpectedContent).
+    /// - Surfaces JSON decode failures as [`Error::Json`](crate::Error::Json).
+    ///
+    /// Retries and waits:
+    /// - A request whose client version the server rejects is retried once
+    ///   with the previous day's version; if that is rejected too,
+    ///   [`Error::ClientVersionRejected`](crate::Error::ClientVersionRejected)
+    ///   is returned. Nothing else is retried.
+    /// - With a rate limiter installed
+    ///   ([`YTMusicClientBuilder::with_rate_limiter`]), each attempt first
+    ///   waits for its turn.
+    ///
+    /// No timeouts are configured; any timeout behavior comes from the
+    /// underlying HTTP client defaults.
+    pub async fn send_request(&self, endpoint: &str, body: Value) -> Result<Value> {
+        self.send_request_as(endpoint, body, ClientProfile::WebRemix)
+            .await
+    }
+
+    /// [`Self::send_request`] claiming to be the `profile` client.
+    ///
+    /// A web request whose client version is rejected is retried once with
+    /// the previous day's version, which the server has had longer to accept.
+    async fn send_request_as(
+        &self,
+        endpoint: &str,
+        mut body: Value,
+        profile: ClientProfile,
+    ) -> Result<Value> {
+        // Merge context into body
+        let context = create_context(
+            &self.language,
+            self.location.as_deref(),
+            self.user.as_deref(),
+            profile,
+        );
+        if let Value::Object(ref mut map) = body
+            && let Value::Object(ctx) = context
+        {
+            for (k, v) in ctx {
+                map.insert(k, v);
+            }
+        }
+
+        // Build URL
+        let params = if self.is_authenticated() {
+            format!("{}{}", YTM_PARAMS, YTM_PARAMS_KEY)
+        } else {
+            YTM_PARAMS.to_string()
+        };
+        let url = format!("{}{}{}", YTM_BASE_API, endpoint, params);
+
+        match self.post(endpoint, &url, &body).await {
+            Err(Error::ClientVersionRejected { .. }) if profile == ClientProfile::WebRemix => {
+                let yesterday = SystemTime::now() - Duration::from_secs(86_400);
+                body["context"]["client"]["clientVersion"] = json!(client_version(yesterday));
+                self.post(endpoint, &url, &body).await
+            }
+            result => result,
+        }
+    }
+
+    /// Post a request body with its context and read the JSON response.
+    async fn post(&self, endpoint: &str, url: &str, body: &Value) -> Result<Value> {
+        if let Some(fixtures) = &self.fixtures {
+            let text = fixtures.respond(endpoint, body)?;
+            return read_response(body, Some("application/json"), &text, None);
+        }
+
+        let request = self.with_auth_headers(self.http.post(url).json(body))?;
+        self.wait_for_turn().await;
+        let response = request.send().await?;
+        let served_by = response
+            .headers()
+            .get(reqwest::header::SERVER)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let clock_offset = response
+            .headers()
+            .get(reqwest::header::DATE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|date| server_offset(date, unix_now()));
+        if self.clock_correction
+            && let Some(offset) = clock_offset
+        {
+            self.clock_offset.store(offset, Ordering::Relaxed);
+        }
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let text = response.text().await.unwrap_or_default();
+            let response_body = serde_json::from_str(&text).unwrap_or(Value::Null);
+            if rejects_client_version(&response_body) {
+                return Err(version_rejected(body));
+            }
+            let body = response_body;
+            let skew = if status == 401 && self.is_authenticated() {
+                clock_offset.and_then(significant_skew)
+            } else {
+                None
+            };
+            return Err(unauthorized_or_server_error(
+                status, text, &body, served_by, skew,
+            ));
+        }
+
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let text = response.text().await?;
+        read_response(body, content_type.as_deref(), &text, served_by)
+    }
+
+    /// Add the auth headers if authenticated, the consent cookie, and the
+    /// language header.
+    fn with_auth_headers(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::RequestBuilder> {
+        let request = request.header(ACCEPT_LANGUAGE, accept_language(&self.language));
+        Ok(match &*self.auth() {
+            // Combine user cookies with required SOCS cookie
+            Some(auth) => {
+                let cookie = if self.language_override {
+                    override_pref_language(&auth.cookie, &self.language)
+                } else {
+                    auth.cookie.clone()
+                };
+                request
+                    .header(
+                        "authorization",
+                        auth.get_authorization_at(self.auth_timestamp())?,
+                    )
+                    .header("cookie", format!("{cookie}; SOCS=CAI"))
+                    .header("x-goog-authuser", &auth.x_goog_authuser)
+            }
+            // Add only SOCS cookie for unauthenticated requests
+            None => request.header("cookie", "SOCS=CAI"),
+        })
+    }
+
+    /// Unix time for `SAPISIDHASH` headers, corrected by the server offset
+    /// when clock correction is on.
+    fn auth_timestamp(&self) -> u64 {
+        let offset = if self.clock_correction {
+            self.clock_offset.load(Ordering::Relaxed)
+        } else {
+            0
+        };
+        (unix_now() + offset).max(0) as u64
+    }
+
+    /// The current credentials. Hold the guard only while reading them.
+    fn auth(&self) -> RwLockReadGuard<'_, Option<BrowserAuth>> {
+        self.auth.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Wait for the rate limiter, if one is installed.
+    async fn wait_for_turn(&self) {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire().await;
+        }
+    }
+
+    /// Pass a mutation to the audit hook, if one is installed.
+    fn audit(&self, operation: MutationKind, target_ids: Vec<String>, outcome: MutationOutcome) {
+        if let Some(hook) = &self.audit_hook {
+            hook(MutationRecord {
+                timestamp: SystemTime::now(),
+                operation,
+                target_ids,
+                outcome,
+            });
+        }
+    }
+
+    /// Audit a `browse/edit_playlist` response if the server applied it.
+    fn audit_edit(&self, playlist_id: &str, video_ids: Vec<String>, response: &Value) {
+        if EditStatus::from_response(response).is_succeeded() {
+            let playlist_id = validate_playlist_id(playlist_id).unwrap_or(playlist_id);
+            let mut target_ids = vec![playlist_id.to_string()];
+            target_ids.extend(video_ids);
+            self.audit(
+                MutationKind::EditPlaylist,
+                target_ids,
+                MutationOutcome::Succeeded,
+            );
+        }
+    }
+
+    /// Check that the client is authenticated, returning an error if not.
+    /// Fail early if cached capabilities show the account has no channel.
+    ///
+    /// Does not fetch capabilities; call [`Self::get_capabilities`] first to
+    /// enable the check.
+    fn check_channel(&self, action: &str) -> Result<()> {
+        match self.cached_capabilities() {
+            Some(capabilities) if !capabilities.has_channel => Err(Error::Unsupported(format!(
+                "this account has no channel; {action} will fail"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    fn cached_capabilities(&self) -> Option<Capabilities> {
+        self.capabilities.lock().ok()?.clone()
+    }
+
+    /// Endpoint and body of every request sent to the fixture directory.
+    #[cfg(test)]
+    pub(crate) fn fixture_requests(&self) -> Vec<(String, Value)> {
+        self.fixtures
+            .as_ref()
+            .map(FixtureTransport::sent)
+            .unwrap_or_default()
+    }
+
+    fn check_auth(&self) -> Result<()> {
+        if !self.is_authenticated() {
+            Err(Error::AuthRequired)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl YTMusicClientBuilder {
+    /// Set browser authentication.
+    pub fn with_browser_auth(mut self, auth: BrowserAuth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Set the language for responses.
+    ///
+    /// This maps to the `hl` client parameter (default: `"en"`), which the API
+    /// localizes responses by and which takes precedence over the account's
+    /// language setting. It is also sent as the `accept-language` header,
+    /// with English as the fallback, for the responses localized by that.
+    /// Parsers read header labels such as track counts, and the "Added"
+    /// labels of playlist rows, in this language. Browser cookies may still
+    /// carry a different language preference; see
+    /// [`with_response_language_override`](Self::with_response_language_override).
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = language.into();
+        self
+    }
+
+    /// Force every response into `language`, whatever the account or browser
+    /// prefers.
+    ///
+    /// Like [`with_language`](Self::with_language), and additionally rewrites
+    /// the language of the `PREF` cookie copied from the browser, so the same
+    /// playlist yields the same labels for every account. Use this when parsed
+    /// text must be normalized to one language.
+    pub fn with_response_language_override(mut self, language: impl Into<String>) -> Self {
+        self.language = language.into();
+        self.language_override = true;
+        self
+    }
+
+    /// Set the location for results.
+    ///
+    /// This maps to the `gl` client parameter and expects ISO 3166-1 alpha-2
+    /// country codes (e.g., `"US"`, `"GB"`, `"DE"`).
+    pub fn with_location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Set a user ID for brand account requests.
+    ///
+    /// This maps to `onBehalfOfUser` in the request context.
+    pub fn with_user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Report every successful mutation to `hook`.
+    ///
+    /// Playlist creation, deletion and edits, ratings, feedback and
+    /// subscription changes each produce a [`MutationRecord`] built from the
+    /// typed outcome; dry runs such as
+    /// [`YTMusicClient::plan_prune`] and plays recorded with a fixture
+    /// directory report as [`MutationOutcome::Simulated`]. The hook runs inline, so forward
+    /// records to a channel rather than doing slow work in it.
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    /// use ytmusicapi::YTMusicClient;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let tx = std::sync::Mutex::new(tx);
+    /// let client = YTMusicClient::builder()
+    ///     .with_audit_hook(move |record| {
+    ///         let _ = tx.lock().unwrap().send(record);
+    ///     })
+    ///     .build()?;
+    /// # drop((client, rx));
+    /// # Ok::<(), ytmusicapi::Error>(())
+    /// ```
+    pub fn with_audit_hook(
+        mut self,
+        hook: impl Fn(MutationRecord) + Send + Sync + 'static,
+    ) -> Self {
+        self.audit_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Pace requests with `limiter`, which may be shared with other clients.
+    ///
+    /// Every request to the API waits for its turn first. See
+    /// [`RateLimiter`].
+    pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Check that playlist edits took effect (off by default).
+    ///
+    /// After [`YTMusicClient::add_playlist_items`] or
+    /// [`YTMusicClient::remove_playlist_items`] (and the methods built on
+    /// them) succeed, the playlist is fetched again to confirm the items were
+    /// added or removed; if not, the call returns
+    /// [`Error::VerificationFailed`]. This costs one request per 100 items in
+    /// the playlist, so a playlist of up to 100 items takes one extra request.
+    /// At most the first 1,000 items are re-read (10 requests). Touched items
+    /// that may lie beyond them, such as items added to a longer playlist,
+    /// are listed in the result's `unverified` field instead of checked.
+    pub fn with_edit_verification(mut self, verify: bool) -> Self {
+        self.verify_edits = verify;
+        self
+    }
+
+    /// Compute `SAPISIDHASH` headers from the server's time (off by default).
+    ///
+    /// Google rejects authenticated requests with a 401 when the local clock
+    /// is a few minutes off. With correction on, the offset to the server is
+    /// read from the `Date` header of each response and applied to the
+    /// timestamps of later requests, so only the first request after starting
+    /// with a skewed clock can fail. Prefer fixing the system time; use this
+    /// where that is not possible.
+    pub fn with_clock_correction(mut self, correct: bool) -> Self {
+        self.clock_correction = correct;
+        self
+    }
+
+    /// Answer requests from recorded responses in `dir` instead of the
+    /// network, for tests and for running the examples offline.
+    ///
+    /// `dir` holds one JSON response body per request at
+    /// `<endpoint>/<key>.json`, where `<endpoint>` is the API endpoint (such
+    /// as `browse` or `next`) and `<key>` the request's continuation token,
+    /// browse ID, video ID, playlist ID or search query, whichever comes
+    /// first, with characters other than ASCII letters, digits, `-` and `_`
+    /// replaced by `_`. Requests without a recorded response for their key
+    /// get `<endpoint>/default.json`; a request with neither fails with
+    /// [`Error::Io`] naming the expected file.
+    ///
+    /// Responses are served as if with status 200, so error payloads in them
+    /// surface as they would from the server. Playback reports made by
+    /// [`YTMusicClient::add_history_item`] are skipped and audited as
+    /// [`MutationOutcome::Simulated`], and
+    /// [`YTMusicClient::upload_song`] returns [`Error::Unsupported`].
+    pub fn with_fixture_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.fixtures = Some(FixtureTransport::new(dir));
+        self
+    }
+
+    /// Send requests through `http` instead of a new connection pool.
+    ///
+    /// `http` must carry the [`default_headers`].
+    #[cfg(feature = "pool")]
+    pub(crate) fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    /// Build the client.
+    ///
+    /// Options are checked together, so an [`Error::Config`] lists every
+    /// invalid one. This does not validate authentication credentials.
+    pub fn build(self) -> Result<YTMusicClient> {
+        let errors = self.validate();
+        if !errors.is_empty() {
+            return Err(Error::Config(errors));
+        }
+
+        let http = match self.http {
+            Some(http) => http,
+            None => http_client()?,
+        };
+
+        Ok(YTMusicClient {
+            http,
+            auth: RwLock::new(self.auth),
+            language: self.language,
+            location: self.location,
+            user: self.user,
+            capabilities: Mutex::new(None),
+            audit_hook: self.audit_hook,
+            rate_limiter: self.rate_limiter,
+            verify_edits: self.verify_edits,
+            language_override: self.language_override,
+            clock_correction: self.clock_correction,
+            clock_offset: AtomicI64::new(0),
+            fixtures: self.fixtures,
+        })
+    }
+
+    /// Collect every invalid option.
+    pub(crate) fn validate(&self) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+        let mut check = |option, valid: bool, problem: &str| {
+            if !valid {
+                errors.push(ConfigError {
+                    option,
+                    problem: problem.to_string(),
+                });
+            }
+        };
+
+        check(
+            "language",
+            is_language_tag(&self.language),
+            "expected a language code such as \"en\" or \"pt-BR\"",
+        );
+        if let Some(location) = &self.location {
+            check(
+                "location",
+                is_country_code(location),
+                "expected an ISO 3166-1 alpha-2 country code such as \"US\"",
+            );
+        }
+        if let Some(user) = &self.user {
+            check(
+                "user",
+                !user.is_empty() && !user.contains(char::is_whitespace),
+                "expected a brand account ID without whitespace",
+            );
+        }
+        errors
+    }
+}
+
+/// Whether `code` is shaped like an ISO 3166-1 alpha-2 country code.
+fn is_country_code(code: &str) -> bool {
+    code.len() == 2 && code.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+/// Whether `tag` looks like a language code: a 2-3 letter primary subtag with
+/// optional alphanumeric subtags, e.g. `"en"`, `"pt-BR"`, `"zh-Hant-TW"`.
+fn is_language_tag(tag: &str) -> bool {
+    let mut subtags = tag.split('-');
+    let primary = subtags.next().unwrap_or_default();
+    (2..=3).contains(&primary.len())
+        && primary.bytes().all(|b| b.is_ascii_alphabetic())
+        && subtags
+            .all(|s| (1..=8).contains(&s.len()) && s.bytes().all(|b| b.is_ascii_alphanumeric()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{ScratchDir, fixture_builder, fixture_client, test_auth};
+    use crate::types::{PlaybackTracking, TrackingUrl};
+
+    fn track(video_id: Option<&str>, set_video_id: Option<&str>) -> PlaylistTrack {
+        PlaylistTrack {
+            video_id: video_id.map(String::from),
+            set_video_id: set_video_id.map(String::from),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn build_reports_every_invalid_option() {
+        let Err(Error::Config(errors)) = YTMusicClient::builder()
+            .with_language("english please")
+            .with_location("USA")
+            .with_user("")
+            .build()
+        else {
+            panic!("expected a configuration error");
+        };
+        let options: Vec<_> = errors.iter().map(|e| e.option).collect();
+        assert_eq!(options, ["language", "location", "user"]);
+
+        assert!(
+            YTMusicClient::builder()
+                .with_language("pt-BR")
+                .with_location("br")
+                .build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn audit_edit_reports_applied_edits_only() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&records);
+        let client = YTMusicClient::builder()
+            .with_audit_hook(move |record| sink.lock().unwrap().push(record))
+            .build()
+            .unwrap();
+
+        let ids = vec!["vid".to_string()];
+        client.audit_edit(
+            "VLPLx",
+            ids.clone(),
+            &json!({ "status": "STATUS_SUCCEEDED" }),
+        );
+        client.audit_edit("PLx", ids, &json!({ "status": "STATUS_FAILED" }));
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].operation, MutationKind::EditPlaylist);
+        assert_eq!(records[0].target_ids, ["PLx", "vid"]);
+        assert_eq!(records[0].outcome, MutationOutcome::Succeeded);
+    }
+
+    #[test]
+    fn account_challenge_needs_a_bare_challenge_command() {
+        let sign_in = json!({
+            "actions": [{ "openPopupAction": { "popup": { "confirmDialogRenderer": {
+                "confirmButton": { "buttonRenderer": {
+                    "navigationEndpoint": { "signInEndpoint": {} }
+                }}
+            }}}}]
+        });
+        assert!(matches!(
+            account_challenge(&sign_in),
+            Some(Error::AccountChallenge { url: None })
+        ));
+
+        let mut with_data = sign_in.clone();
+        with_data["contents"] = json!({});
+        assert!(account_challenge(&with_data).is_none());
+
+        let account_link = json!({
+            "actions": [{ "urlEndpoint": { "url": "https://myaccount.google.com/" } }]
+        });
+        assert!(account_challenge(&account_link).is_none());
+    }
+
+    #[test]
+    fn server_error_meta_combines_body_and_header() {
+        let body = json!({
+            "error": { "code": 400 },
+            "responseContext": { "visitorData": "abc" }
+        });
+        let meta = server_error_meta(&body, Some("ESF".to_string())).unwrap();
+        assert_eq!(meta.visitor_data.as_deref(), Some("abc"));
+        assert_eq!(meta.served_by.as_deref(), Some("ESF"));
+
+        assert!(server_error_meta(&Value::Null, None).is_none());
+    }
+
+    #[test]
+    fn client_version_rejection_reports_the_sent_version() {
+        let rejected = json!({
+            "error": {
+                "code": 400,
+                "message": "Unsupported client version.",
+                "status": "FAILED_PRECONDITION"
+            }
+        });
+        assert!(rejects_client_version(&rejected));
+        let reason = json!({ "error": { "details": [{ "reason": "UNSUPPORTED_CLIENT" }] } });
+        assert!(rejects_client_version(&reason));
+        let other = json!({
+            "error": { "code": 400, "message": "Precondition check failed." }
+        });
+        assert!(!rejects_client_version(&other));
+        assert!(!rejects_client_version(&json!({ "contents": {} })));
+
+        let body = json!({ "context": { "client": { "clientVersion": "1.20240305.01.00" } } });
+        assert!(matches!(
+            version_rejected(&body),
+            Error::ClientVersionRejected { sent_version } if sent_version == "1.20240305.01.00"
+        ));
+    }
+
+    #[test]
+    fn unauthorized_error_explains_clock_skew() {
+        let body = json!({ "error": { "code": 401 } });
+        match unauthorized_or_server_error(401, "denied".to_string(), &body, None, Some(900)) {
+            Error::Server {
+                status,
+                message,
+                meta,
+            } => {
+                assert_eq!(status, 401);
+                assert!(message.starts_with("denied (the local clock is 900s behind"));
+                assert_eq!(meta.unwrap().clock_skew_seconds, Some(900));
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+
+        match unauthorized_or_server_error(401, "denied".to_string(), &body, None, None) {
+            Error::Server { message, meta, .. } => {
+                assert_eq!(message, "denied");
+                assert!(meta.is_none());
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn clock_correction_shifts_auth_timestamp() {
+        let client = YTMusicClient::builder()
+            .with_browser_auth(test_auth())
+            .with_clock_correction(true)
+            .build()
+            .unwrap();
+        // The server is an hour ahead, as read from an earlier response.
+        client.clock_offset.store(3600, Ordering::Relaxed);
+        let skew = client.auth_timestamp() as i64 - unix_now();
+        assert!((3599..=3601).contains(&skew), "{skew}");
+
+        let uncorrected = YTMusicClient::builder()
+            .with_browser_auth(test_auth())
+            .build()
+            .unwrap();
+        uncorrected.clock_offset.store(3600, Ordering::Relaxed);
+        let skew = uncorrected.auth_timestamp() as i64 - unix_now();
+        assert!((-1..=1).contains(&skew), "{skew}");
+    }
+
+    #[test]
+    fn decode_json_body_rejects_html() {
+        let html = format!("<!DOCTYPE html><html>{}</html>", "x".repeat(500));
+        match decode_json_body(Some("text/html; charset=utf-8"), &html) {
+            Err(Error::UnexpectedContent {
+                content_type,
+                snippet,
+            }) => {
+                assert_eq!(content_type, "text/html; charset=utf-8");
+                assert!(snippet.starts_with("<!DOCTYPE html>"));
+                assert_eq!(snippet.chars().count(), SNIPPET_CHARS);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        // HTML served with a JSON content type is still reported as unexpected.
+        assert!(matches!(
+            decode_json_body(Some("application/json"), "<html></html>"),
+            Err(Error::UnexpectedContent { .. })
+        ));
+    }
+
+    #[test]
+    fn decode_json_body_accepts_json() {
+        let value =
+            decode_json_body(Some("application/json; charset=UTF-8"), r#"{"a": 1}"#).unwrap();
+        assert_eq!(value["a"], 1);
+
+        // A JSON body without a content type is still accepted.
+        assert!(decode_json_body(None, "{}").is_ok());
+
+        // Malformed JSON with a JSON content type remains a JSON error.
+        assert!(matches!(
+            decode_json_body(Some("application/json"), "{"),
+            Err(Error::Json(_))
+        ));
+    }
+
+    fn summary(playlist_id: &str, count: Option<u32>) -> PlaylistSummary {
+        PlaylistSummary {
+            playlist_id: playlist_id.to_string(),
+            title: playlist_id.to_string(),
+            thumbnails: Vec::new(),
+            count,
+            author: None,
+        }
+    }
+
+    #[test]
+    fn plan_library_prune_selects_empty_user_playlists() {
+        let playlists = vec![
+            summary("LM", Some(0)),
+            summary("PLEMPTY", Some(0)),
+            summary("PLFULL", Some(12)),
+            summary("PLUNKNOWN", None),
+        ];
+
+        let planned = plan_library_prune(&playlists, &PruneOptions::default());
+        assert_eq!(planned.len(), 1);
+        assert_eq!(planned[0].id, "PLEMPTY");
+        assert_eq!(planned[0].reason, PruneReason::EmptyPlaylist);
+
+        let options = PruneOptions {
+            empty_playlists: false,
+            ..Default::default()
+        };
+        assert!(plan_library_prune(&playlists, &options).is_empty());
+    }
+
+    fn library_artist(browse_id: &str, songs: Option<u32>) -> LibraryArtist {
+        LibraryArtist {
+            name: browse_id.to_string(),
+            browse_id: browse_id.to_string(),
+            subscribers: None,
+            songs,
+            thumbnails: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn plan_artists_without_songs_selects_subscriptions_missing_from_the_library() {
+        let subscriptions = vec![
+            library_artist("UCKEPT", None),
+            library_artist("UCZERO", None),
+            library_artist("UCGONE", None),
+        ];
+        let library_artists = vec![
+            library_artist("UCKEPT", Some(3)),
+            library_artist("UCZERO", Some(0)),
+            library_artist("UCOTHER", Some(1)),
+        ];
+
+        let planned = plan_artists_without_songs(&subscriptions, &library_artists);
+        let ids: Vec<_> = planned.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["UCZERO", "UCGONE"]);
+        assert!(
+            planned
+                .iter()
+                .all(|c| c.reason == PruneReason::ArtistWithoutSongs)
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_prune_removes_the_planned_items_without_planning_again() {
+        let dir = ScratchDir::new("execute-prune");
+        dir.write("playlist/delete/default.json", "{}");
+        let client = fixture_client(&dir);
+        let plan = PrunePlan {
+            candidates: vec![PruneCandidate {
+                id: "PLempty".to_string(),
+                title: "Empty".to_string(),
+                reason: PruneReason::EmptyPlaylist,
+            }],
+        };
+
+        let report = client.execute_prune(&plan).await.unwrap();
+        assert_eq!(report.removed, ["PLempty"]);
+        assert!(report.failed.is_empty());
+        let endpoints: Vec<String> = client
+            .fixture_requests()
+            .into_iter()
+            .map(|(endpoint, _)| endpoint)
+            .collect();
+        assert_eq!(endpoints, ["playlist/delete"]);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn plan_albums_not_played_skips_albums_in_the_history() {
+        let album = |browse_id: &str| LibraryAlbum {
+            title: browse_id.to_string(),
+            browse_id: browse_id.to_string(),
+            album_type: None,
+            artists: Vec::new(),
+            year: None,
+            thumbnails: Vec::new(),
+            is_explicit: false,
+        };
+        let play = |album_id: Option<&str>| HistoryTrack {
+            track: PlaylistTrack {
+                album: album_id.map(|id| crate::types::Album {
+                    name: id.to_string(),
+                    id: Some(id.to_string()),
+                }),
+                ..Default::default()
+            },
+            played: "Today".to_string(),
+            feedback_token: None,
+        };
+        let history = |tracks, reached_cutoff| HistorySince {
+            buckets: vec![HistoryBucket {
+                period: "Today".to_string(),
+                latest: None,
+                tracks,
+            }],
+            reached_cutoff,
+        };
+        let albums = vec![album("MPREb_PLAYED"), album("MPREb_IDLE")];
+
+        let planned = plan_albums_not_played(
+            &albums,
+            &history(vec![play(Some("MPREb_PLAYED")), play(None)], true),
+        );
+        assert_eq!(planned.len(), 1);
+        assert_eq!(planned[0].id, "MPREb_IDLE");
+        assert_eq!(planned[0].reason, PruneReason::AlbumNotPlayed);
+
+        // Nothing played since a cutoff that was reached: every album is idle.
+        assert_eq!(
+            plan_albums_not_played(&albums, &history(Vec::new(), true)).len(),
+            2
+        );
+
+        // A history ending short of the cutoff (e.g. a paused one) does not
+        // cover the period, even when it holds plays.
+        assert!(plan_albums_not_played(&albums, &history(Vec::new(), false)).is_empty());
+        assert!(
+            plan_albums_not_played(&albums, &history(vec![play(Some("MPREb_PLAYED"))], false))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn artist_browse_id_strips_library_prefix() {
+        assert_eq!(artist_browse_id("MPLAUCabc").unwrap(), "UCabc");
+        assert_eq!(artist_browse_id(" UCabc ").unwrap(), "UCabc");
+        assert!(matches!(artist_browse_id(""), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn subscription_channel_ids_rejects_empty_input() {
+        assert!(matches!(
+            subscription_channel_ids(&[]),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            subscription_channel_ids(&["UCa".to_string(), " ".to_string()]),
+            Err(Error::InvalidInput(_))
+        ));
+        assert_eq!(
+            subscription_channel_ids(&["MPLAUCa".to_string()]).unwrap(),
+            ["UCa"]
+        );
+    }
+
+    #[test]
+    fn artist_releases_browse_id_accepts_channel_id() {
+        assert_eq!(artist_releases_browse_id("UCabc").unwrap(), "MPADUCabc");
+        assert_eq!(artist_releases_browse_id("MPADUCabc").unwrap(), "MPADUCabc");
+        assert_eq!(artist_releases_browse_id("MPLAUCabc").unwrap(), "MPADUCabc");
+    }
+
+    #[test]
+    fn song_body_uses_video_id_key() {
+        let body = song_request_body(" abc ").unwrap();
+        assert_eq!(body["videoId"], "abc");
+        assert!(body.get("video_id").is_none());
+        assert!(matches!(
+            song_request_body(" "),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn watch_body_queues_song_radio() {
+        let body = watch_request_body(Some("abc"), None, false).unwrap();
+        assert_eq!(body["playlistId"], "RDAMVMabc");
+        assert_eq!(body["videoId"], "abc");
+        assert!(body.get("params").is_none());
+        assert!(body.get("watchEndpointMusicSupportedConfigs").is_some());
+
+        let body = watch_request_body(None, Some("VLPLabc"), true).unwrap();
+        assert_eq!(body["playlistId"], "PLabc");
+        assert_eq!(body["params"], "wAEB");
+        assert!(body.get("videoId").is_none());
+
+        assert!(matches!(
+            watch_request_body(None, None, true),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn check_channel_uses_cached_capabilities() {
+        let client = YTMusicClient::builder().build().unwrap();
+        assert!(client.check_channel("playlist creation").is_ok());
+
+        *client.capabilities.lock().unwrap() = Some(Capabilities {
+            has_channel: false,
+            is_premium: None,
+        });
+        match client.check_channel("playlist creation") {
+            Err(Error::Unsupported(message)) => assert!(message.contains("no channel")),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn search_body_trims_query() {
+        let body = search_request_body("  daft punk ", None, None).unwrap();
+        assert_eq!(body, json!({ "query": "daft punk" }));
+        assert!(matches!(
+            search_request_body("   ", None, None),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn search_body_includes_filter_params() {
+        let body = search_request_body("daft punk", Some(SearchFilter::Songs), None).unwrap();
+        assert_eq!(body["params"], "EgWKAQIIAWoMEA4QChADEAQQCRAF");
+    }
+
+    #[tokio::test]
+    async fn search_pagination_stops_on_empty_continuation() {
+        let row = |video_id: &str| {
+            json!({
+                "musicResponsiveListItemRenderer": {
+                    "playlistItemData": { "videoId": video_id },
+                    "flexColumns": [{
+                        "musicResponsiveListItemFlexColumnRenderer": {
+                            "text": { "runs": [{ "text": video_id }] }
+                        }
+                    }]
+                }
+            })
+        };
+        let next = |token: &str| {
+            json!({
+                "continuationItemRenderer": {
+                    "continuationEndpoint": { "continuationCommand": { "token": token } }
+                }
+            })
+        };
+        let pages = std::collections::HashMap::from([
+            (
+                "t1",
+                json!({
+                    "continuationContents": {
+                        "musicShelfContinuation": { "contents": [row("b"), next("t2")] }
+                    }
+                }),
+            ),
+            (
+                "t2",
+                json!({
+                    "continuationContents": {
+                        "musicShelfContinuation": { "contents": [], "continuations": [{
+                            "nextContinuationData": { "continuation": "t3" }
+                        }] }
+                    }
+                }),
+            ),
+        ]);
+
+        let mut results = parse_search_rows(&[row("a")]);
+        let continuation = paginate(
+            &mut results,
+            Some("t1".to_string()),
+            100,
+            &SEARCH_RESULT_PAGES,
+            |token| {
+                let page = pages[token.as_str()].clone();
+                async move { Ok(page) }
+            },
+        )
+        .await
+        .unwrap();
+
+        let ids: Vec<_> = results
+            .iter()
+            .filter_map(|r| r.video_id.as_deref())
+            .collect();
+        assert_eq!(ids, ["a", "b"]);
+        assert_eq!(continuation, None);
+    }
+
+    #[test]
+    fn search_params_for_scopes() {
+        let library = Some(SearchScope::Library);
+        let uploads = Some(SearchScope::Uploads);
+
+        assert_eq!(
+            search_params(None, library).unwrap().as_deref(),
+            Some("agIYBA%3D%3D")
+        );
+        assert_eq!(
+            search_params(Some(SearchFilter::Songs), library)
+                .unwrap()
+                .as_deref(),
+            Some("EgWKAQIIAWoKEAUQCRADEAoYBA%3D%3D")
+        );
+        assert_eq!(
+            search_params(None, uploads).unwrap().as_deref(),
+            Some("agIYAw%3D%3D")
+        );
+        assert!(matches!(
+            search_params(Some(SearchFilter::Songs), uploads),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            search_params(Some(SearchFilter::CommunityPlaylists), library),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn feedback_body_and_response() {
+        assert!(matches!(
+            feedback_request_body(&[]),
+            Err(Error::InvalidInput(_))
+        ));
+        let body = feedback_request_body(&["t1".to_string(), "t2".to_string()]).unwrap();
+        assert_eq!(body, json!({ "feedbackTokens": ["t1", "t2"] }));
+
+        let processed = json!({
+            "feedbackResponses": [{ "isProcessed": true }, { "isProcessed": true }]
+        });
+        assert!(feedback_processed(&processed));
+        let partial = json!({
+            "feedbackResponses": [{ "isProcessed": true }, { "isProcessed": false }]
+        });
+        assert!(!feedback_processed(&partial));
+        assert!(!feedback_processed(&json!({})));
+    }
+
+    #[test]
+    fn rating_body_validates_video_id() {
+        let body = rating_request_body("abc").unwrap();
+        assert_eq!(body["target"]["videoId"], "abc");
+        assert!(matches!(
+            rating_request_body(""),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn playlist_rating_body_strips_prefix_and_rejects_dislike() {
+        let body = playlist_rating_request_body("VLPL123", LikeStatus::Like).unwrap();
+        assert_eq!(body, json!({ "target": { "playlistId": "PL123" } }));
+        assert!(playlist_rating_request_body("PL123", LikeStatus::Indifferent).is_ok());
+        assert!(matches!(
+            playlist_rating_request_body("PL123", LikeStatus::Dislike),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn add_playlist_items_allowing_duplicates_skips_the_server_check() {
+        let video_ids = vec!["abc".to_string(), "abc".to_string()];
+
+        let allow =
+            add_playlist_items_body("VLPL123", &video_ids, DuplicateHandling::Allow).unwrap();
+        assert_eq!(allow["playlistId"], "PL123");
+        for action in allow["actions"].as_array().unwrap() {
+            assert_eq!(action["dedupeOption"], "DEDUPE_OPTION_SKIP");
+        }
+
+        // Both copies come back as added, so nothing is reported skipped.
+        let response = json!({
+            "status": "STATUS_SUCCEEDED",
+            "playlistEditResults": [
+                { "playlistEditVideoAddedResultData": { "videoId": "abc", "setVideoId": "s1" } },
+                { "playlistEditVideoAddedResultData": { "videoId": "abc", "setVideoId": "s2" } }
+            ]
+        });
+        let result = AddPlaylistItemsResult::from_response(response, &video_ids);
+        let result = check_duplicates(result, DuplicateHandling::Allow).unwrap();
+        assert_eq!(result.set_video_ids(), ["s1", "s2"]);
+        assert!(result.skipped.is_empty());
+    }
+
+    #[test]
+    fn add_playlist_items_blocking_duplicates_asks_for_the_server_check() {
+        let video_ids = vec!["abc".to_string(), "def".to_string()];
+
+        for duplicates in [DuplicateHandling::Skip, DuplicateHandling::Fail] {
+            let body = add_playlist_items_body("PL123", &video_ids, duplicates).unwrap();
+            for action in body["actions"].as_array().unwrap() {
+                assert_eq!(
+                    action["dedupeOption"], "DEDUPE_OPTION_CHECK",
+                    "{duplicates:?}"
+                );
+            }
+        }
+
+        // The server leaves out the video already present.
+        let response = json!({
+            "status": "STATUS_SUCCEEDED",
+            "playlistEditResults": [
+                { "playlistEditVideoAddedResultData": { "videoId": "def", "setVideoId": "s1" } }
+            ]
+        });
+        let result = AddPlaylistItemsResult::from_response(response, &video_ids);
+        let result = check_duplicates(result, DuplicateHandling::Skip).unwrap();
+        assert_eq!(result.skipped, ["abc"]);
+    }
+
+    #[test]
+    fn check_duplicates_fails_only_when_asked() {
+        let requested: Vec<String> = ["a", "b"].map(String::from).to_vec();
+        let response = json!({
+            "status": "STATUS_SUCCEEDED",
+            "playlistEditResults": [
+                { "playlistEditVideoAddedResultData": { "videoId": "b", "setVideoId": "sb" } }
+            ]
+        });
+        let result = || AddPlaylistItemsResult::from_response(response.clone(), &requested);
+
+        let skipped = check_duplicates(result(), DuplicateHandling::Skip).unwrap();
+        assert_eq!(skipped.skipped, ["a"]);
+        assert!(matches!(
+            check_duplicates(result(), DuplicateHandling::Fail),
+            Err(Error::Duplicates { result })
+                if result.skipped == ["a"] && result.set_video_ids() == ["sb"]
+        ));
+
+        let all_added = json!({
+            "status": "STATUS_SUCCEEDED",
+            "playlistEditResults": [
+                { "playlistEditVideoAddedResultData": { "videoId": "a" } },
+                { "playlistEditVideoAddedResultData": { "videoId": "b" } }
+            ]
+        });
+        let result = AddPlaylistItemsResult::from_response(all_added, &requested);
+        assert!(check_duplicates(result, DuplicateHandling::Fail).is_ok());
+    }
+
+    #[test]
+    fn duplicate_handling_from_bool_matches_legacy_flag() {
+        assert_eq!(DuplicateHandling::from(true), DuplicateHandling::Allow);
+        assert_eq!(DuplicateHandling::from(false), DuplicateHandling::Skip);
+
+        let video_ids = vec!["abc".to_string()];
+        let option = |allow_duplicates: bool| {
+            let body = add_playlist_items_body("PL123", &video_ids, allow_duplicates.into());
+            body.unwrap()["actions"][0]["dedupeOption"].clone()
+        };
+        assert_eq!(option(true), "DEDUPE_OPTION_SKIP");
+        assert_eq!(option(false), "DEDUPE_OPTION_CHECK");
+    }
 
-        let json: Value = response.json().await?;
+    #[test]
+    fn validate_playlist_id_normalizes_input_forms() {
+        for input in [
+            "PL123",
+            "VLPL123",
+            " PL123 ",
+            "https://music.youtube.com/playlist?list=PL123",
+            "https://www.youtube.com/watch?v=abc&list=VLPL123&index=2",
+        ] {
+            assert_eq!(validate_playlist_id(input).unwrap(), "PL123", "{input}");
+        }
+        assert!(validate_playlist_id("https://music.youtube.com/playlist?list=").is_err());
+    }
 
-        // Check for API error in response
-        if let Some(error) = json.get("error") {
-            let message = error
-                .get("message")
-                .and_then(|m| m.as_str())
-                .unwrap_or("Unknown error")
-                .to_string();
-            let code = error.get("code").and_then(|c| c.as_u64()).unwrap_or(500) as u16;
-            return Err(Error::Server {
-                status: code,
-                message,
-            });
+    /// Apply move actions the way the server does.
+    fn apply_moves(mut items: Vec<String>, actions: &[Value]) -> Vec<String> {
+        for action in actions {
+            let moved = action["setVideoId"].as_str().unwrap();
+            items.retain(|id| id != moved);
+            let at = match action["movedSetVideoIdSuccessor"].as_str() {
+                Some(before) => items.iter().position(|id| id == before).unwrap(),
+                None => items.len(),
+            };
+            items.insert(at, moved.to_string());
         }
+        items
+    }
 
-        Ok(json)
+    #[test]
+    fn reorder_actions_reach_the_target_with_fewest_moves() {
+        let ids = |s: &str| -> Vec<String> { s.chars().map(String::from).collect() };
+        for (current, target, moves) in [
+            ("abcdef", "abcdef", 0),
+            ("abcdef", "bacdfe", 2),
+            ("abcdef", "fedcba", 5),
+            ("abcdef", "bcdefa", 1),
+            ("abcdef", "fabcde", 1),
+            ("abcdef", "cafbed", 3),
+        ] {
+            let actions = reorder_actions(&ids(current), &ids(target)).unwrap();
+            assert_eq!(actions.len(), moves, "{current} -> {target}");
+            assert_eq!(
+                apply_moves(ids(current), &actions),
+                ids(target),
+                "{current} -> {target}"
+            );
+        }
     }
 
-    /// Check that the client is authenticated, returning an error if not.
-    fn check_auth(&self) -> Result<()> {
-        if self.auth.is_none() {
-            Err(Error::AuthRequired)
-        } else {
-            Ok(())
+    #[test]
+    fn reorder_actions_require_a_permutation() {
+        let ids = |s: &str| -> Vec<String> { s.chars().map(String::from).collect() };
+        for (current, target) in [
+            ("abc", "ab"),
+            ("abc", "abcd"),
+            ("abc", "aab"),
+            ("aab", "aab"),
+        ] {
+            assert!(
+                matches!(
+                    reorder_actions(&ids(current), &ids(target)),
+                    Err(Error::InvalidInput(_))
+                ),
+                "{current} -> {target}"
+            );
         }
     }
-}
 
-impl YTMusicClientBuilder {
-    /// Set browser authentication.
-    pub fn with_browser_auth(mut self, auth: BrowserAuth) -> Self {
-        self.auth = Some(auth);
-        self
+    #[test]
+    fn add_playlist_to_playlist_body_normalizes_ids() {
+        let body = add_playlist_to_playlist_body(
+            "VLPLdest",
+            "https://music.youtube.com/playlist?list=PLsrc",
+        )
+        .unwrap();
+        assert_eq!(body["playlistId"], "PLdest");
+        assert_eq!(
+            body["actions"][0],
+            json!({ "action": "ACTION_ADD_PLAYLIST", "addedFullListId": "PLsrc" })
+        );
+
+        assert!(matches!(
+            add_playlist_to_playlist_body("PLsame", "VLPLsame"),
+            Err(Error::InvalidInput(_))
+        ));
     }
 
-    /// Set the language for responses.
-    ///
-    /// This maps to the `hl` client parameter (default: `"en"`).
-    pub fn with_language(mut self, language: impl Into<String>) -> Self {
-        self.language = language.into();
-        self
+    #[test]
+    fn check_playlist_items_compares_touched_items() {
+        let tracks = [
+            track(Some("vid1"), Some("set1")),
+            track(Some("vid2"), Some("set2")),
+        ];
+        let ids = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+
+        assert!(
+            check_playlist_items(&tracks, ids(&["set1", "vid2"]), true, true)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            check_playlist_items(&tracks, ids(&["set3"]), false, true)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(matches!(
+            check_playlist_items(&tracks, ids(&["set1", "set3"]), true, true),
+            Err(Error::VerificationFailed { expected, observed })
+                if expected == ["set1", "set3"] && observed == ["set1"]
+        ));
+        assert!(matches!(
+            check_playlist_items(&tracks, ids(&["set2", "set3"]), false, true),
+            Err(Error::VerificationFailed { expected, observed })
+                if expected.is_empty() && observed == ["set2"]
+        ));
     }
 
-    /// Set the location for results.
-    ///
-    /// This maps to the `gl` client parameter and expects ISO 3166-1 alpha-2
-    /// country codes (e.g., `"US"`, `"GB"`, `"DE"`).
-    pub fn with_location(mut self, location: impl Into<String>) -> Self {
-        self.location = Some(location.into());
-        self
+    #[test]
+    fn check_playlist_items_leaves_items_past_a_partial_read_unchecked() {
+        let tracks = [
+            track(Some("vid1"), Some("set1")),
+            track(Some("vid2"), Some("set2")),
+        ];
+        let ids = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+
+        // Added items not among the tracks read may have been appended later on.
+        assert_eq!(
+            check_playlist_items(&tracks, ids(&["set1", "set9"]), true, false).unwrap(),
+            ["set9"]
+        );
+        assert_eq!(
+            check_playlist_items(&tracks, ids(&["set8", "set9"]), false, false).unwrap(),
+            ["set8", "set9"]
+        );
+        // A removed item that is still listed fails either way.
+        assert!(matches!(
+            check_playlist_items(&tracks, ids(&["set2", "set9"]), false, false),
+            Err(Error::VerificationFailed { expected, observed })
+                if expected.is_empty() && observed == ["set2"]
+        ));
     }
 
-    /// Set a user ID for brand account requests.
-    ///
-    /// This maps to `onBehalfOfUser` in the request context.
-    pub fn with_user(mut self, user: impl Into<String>) -> Self {
-        self.user = Some(user.into());
-        self
+    #[test]
+    fn add_result_reads_edit_results_and_skips() {
+        let requested: Vec<String> = ["a", "b", "c", "a"].map(String::from).to_vec();
+        let response = json!({
+            "status": "STATUS_SUCCEEDED",
+            "playlistEditResults": [
+                { "playlistEditVideoAddedResultData": { "videoId": "a", "setVideoId": "sa" } },
+                { "playlistEditVideoAddedResultData": { "videoId": "b", "videoTitle": "Bee" } }
+            ]
+        });
+        let result = AddPlaylistItemsResult::from_response(response, &requested);
+        assert!(result.is_succeeded());
+        assert_eq!(result.set_video_ids(), ["sa"]);
+        assert_eq!(result.added[1].title.as_deref(), Some("Bee"));
+        // The second "a" was only added once.
+        assert_eq!(result.skipped, ["c", "a"]);
+
+        let failed = json!({ "status": "STATUS_FAILED" });
+        let result = AddPlaylistItemsResult::from_response(failed, &requested);
+        assert_eq!(result.status, EditStatus::Failed);
+        assert!(result.added.is_empty() && result.skipped.is_empty());
+
+        // Without edit results nothing is known to be skipped.
+        let bare = json!({ "status": "STATUS_SUCCEEDED" });
+        let result = AddPlaylistItemsResult::from_response(bare, &requested);
+        assert!(result.skipped.is_empty());
     }
 
-    /// Build the client.
-    ///
-    /// This does not validate authentication credentials.
-    pub fn build(self) -> Result<YTMusicClient> {
-        let mut headers = HeaderMap::new();
+    #[test]
+    fn override_pref_language_replaces_the_cookie_language() {
+        assert_eq!(
+            override_pref_language("SID=x; PREF=f6=40000000&hl=de&tz=Europe.Berlin; A=b", "en"),
+            "SID=x; PREF=f6=40000000&tz=Europe.Berlin&hl=en; A=b"
+        );
+        assert_eq!(override_pref_language("SID=x", "fr"), "SID=x; PREF=hl=fr");
+    }
 
-        for (key, value) in default_headers() {
-            if let Ok(header_value) = HeaderValue::from_str(&value)
-                && let Ok(header_name) = key.parse::<HeaderName>()
-            {
-                headers.insert(header_name, header_value);
+    #[test]
+    fn accept_language_header_follows_the_configured_language() {
+        let header = |builder: YTMusicClientBuilder| {
+            let client = builder.build().unwrap();
+            let request = client
+                .with_auth_headers(client.http.post(YTM_BASE_API))
+                .unwrap()
+                .build()
+                .unwrap();
+            request.headers()[ACCEPT_LANGUAGE]
+                .to_str()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(header(YTMusicClient::builder()), "en");
+        assert_eq!(
+            header(YTMusicClient::builder().with_language("pt-BR")),
+            "pt-BR,en;q=0.9"
+        );
+        assert_eq!(
+            header(
+                YTMusicClient::builder()
+                    .with_browser_auth(test_auth())
+                    .with_response_language_override("de")
+            ),
+            "de,en;q=0.9"
+        );
+    }
+
+    #[test]
+    fn replace_auth_applies_to_requests_built_afterwards() {
+        let auth = |sapisid: &str, authuser: &str| {
+            BrowserAuth::from_json(&format!(
+                r#"{{"cookie": "__Secure-3PAPISID={sapisid}", "x-goog-authuser": "{authuser}"}}"#
+            ))
+            .unwrap()
+        };
+        let client = YTMusicClient::builder().build().unwrap();
+        let build = |client: &YTMusicClient| {
+            client
+                .with_auth_headers(client.http.post(YTM_BASE_API))
+                .unwrap()
+                .build()
+                .unwrap()
+        };
+        let sent = build(&client);
+        assert!(!client.is_authenticated());
+
+        client.replace_auth(auth("old", "0"));
+        let old = build(&client);
+        client.replace_auth(auth("new", "1"));
+        assert!(
+            sent.headers()["cookie"]
+                .to_str()
+                .unwrap()
+                .starts_with("SOCS")
+        );
+        assert!(old.headers()["cookie"].to_str().unwrap().contains("=old"));
+        assert!(
+            build(&client).headers()["cookie"]
+                .to_str()
+                .unwrap()
+                .contains("=new")
+        );
+
+        // Each request reads one set of credentials, even while they are
+        // being swapped.
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                for i in 0..200 {
+                    let (sapisid, authuser) = if i % 2 == 0 {
+                        ("old", "0")
+                    } else {
+                        ("new", "1")
+                    };
+                    client.replace_auth(auth(sapisid, authuser));
+                }
+            });
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..200 {
+                        let request = build(&client);
+                        let cookie = request.headers()["cookie"].to_str().unwrap();
+                        let authuser = request.headers()["x-goog-authuser"].to_str().unwrap();
+                        let expected = if authuser == "0" { "=old" } else { "=new" };
+                        assert!(
+                            cookie.contains(expected),
+                            "{cookie} with authuser {authuser}"
+                        );
+                    }
+                });
             }
-        }
+        });
+    }
+
+    #[test]
+    fn response_language_override_wins_over_the_account_cookie() {
+        let cookie_language = |builder: YTMusicClientBuilder| {
+            let auth = BrowserAuth::from_json(
+                r#"{"cookie": "__Secure-3PAPISID=abc; PREF=hl=de", "x-goog-authuser": "0"}"#,
+            )
+            .unwrap();
+            let client = builder.with_browser_auth(auth).build().unwrap();
+            let request = client
+                .with_auth_headers(client.http.post(YTM_BASE_API))
+                .unwrap()
+                .build()
+                .unwrap();
+            let cookie = request.headers()["cookie"].to_str().unwrap().to_string();
+            let hl =
+                create_context(&client.language, None, None, ClientProfile::WebRemix)["context"]["client"]["hl"].clone();
+            (cookie, hl)
+        };
 
-        let http = reqwest::Client::builder()
-            .default_headers(headers)
-            .gzip(true)
-            .build()?;
+        let (cookie, hl) = cookie_language(YTMusicClient::builder().with_language("en"));
+        assert!(cookie.contains("PREF=hl=de"), "{cookie}");
+        assert_eq!(hl, "en");
 
-        Ok(YTMusicClient {
-            http,
-            auth: self.auth,
-            language: self.language,
-            location: self.location,
-            user: self.user,
-        })
+        let (cookie, hl) =
+            cookie_language(YTMusicClient::builder().with_response_language_override("en"));
+        assert!(
+            cookie.contains("PREF=hl=en") && !cookie.contains("hl=de"),
+            "{cookie}"
+        );
+        assert_eq!(hl, "en");
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn edit_playlist_body_sets_only_given_fields() {
+        let options = EditPlaylistOptions {
+            title: Some(" Road Trip ".to_string()),
+            privacy: Some(Privacy::Unlisted),
+            ..Default::default()
+        };
+        assert_eq!(
+            edit_playlist_body("VLPL123", &options).unwrap(),
+            json!({
+                "playlistId": "PL123",
+                "actions": [
+                    { "action": "ACTION_SET_PLAYLIST_NAME", "playlistName": "Road Trip" },
+                    { "action": "ACTION_SET_PLAYLIST_PRIVACY", "playlistPrivacy": "UNLISTED" }
+                ]
+            })
+        );
 
-    fn track(video_id: Option<&str>, set_video_id: Option<&str>) -> PlaylistTrack {
-        PlaylistTrack {
-            video_id: video_id.map(String::from),
-            set_video_id: set_video_id.map(String::from),
+        let clear_description = EditPlaylistOptions {
+            description: Some(String::new()),
             ..Default::default()
+        };
+        assert_eq!(
+            edit_playlist_body("PL123", &clear_description).unwrap()["actions"][0],
+            json!({ "action": "ACTION_SET_PLAYLIST_DESCRIPTION", "playlistDescription": "" })
+        );
+
+        for invalid in [
+            EditPlaylistOptions::default(),
+            EditPlaylistOptions {
+                title: Some("  ".to_string()),
+                ..Default::default()
+            },
+        ] {
+            assert!(matches!(
+                edit_playlist_body("PL123", &invalid),
+                Err(Error::InvalidInput(_))
+            ));
         }
     }
 
     #[test]
-    fn song_body_uses_video_id_key() {
-        let body = song_request_body(" abc ").unwrap();
-        assert_eq!(body["videoId"], "abc");
-        assert!(body.get("video_id").is_none());
+    fn edit_status_reads_the_response() {
+        assert!(EditStatus::from_response(&json!({ "status": "STATUS_SUCCEEDED" })).is_succeeded());
+        assert_eq!(
+            EditStatus::from_response(&json!({ "status": "STATUS_FAILED" })),
+            EditStatus::Failed
+        );
+        assert_eq!(
+            EditStatus::from_response(&json!({})),
+            EditStatus::Other(String::new())
+        );
+    }
+
+    #[test]
+    fn add_playlist_items_validates_ids() {
         assert!(matches!(
-            song_request_body(" "),
+            add_playlist_items_body("", &["abc".to_string()], DuplicateHandling::Allow),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            add_playlist_items_body("PL123", &[], DuplicateHandling::Allow),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            add_playlist_items_body("PL123", &[" ".to_string()], DuplicateHandling::Allow),
             Err(Error::InvalidInput(_))
         ));
     }
 
     #[test]
-    fn rating_body_validates_video_id() {
-        let body = rating_request_body("abc").unwrap();
-        assert_eq!(body["target"]["videoId"], "abc");
+    fn track_video_ids_skips_tracks_without_ids() {
+        let tracks = vec![
+            track(Some("vid1"), None),
+            track(None, Some("set1")),
+            track(Some(" "), None),
+        ];
+        assert_eq!(track_video_ids(&tracks), vec!["vid1".to_string()]);
+    }
+
+    #[test]
+    fn playlist_item_note_body_targets_set_video_id() {
+        let body = playlist_item_note_body("VLPL123", " set1 ", " note ").unwrap();
+        assert_eq!(body["playlistId"], "PL123");
+        assert_eq!(body["actions"][0]["setVideoId"], "set1");
+        assert_eq!(body["actions"][0]["videoNote"], "note");
         assert!(matches!(
-            rating_request_body(""),
+            playlist_item_note_body("PL123", "", "note"),
             Err(Error::InvalidInput(_))
         ));
     }
 
     #[test]
-    fn add_playlist_items_honors_allow_duplicates() {
-        let video_ids = vec!["abc".to_string()];
-
-        let allow = add_playlist_items_body("VLPL123", &video_ids, true).unwrap();
-        assert_eq!(allow["playlistId"], "PL123");
-        assert!(allow["actions"][0].get("dedupeOption").is_none());
+    fn classify_rollout_action_detects_rejection() {
+        let recorded = |name: &str| {
+            let path = format!("tests/fixtures/errors/{name}.json");
+            let body: Value =
+                serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+            Err(api_error(&body, None).unwrap())
+        };
+        let classify = |result| classify_rollout_action("notes", "ACTION_SET_VIDEO_NOTE", result);
 
-        let skip = add_playlist_items_body("PL123", &video_ids, false).unwrap();
-        assert_eq!(skip["actions"][0]["dedupeOption"], "DEDUPE_OPTION_SKIP");
-    }
+        assert!(matches!(
+            classify(recorded("unknown_edit_action")),
+            Err(Error::Unsupported(_))
+        ));
+        let not_implemented = Error::Server {
+            status: 501,
+            message: "Not implemented".to_string(),
+            meta: None,
+        };
+        assert!(matches!(
+            classify(Err(not_implemented)),
+            Err(Error::Unsupported(_))
+        ));
 
-    #[test]
-    fn add_playlist_items_validates_ids() {
+        // Other rejections are not mistaken for a missing rollout.
         assert!(matches!(
-            add_playlist_items_body("", &["abc".to_string()], true),
-            Err(Error::InvalidInput(_))
+            classify(recorded("precondition_failed")),
+            Err(Error::Server { status: 400, .. })
         ));
+        let other_action = Error::Server {
+            status: 400,
+            message: "Invalid value at 'actions[0].action', \"ACTION_OTHER\"".to_string(),
+            meta: None,
+        };
         assert!(matches!(
-            add_playlist_items_body("PL123", &[], true),
-            Err(Error::InvalidInput(_))
+            classify(Err(other_action)),
+            Err(Error::Server { .. })
         ));
+        let failed = classify(Ok(json!({"status": "STATUS_FAILED"}))).unwrap();
+        assert_eq!(failed.status, EditStatus::Failed);
+        assert!(
+            classify(Ok(json!({"status": "STATUS_SUCCEEDED"})))
+                .unwrap()
+                .is_succeeded()
+        );
         assert!(matches!(
-            add_playlist_items_body("PL123", &[" ".to_string()], true),
-            Err(Error::InvalidInput(_))
+            classify(Err(Error::AuthRequired)),
+            Err(Error::AuthRequired)
         ));
     }
 
@@ -794,4 +5296,390 @@ mod tests {
             Err(Error::InvalidInput(_))
         ));
     }
+
+    #[test]
+    fn take_playlist_range_checks_bounds() {
+        let tracks: Vec<_> = (0..5)
+            .map(|i| track(Some(&format!("vid{i}")), Some(&format!("set{i}"))))
+            .collect();
+
+        let taken = take_playlist_range(tracks.clone(), &(1..3)).unwrap();
+        let ids: Vec<_> = taken
+            .iter()
+            .map(|t| t.video_id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, ["vid1", "vid2"]);
+        assert_eq!(
+            take_playlist_range(tracks.clone(), &(0..5)).unwrap().len(),
+            5
+        );
+        assert_eq!(
+            take_playlist_range(tracks.clone(), &(4..5)).unwrap().len(),
+            1
+        );
+
+        for range in [0..6, 5..6, 2..2] {
+            assert!(
+                matches!(
+                    take_playlist_range(tracks.clone(), &range),
+                    Err(Error::InvalidInput(_))
+                ),
+                "{range:?}"
+            );
+        }
+
+        let mut tracks = tracks;
+        tracks[3].set_video_id = None;
+        let Err(Error::InvalidInput(message)) = take_playlist_range(tracks, &(2..4)) else {
+            panic!("expected an unremovable item to be rejected");
+        };
+        assert!(message.contains("item 3"), "{message}");
+    }
+
+    #[test]
+    fn feedback_flags_pads_missing_responses() {
+        let response = json!({
+            "feedbackResponses": [{ "isProcessed": true }, { "isProcessed": false }]
+        });
+        assert_eq!(feedback_flags(&response, 3), [true, false, false]);
+        assert_eq!(feedback_flags(&json!({}), 2), [false, false]);
+    }
+
+    #[test]
+    fn client_playback_nonce_uses_the_alphabet() {
+        let nonce = client_playback_nonce();
+        assert_eq!(nonce.len(), 16);
+        assert!(nonce.bytes().all(|b| CPN_ALPHABET.contains(&b)));
+        assert_ne!(nonce, client_playback_nonce());
+    }
+
+    #[tokio::test]
+    async fn export_liked_songs_falls_back_to_the_listed_id() {
+        let dir = ScratchDir::new("export-liked");
+        let fixture =
+            |path: &str| std::fs::read_to_string(format!("tests/fixtures/{path}")).unwrap();
+        dir.write(
+            "browse/VLLM.json",
+            &fixture("errors/precondition_failed.json"),
+        );
+        dir.write(
+            "browse/FEmusic_liked_playlists.json",
+            &fixture("liked_summary/brand_account.json"),
+        );
+        dir.write(
+            "browse/VLLLfixturebrandaccount000000.json",
+            &fixture("playlist/public.json"),
+        );
+        let client = fixture_client(&dir);
+
+        let mut csv = Vec::new();
+        let written = client
+            .export_liked_songs(ExportFormat::Csv, &mut csv)
+            .await
+            .unwrap();
+        assert_eq!(written, 2);
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv.lines().count(), 1 + written, "{csv}");
+        let browsed: Vec<_> = client
+            .fixture_requests()
+            .into_iter()
+            .map(|(_, body)| body["browseId"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            browsed,
+            [
+                "VLLM",
+                "FEmusic_liked_playlists",
+                "VLLLfixturebrandaccount000000"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn plays_recorded_from_fixtures_are_audited_as_simulated() {
+        let dir = ScratchDir::new("add-history");
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&records);
+        let client = fixture_builder(&dir)
+            .with_audit_hook(move |record| sink.lock().unwrap().push(record))
+            .build()
+            .unwrap();
+        let song = Song {
+            playback_tracking: Some(PlaybackTracking {
+                videostats_playback_url: Some(TrackingUrl {
+                    base_url: "https://s.youtube.com/api/stats/playback?docid=abc".to_string(),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        client.add_history_item(&song).await.unwrap();
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].operation, MutationKind::AddHistory);
+        assert_eq!(records[0].outcome, MutationOutcome::Simulated);
+    }
+
+    #[test]
+    fn playback_url_requires_a_youtube_url() {
+        let song = |url: &str| Song {
+            playback_tracking: Some(PlaybackTracking {
+                videostats_playback_url: Some(TrackingUrl {
+                    base_url: url.to_string(),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let url =
+            playback_url(&song("https://s.youtube.com/api/stats/playback?docid=abc")).unwrap();
+        assert_eq!(url.host_str(), Some("s.youtube.com"));
+
+        for url in [
+            "http://s.youtube.com/api/stats/playback",
+            "https://example.com/api/stats/playback",
+            "https://youtube.com.example.com/x",
+            "not a url",
+        ] {
+            assert!(
+                matches!(playback_url(&song(url)), Err(Error::InvalidInput(_))),
+                "{url}"
+            );
+        }
+        assert!(matches!(
+            playback_url(&Song::default()),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn upload_file_name_checks_extension_and_size() {
+        let path = Path::new("/music/Song Title.FLAC");
+        assert_eq!(upload_file_name(path, 1024).unwrap(), "Song Title.FLAC");
+        assert!(upload_file_name(Path::new("a.ogg"), UPLOAD_SIZE_LIMIT - 1).is_ok());
+
+        for (path, size) in [
+            ("cover.jpg", 1024),
+            ("no_extension", 1024),
+            ("track.mp3", UPLOAD_SIZE_LIMIT),
+        ] {
+            assert!(
+                matches!(
+                    upload_file_name(Path::new(path), size),
+                    Err(Error::InvalidInput(_))
+                ),
+                "{path}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn upload_song_requires_auth_before_touching_the_file() {
+        let client = YTMusicClient::builder().build().unwrap();
+        let result = client.upload_song(Path::new("missing.mp3")).await;
+        assert!(matches!(result, Err(Error::AuthRequired)));
+    }
+
+    #[tokio::test]
+    async fn upload_song_is_not_faked_from_fixtures() {
+        let dir = ScratchDir::new("upload-song");
+        dir.write("song.mp3", "ID3");
+        let client = fixture_client(&dir);
+
+        let song = client.upload_song(&dir.path().join("song.mp3")).await;
+        assert!(matches!(song, Err(Error::Unsupported(_))));
+        // The file is still checked first.
+        dir.write("cover.jpg", "");
+        let cover = client.upload_song(&dir.path().join("cover.jpg")).await;
+        assert!(matches!(cover, Err(Error::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn delete_upload_entity_sends_the_listed_entity_id() {
+        let dir = ScratchDir::new("delete-upload");
+        let fixture =
+            |path: &str| std::fs::read_to_string(format!("tests/fixtures/{path}")).unwrap();
+        dir.write(
+            "browse/FEmusic_library_privately_owned_tracks.json",
+            &fixture("upload_songs/flac_rips.json"),
+        );
+        dir.write(
+            "browse/4qmFsgIfixtureuploads1.json",
+            &fixture("upload_songs_continuation/last_page.json"),
+        );
+        dir.write("music/delete_privately_owned_entity/default.json", "{}");
+        let client = fixture_client(&dir);
+        let last_sent = || {
+            let sent = client.fixtures.as_ref().unwrap().sent();
+            sent.last().cloned().unwrap()
+        };
+
+        let uploads = client.get_library_upload_songs(None, None).await.unwrap();
+        let entity_id = &uploads[0].entity_id;
+        let status = client.delete_upload_entity(entity_id).await.unwrap();
+        assert_eq!(status, EditStatus::Succeeded);
+        let (endpoint, body) = last_sent();
+        assert_eq!(endpoint, "music/delete_privately_owned_entity");
+        assert_eq!(body["entityId"], entity_id.as_str());
+
+        // Album browse IDs are sent as the entity ID they end in.
+        let status = client
+            .delete_upload_entity("FEmusic_library_privately_owned_release_detailb_fixturealbum01")
+            .await
+            .unwrap();
+        assert!(status.is_succeeded());
+        assert_eq!(last_sent().1["entityId"], "b_fixturealbum01");
+
+        let requests = client.fixtures.as_ref().unwrap().sent().len();
+        assert!(matches!(
+            client.delete_upload_entity(" ").await,
+            Err(Error::InvalidInput(_))
+        ));
+        assert_eq!(client.fixtures.as_ref().unwrap().sent().len(), requests);
+    }
+
+    /// A playlist row for `video_id`, lasting `duration`.
+    fn mega_playlist_row(video_id: &str, duration: &str) -> Value {
+        json!({
+            "musicResponsiveListItemRenderer": {
+                "overlay": {
+                    "musicItemThumbnailOverlayRenderer": {
+                        "content": {
+                            "musicPlayButtonRenderer": {
+                                "playNavigationEndpoint": {
+                                    "watchEndpoint": { "videoId": video_id }
+                                }
+                            }
+                        }
+                    }
+                },
+                "flexColumns": [{
+                    "musicResponsiveListItemFlexColumnRenderer": {
+                        "text": { "runs": [{ "text": video_id }] }
+                    }
+                }],
+                "fixedColumns": [{
+                    "musicResponsiveListItemFixedColumnRenderer": {
+                        "text": { "runs": [{ "text": duration }] }
+                    }
+                }]
+            }
+        })
+    }
+
+    /// Set the `contents` of every playlist shelf in `value`.
+    fn fill_playlist_shelf(value: &mut Value, contents: &Value) {
+        match value {
+            Value::Object(fields) => {
+                if let Some(shelf) = fields.get_mut("musicPlaylistShelfRenderer") {
+                    shelf["contents"] = contents.clone();
+                }
+                fields
+                    .values_mut()
+                    .for_each(|field| fill_playlist_shelf(field, contents));
+            }
+            Value::Array(items) => items
+                .iter_mut()
+                .for_each(|item| fill_playlist_shelf(item, contents)),
+            _ => {}
+        }
+    }
+
+    /// Record `playlist_id` as a playlist of `tracks` tracks of `duration`
+    /// each, 100 per page, with video IDs `v00000`, `v00001`, ...
+    fn write_long_playlist(dir: &ScratchDir, playlist_id: &str, tracks: usize, duration: &str) {
+        const PAGE: usize = 100;
+
+        let page_rows = |page: usize| {
+            let mut rows: Vec<Value> = (page * PAGE..((page + 1) * PAGE).min(tracks))
+                .map(|i| mega_playlist_row(&format!("v{i:05}"), duration))
+                .collect();
+            if (page + 1) * PAGE < tracks {
+                rows.push(json!({
+                    "continuationItemRenderer": {
+                        "continuationEndpoint": {
+                            "continuationCommand": { "token": format!("page{}", page + 1) }
+                        }
+                    }
+                }));
+            }
+            Value::Array(rows)
+        };
+        let mut first: Value = serde_json::from_str(
+            &std::fs::read_to_string("tests/fixtures/playlist/empty.json").unwrap(),
+        )
+        .unwrap();
+        fill_playlist_shelf(&mut first, &page_rows(0));
+        dir.write(&format!("browse/VL{playlist_id}.json"), &first.to_string());
+        for page in 1..tracks.div_ceil(PAGE) {
+            let response = json!({
+                "onResponseReceivedActions": [{
+                    "appendContinuationItemsAction": { "continuationItems": page_rows(page) }
+                }]
+            });
+            dir.write(&format!("browse/page{page}.json"), &response.to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn playlists_beyond_five_thousand_tracks_are_read_in_full() {
+        const TRACKS: usize = 12_000;
+        // 100,000 hours per track, so the total overflows a u32.
+        const HOURS: &str = "100000:00:00";
+
+        let dir = ScratchDir::new("mega-playlist");
+        write_long_playlist(&dir, "PLmega", TRACKS, HOURS);
+        let client = fixture_client(&dir);
+
+        let playlist = client.get_playlist("PLmega", None).await.unwrap();
+        assert_eq!(playlist.tracks.len(), TRACKS);
+        assert!(
+            playlist.tracks.iter().enumerate().all(|(i, track)| {
+                track.video_id.as_deref() == Some(format!("v{i:05}").as_str())
+            })
+        );
+        assert_eq!(playlist.duration_seconds, Some(TRACKS as u64 * 360_000_000));
+
+        let limited = client.get_playlist("PLmega", Some(5_001)).await.unwrap();
+        assert_eq!(limited.tracks.len(), 5_001);
+        let playable = GetPlaylistOptions::new().include_unavailable(false);
+        let playable = client.get_playlist_with("PLmega", &playable).await.unwrap();
+        assert_eq!(playable.tracks.len(), TRACKS);
+    }
+
+    #[tokio::test]
+    async fn edit_verification_reads_a_bounded_part_of_long_playlists() {
+        let dir = ScratchDir::new("verify-long");
+        write_long_playlist(&dir, "PLlong", 3_000, "3:00");
+        dir.write(
+            "browse/edit_playlist/default.json",
+            &json!({
+                "status": "STATUS_SUCCEEDED",
+                "playlistEditResults": [{
+                    "playlistEditVideoAddedResultData": { "videoId": "vnew", "setVideoId": "setnew" }
+                }]
+            })
+            .to_string(),
+        );
+        let client = fixture_builder(&dir)
+            .with_edit_verification(true)
+            .build()
+            .unwrap();
+
+        let result = client
+            .add_playlist_items("PLlong", &["vnew".to_string()], DuplicateHandling::Allow)
+            .await
+            .unwrap();
+        // The new item is appended past the part re-read, so it is reported
+        // rather than checked.
+        assert_eq!(result.unverified, ["setnew"]);
+        let browses = client
+            .fixture_requests()
+            .iter()
+            .filter(|(endpoint, _)| endpoint == "browse")
+            .count();
+        assert_eq!(browses, VERIFY_TRACK_LIMIT as usize / 100);
+    }
 }
@@ -0,0 +1,118 @@
+//! Comparing the local clock with the server's.
+//!
+//! `SAPISIDHASH` headers embed the local Unix time, and Google rejects them
+//! with a 401 when that time is too far from its own. The `Date` header of
+//! every response tells how far off the local clock is.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Clock difference beyond which a rejected request is blamed on the clock.
+pub(crate) const SKEW_TOLERANCE_SECS: i64 = 5 * 60;
+
+/// Seconds since the Unix epoch, in UTC, from the local clock.
+pub(crate) fn unix_now() -> i64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        Err(before) => -(before.duration().as_secs() as i64),
+    }
+}
+
+/// Seconds the server clock is ahead of `now` (negative if behind), from the
+/// value of a `Date` header.
+pub(crate) fn server_offset(date_header: &str, now: i64) -> Option<i64> {
+    Some(parse_http_date(date_header)? - now)
+}
+
+/// The offset, if it is large enough to explain a rejected `SAPISIDHASH`.
+pub(crate) fn significant_skew(offset: i64) -> Option<i64> {
+    (offset.abs() > SKEW_TOLERANCE_SECS).then_some(offset)
+}
+
+/// Explain a clock offset found on a 401 response.
+pub(crate) fn skew_hint(offset: i64) -> String {
+    let direction = if offset > 0 { "behind" } else { "ahead of" };
+    format!(
+        "the local clock is {}s {direction} the server, so the SAPISIDHASH timestamp \
+         was likely rejected; fix the system time or enable \
+         YTMusicClientBuilder::with_clock_correction",
+        offset.abs()
+    )
+}
+
+/// Parse an IMF-fixdate such as `"Sun, 06 Nov 1994 08:49:37 GMT"` into Unix
+/// seconds. Obsolete HTTP date formats are not accepted.
+pub(crate) fn parse_http_date(text: &str) -> Option<i64> {
+    let (_, rest) = text.trim().split_once(", ")?;
+    let mut fields = rest.split(' ');
+    let day: u32 = fields.next()?.parse().ok()?;
+    let month = match fields.next()? {
+        "Jan" => 1,
+        "Feb" => 2,
+        "Mar" => 3,
+        "Apr" => 4,
+        "May" => 5,
+        "Jun" => 6,
+        "Jul" => 7,
+        "Aug" => 8,
+        "Sep" => 9,
+        "Oct" => 10,
+        "Nov" => 11,
+        "Dec" => 12,
+        _ => return None,
+    };
+    let year: i64 = fields.next()?.parse().ok()?;
+    let mut time = fields
+        .next()?
+        .split(':')
+        .map(|part| part.parse::<i64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+    if fields.next()? != "GMT" || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+    Some(days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second)
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (i64::from(month) + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_http_date() {
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(784_111_777)
+        );
+        assert_eq!(parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT"), Some(0));
+        assert_eq!(
+            parse_http_date("Thu, 29 Feb 2024 23:59:59 GMT"),
+            Some(1_709_251_199)
+        );
+        assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), None);
+        assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 PST"), None);
+    }
+
+    #[test]
+    fn test_skew_only_reported_beyond_tolerance() {
+        let date = "Sun, 06 Nov 1994 08:49:37 GMT";
+        // The local clock runs ten minutes late.
+        let offset = server_offset(date, 784_111_777 - 600).unwrap();
+        assert_eq!(offset, 600);
+        assert_eq!(significant_skew(offset), Some(600));
+        assert!(skew_hint(offset).contains("600s behind"));
+
+        let offset = server_offset(date, 784_111_777 + 30).unwrap();
+        assert_eq!(significant_skew(offset), None);
+        assert!(skew_hint(-600).contains("600s ahead of"));
+    }
+}
@@ -0,0 +1,161 @@
+//! Former forms of APIs that have changed, kept for one minor release.
+//!
+//! # Deprecation policy
+//!
+//! When a release changes a public type or signature, the old form stays
+//! available for one more minor release wherever the language allows it:
+//!
+//! - Old methods and functions are kept here, marked `#[deprecated]` with the
+//!   release that replaced them and a note naming the replacement. Each shim
+//!   converts to the new API, so it behaves exactly like it.
+//! - Conversions that keep old call sites compiling (such as `bool` for
+//!   [`DuplicateHandling`]) also live here. Trait implementations cannot be
+//!   marked deprecated, so these are listed in the changelog instead.
+//! - Changed field types cannot be shimmed; an accessor returning the old type
+//!   is added instead. Likewise, a method that now returns a typed result
+//!   keeps the former raw response reachable, such as
+//!   [`AddPlaylistItemsResult::raw`](crate::AddPlaylistItemsResult::raw).
+//!
+//! Everything in this module is removed in the minor release after the one
+//! that deprecated it.
+//!
+//! # Current shims
+//!
+//! | Deprecated | Replacement | Since |
+//! | --- | --- | --- |
+//! | `add_playlist_items(.., bool)` and friends | [`DuplicateHandling`] | 0.5.0 |
+//! | [`PlaylistTrack::video_type_string`] | [`PlaylistTrack::video_type`] | 0.5.0 |
+//! | [`Playlist::year_string`] | [`Playlist::year`] | 0.5.0 |
+//! | [`Playlist::duration_seconds_u32`] | [`Playlist::duration_seconds`] | 0.5.0 |
+//! | [`MovePlaylistItemsResult::add_response`] | [`MovePlaylistItemsResult::added`] | 0.5.0 |
+//! | [`MovePlaylistItemsResult::remove_response`] | [`MovePlaylistItemsResult::removed`] | 0.5.0 |
+//!
+//! Deprecated items keep compiling, with a warning:
+//!
+//! ```
+//! #![allow(deprecated)]
+//! let track = ytmusicapi::PlaylistTrack::default();
+//! assert_eq!(track.video_type_string(), None);
+//! assert_eq!(ytmusicapi::Playlist::default().year_string(), None);
+//! ```
+//!
+//! ```compile_fail
+//! #![deny(deprecated)]
+//! let track = ytmusicapi::PlaylistTrack::default();
+//! let _ = track.video_type_string();
+//! ```
+//!
+//! ```compile_fail
+//! #![deny(deprecated)]
+//! let _ = ytmusicapi::Playlist::default().year_string();
+//! ```
+
+use serde_json::Value;
+
+use crate::types::{DuplicateHandling, MovePlaylistItemsResult, Playlist, PlaylistTrack};
+
+/// Compatibility with the former `allow_duplicates: bool` parameter.
+///
+/// `true` allows duplicates ([`DuplicateHandling::Allow`], sent as
+/// `DEDUPE_OPTION_SKIP`) and `false` leaves out videos already in the
+/// playlist ([`DuplicateHandling::Skip`], sent as `DEDUPE_OPTION_CHECK`).
+/// Versions before 0.5.0 sent these the wrong way round. Prefer passing a
+/// [`DuplicateHandling`] directly; this conversion will be removed in 0.6.0.
+impl From<bool> for DuplicateHandling {
+    fn from(allow_duplicates: bool) -> Self {
+        if allow_duplicates {
+            DuplicateHandling::Allow
+        } else {
+            DuplicateHandling::Skip
+        }
+    }
+}
+
+impl PlaylistTrack {
+    /// Video type as its API constant (e.g., `"MUSIC_VIDEO_TYPE_OMV"`), the
+    /// form `video_type` had before it became a [`MusicVideoType`](crate::MusicVideoType).
+    #[deprecated(
+        since = "0.5.0",
+        note = "use `video_type`, or `MusicVideoType::as_str` for the API constant"
+    )]
+    pub fn video_type_string(&self) -> Option<String> {
+        self.video_type.as_ref().map(|t| t.as_str().to_string())
+    }
+}
+
+impl Playlist {
+    /// Header year as a string, the form `year` had before it was parsed.
+    #[deprecated(since = "0.5.0", note = "use `year`, now parsed as a number")]
+    pub fn year_string(&self) -> Option<String> {
+        self.year.map(|year| year.to_string())
+    }
+
+    /// Total duration in seconds as a `u32`, the type `duration_seconds` had
+    /// before it was widened. Saturates at `u32::MAX`.
+    #[deprecated(since = "0.5.0", note = "use `duration_seconds`, now a `u64`")]
+    pub fn duration_seconds_u32(&self) -> Option<u32> {
+        self.duration_seconds
+            .map(|seconds| u32::try_from(seconds).unwrap_or(u32::MAX))
+    }
+}
+
+impl MovePlaylistItemsResult {
+    /// Raw response of adding the items, the former `add_response` field.
+    #[deprecated(since = "0.5.0", note = "use `added`, or `added.raw` for the response")]
+    pub fn add_response(&self) -> &Value {
+        &self.added.raw
+    }
+
+    /// Raw response of removing the items, the former `remove_response` field.
+    #[deprecated(
+        since = "0.5.0",
+        note = "use `removed`, or `removed.raw` for the response"
+    )]
+    pub fn remove_response(&self) -> &Value {
+        &self.removed.raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(deprecated)]
+
+    use super::*;
+    use crate::types::{AddPlaylistItemsResult, EditPlaylistResponse, MusicVideoType};
+    use serde_json::json;
+
+    #[test]
+    fn test_shims_match_new_fields() {
+        let track = PlaylistTrack {
+            video_type: Some(MusicVideoType::Omv),
+            ..Default::default()
+        };
+        assert_eq!(
+            track.video_type_string().as_deref(),
+            Some("MUSIC_VIDEO_TYPE_OMV")
+        );
+
+        let playlist = Playlist {
+            year: Some(2024),
+            ..Default::default()
+        };
+        assert_eq!(playlist.year_string().as_deref(), Some("2024"));
+        let long = Playlist {
+            duration_seconds: Some(u64::from(u32::MAX) + 1),
+            ..Default::default()
+        };
+        assert_eq!(long.duration_seconds_u32(), Some(u32::MAX));
+        assert_eq!(Playlist::default().duration_seconds_u32(), None);
+        assert_eq!(DuplicateHandling::from(false), DuplicateHandling::Skip);
+
+        let moved = MovePlaylistItemsResult {
+            added: AddPlaylistItemsResult::from_response(
+                json!({"status": "STATUS_SUCCEEDED"}),
+                &[],
+            ),
+            removed: EditPlaylistResponse::from_response(json!({"status": "STATUS_FAILED"})),
+        };
+        assert_eq!(moved.add_response()["status"], "STATUS_SUCCEEDED");
+        assert_eq!(moved.remove_response()["status"], "STATUS_FAILED");
+    }
+}
@@ -0,0 +1,177 @@
+//! Reading continuation responses of any `browse` endpoint.
+//!
+//! Long listings arrive in pages: the first response ends with a token, and
+//! each token is sent back to get the next page. Every listing the crate
+//! parses uses these helpers, and they work just as well for endpoints it does
+//! not type yet:
+//!
+//! ```no_run
+//! # async fn demo(client: &ytmusicapi::YTMusicClient, first_token: String) -> ytmusicapi::Result<()> {
+//! use ytmusicapi::continuation::{extract_items, extract_next_token};
+//!
+//! let mut token = Some(first_token);
+//! while let Some(current) = token {
+//!     let page = client.fetch_continuation(&current).await?;
+//!     println!("{} items", extract_items(&page).len());
+//!     token = extract_next_token(&page);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Three response shapes are understood:
+//!
+//! - `continuationContents` holding a shelf or section list continuation
+//!   (`musicPlaylistShelfContinuation`, `musicShelfContinuation`,
+//!   `sectionListContinuation`), with the items in `contents`.
+//! - `continuationContents.gridContinuation`, with the items in `items`.
+//! - `onResponseReceivedActions` or `onResponseReceivedCommands` holding an
+//!   `appendContinuationItemsAction`, with the items in `continuationItems`.
+//!
+//! In each, the next token is either a trailing `continuationItemRenderer`
+//! item or a `nextContinuationData` entry.
+
+use serde_json::Value;
+
+use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::navigation::paths;
+
+/// Continuation containers under `continuationContents`, with the key of
+/// their item list.
+const CONTAINERS: [(&str, &str); 4] = [
+    ("musicPlaylistShelfContinuation", "contents"),
+    ("musicShelfContinuation", "contents"),
+    ("sectionListContinuation", "contents"),
+    ("gridContinuation", "items"),
+];
+
+/// The items of a continuation response, in server order.
+///
+/// A trailing `continuationItemRenderer` is included; it is skipped by the
+/// crate's row parsers. Returns an empty slice for an unknown shape.
+pub fn extract_items(response: &Value) -> &[Value] {
+    continuation_rows(response).0
+}
+
+/// The token for the page after this continuation response, if any.
+pub fn extract_next_token(response: &Value) -> Option<String> {
+    continuation_rows(response).1
+}
+
+/// Locate the raw rows and next token of a continuation response without
+/// parsing the rows.
+///
+/// This is cheap, so pagination can start the next request before parsing.
+pub(crate) fn continuation_rows(response: &Value) -> (&[Value], Option<String>) {
+    for (key, rows_key) in CONTAINERS {
+        if let Some(container) = nav(response, &path!["continuationContents", key]) {
+            let rows = nav_array(container, &path![rows_key])
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            return (rows, get_continuation_token(container));
+        }
+    }
+
+    let rows = ["onResponseReceivedActions", "onResponseReceivedCommands"]
+        .into_iter()
+        .find_map(|key| {
+            nav_array(
+                response,
+                &path![key, 0, "appendContinuationItemsAction", "continuationItems"],
+            )
+        });
+    match rows {
+        Some(rows) => (
+            rows,
+            rows.last()
+                .and_then(|last| nav_str(last, paths::CONTINUATION_TOKEN))
+                .map(str::to_string),
+        ),
+        None => (&[], None),
+    }
+}
+
+/// The next-page token of a shelf or grid renderer, from a trailing
+/// `continuationItemRenderer` or from `nextContinuationData`.
+pub(crate) fn get_continuation_token(results: &Value) -> Option<String> {
+    results
+        .get("contents")
+        .or_else(|| results.get("items"))
+        .and_then(|v| v.as_array())
+        .and_then(|contents| contents.last())
+        .and_then(|last| nav_str(last, paths::CONTINUATION_TOKEN))
+        .or_else(|| {
+            nav_str(
+                results,
+                &path!["continuations", 0, "nextContinuationData", "continuation"],
+            )
+        })
+        .map(|s| s.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn continuation_item(token: &str) -> Value {
+        json!({
+            "continuationItemRenderer": {
+                "continuationEndpoint": { "continuationCommand": { "token": token } }
+            }
+        })
+    }
+
+    #[test]
+    fn test_continuation_contents_shapes() {
+        for (key, rows_key) in CONTAINERS {
+            let response = json!({
+                "continuationContents": {
+                    key: { rows_key: [{ "row": 1 }, continuation_item("next")] }
+                }
+            });
+            assert_eq!(extract_items(&response).len(), 2, "{key}");
+            assert_eq!(extract_next_token(&response).as_deref(), Some("next"));
+        }
+
+        let legacy_token = json!({
+            "continuationContents": {
+                "gridContinuation": {
+                    "items": [{ "row": 1 }],
+                    "continuations": [{ "nextContinuationData": { "continuation": "page3" } }]
+                }
+            }
+        });
+        assert_eq!(extract_items(&legacy_token).len(), 1);
+        assert_eq!(extract_next_token(&legacy_token).as_deref(), Some("page3"));
+    }
+
+    #[test]
+    fn test_append_action_shapes() {
+        for key in ["onResponseReceivedActions", "onResponseReceivedCommands"] {
+            let response = json!({
+                key: [{
+                    "appendContinuationItemsAction": {
+                        "continuationItems": [{ "row": 1 }, continuation_item("t2")]
+                    }
+                }]
+            });
+            assert_eq!(extract_items(&response).len(), 2, "{key}");
+            assert_eq!(extract_next_token(&response).as_deref(), Some("t2"));
+        }
+
+        let last_page = json!({
+            "onResponseReceivedActions": [{
+                "appendContinuationItemsAction": { "continuationItems": [{ "row": 1 }] }
+            }]
+        });
+        assert_eq!(extract_next_token(&last_page), None);
+    }
+
+    #[test]
+    fn test_unknown_shape_is_empty() {
+        assert!(extract_items(&json!({})).is_empty());
+        assert_eq!(extract_next_token(&json!({ "contents": {} })), None);
+    }
+}
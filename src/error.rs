@@ -1,5 +1,7 @@
 //! Error types for the YouTube Music API client.
 
+use crate::types::AddPlaylistItemsResult;
+
 /// The error type for YouTube Music API operations.
 ///
 /// Methods in this crate return `Result<T, Error>`. No automatic retries are
@@ -25,6 +27,32 @@ pub enum Error {
         status: u16,
         /// Error message from server
         message: String,
+        /// Identifiers from the response useful when reporting the failure.
+        meta: Option<Box<ResponseMeta>>,
+    },
+
+    /// The server responded with something other than JSON (for example a
+    /// consent page, captcha, or proxy error page).
+    #[error("Unexpected {content_type} response: {snippet}")]
+    UnexpectedContent {
+        /// The `content-type` header of the response, or `"unknown"`.
+        content_type: String,
+        /// The first ~200 characters of the response body.
+        snippet: String,
+    },
+
+    /// Google is asking the signed-in user to re-verify their identity.
+    ///
+    /// While a challenge is pending every request returns a prompt instead of
+    /// data. Open `url` (when the response carries one) in a browser signed in
+    /// to the same account, complete the check, then retry.
+    #[error(
+        "Account challenge: verify the account in a browser{}",
+        .url.as_deref().map(|url| format!(" at {url}")).unwrap_or_default()
+    )]
+    AccountChallenge {
+        /// Verification URL from the challenge prompt, if present.
+        url: Option<String>,
     },
 
     /// Failed to navigate JSON response.
@@ -42,10 +70,172 @@ pub enum Error {
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
+    /// One or more client builder options are invalid.
+    ///
+    /// Returned by [`YTMusicClientBuilder::build`](crate::YTMusicClientBuilder::build)
+    /// with every problem found, not just the first.
+    #[error("Invalid configuration: {}", join_config_errors(.0))]
+    Config(Vec<ConfigError>),
+
+    /// The requested feature is not available for this account or playlist.
+    ///
+    /// Returned when the backend rejects an action that is only rolled out to
+    /// some accounts, so callers can feature-detect instead of treating it as a
+    /// generic server failure.
+    #[error("Feature unavailable: {0}")]
+    Unsupported(String),
+
+    /// The server accepted an edit, but the playlist does not reflect it.
+    ///
+    /// Only returned with
+    /// [`YTMusicClientBuilder::with_edit_verification`](crate::YTMusicClientBuilder::with_edit_verification).
+    /// Among the items the edit touched, `expected` lists those that should
+    /// now be in the playlist and `observed` those that are. Seen with edits
+    /// sent on behalf of the wrong brand account and with replication delays.
+    #[error("Edit not reflected: expected {expected:?} in the playlist, found {observed:?}")]
+    VerificationFailed {
+        /// Touched items that should be in the playlist.
+        expected: Vec<String>,
+        /// Touched items found in the playlist.
+        observed: Vec<String>,
+    },
+
+    /// Videos to add were already in the playlist and were not added.
+    ///
+    /// Only returned by
+    /// [`YTMusicClient::add_playlist_items`](crate::YTMusicClient::add_playlist_items)
+    /// and friends with [`DuplicateHandling::Fail`](crate::DuplicateHandling::Fail).
+    /// The other videos of the same request were still added: `result` is
+    /// what a successful call would have returned, with the added items and
+    /// their set video IDs, and the videos left out in
+    /// [`skipped`](crate::AddPlaylistItemsResult::skipped).
+    #[error("Already in the playlist: {:?}", result.skipped)]
+    Duplicates {
+        /// The add as the server applied it.
+        result: Box<AddPlaylistItemsResult>,
+    },
+
+    /// The server answered a change without applying it.
+    ///
+    /// Returned by multi-step operations such as
+    /// [`YTMusicClient::execute_prune`](crate::YTMusicClient::execute_prune)
+    /// when a request succeeded but its result reports that the change was
+    /// not made, e.g. an unsubscription the server did not confirm. The
+    /// message names the change.
+    #[error("Not applied: {0}")]
+    NotApplied(String),
+
+    /// The server rejected the `clientVersion` sent in the request context as
+    /// unsupported or outdated.
+    ///
+    /// The web client version is derived from the current date. Requests
+    /// rejected this way are retried once with the previous day's version;
+    /// this is returned when the retry is rejected too, with the version it
+    /// sent. Such rejections usually clear up within a day.
+    #[error("Client version {sent_version} rejected as unsupported")]
+    ClientVersionRejected {
+        /// The `clientVersion` of the rejected request.
+        sent_version: String,
+    },
+
     /// I/O error.
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
 
+/// An invalid client builder option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    /// Builder option at fault, e.g. `"language"`.
+    pub option: &'static str,
+    /// The constraint the value violates.
+    pub problem: String,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.option, self.problem)
+    }
+}
+
+fn join_config_errors(errors: &[ConfigError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Debugging identifiers extracted from a response.
+///
+/// Quote these when reporting problems (for example edits that are accepted but
+/// not applied); they are what Google's tooling keys on.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ResponseMeta {
+    /// Tracking parameter from `responseContext.mainAppWebResponseContext`.
+    pub trace_id: Option<String>,
+    /// Visitor data from `responseContext.visitorData`.
+    pub visitor_data: Option<String>,
+    /// Value of the HTTP `server` header.
+    pub served_by: Option<String>,
+    /// Seconds the local clock is behind the server's (negative if ahead),
+    /// set on a 401 when the difference is large enough to explain it.
+    pub clock_skew_seconds: Option<i64>,
+}
+
+impl ResponseMeta {
+    /// Extract debugging identifiers from a raw JSON response.
+    ///
+    /// Useful with [`YTMusicClient::send_request`](crate::YTMusicClient::send_request),
+    /// whose successful responses carry the same `responseContext`.
+    pub fn from_response(response: &serde_json::Value) -> Self {
+        let context = response.get("responseContext");
+        let field = |value: Option<&serde_json::Value>| {
+            value.and_then(|v| v.as_str()).map(|s| s.to_string())
+        };
+
+        Self {
+            trace_id: field(
+                context
+                    .and_then(|c| c.get("mainAppWebResponseContext"))
+                    .and_then(|c| c.get("trackingParam")),
+            ),
+            visitor_data: field(context.and_then(|c| c.get("visitorData"))),
+            served_by: None,
+            clock_skew_seconds: None,
+        }
+    }
+
+    /// Whether no identifiers were found.
+    pub fn is_empty(&self) -> bool {
+        self.trace_id.is_none()
+            && self.visitor_data.is_none()
+            && self.served_by.is_none()
+            && self.clock_skew_seconds.is_none()
+    }
+}
+
 /// A specialized Result type for YouTube Music API operations.
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_response_meta_from_response() {
+        let response = json!({
+            "responseContext": {
+                "visitorData": "Cgt2aXNpdG9y",
+                "mainAppWebResponseContext": { "trackingParam": "kx_fmPxhoPZR" }
+            }
+        });
+
+        let meta = ResponseMeta::from_response(&response);
+        assert_eq!(meta.trace_id.as_deref(), Some("kx_fmPxhoPZR"));
+        assert_eq!(meta.visitor_data.as_deref(), Some("Cgt2aXNpdG9y"));
+        assert!(!meta.is_empty());
+        assert!(ResponseMeta::from_response(&json!({})).is_empty());
+    }
+}
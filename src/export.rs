@@ -0,0 +1,215 @@
+//! Writing playlists to CSV or M3U files.
+//!
+//! [`TrackWriter`] is incremental: the header is written once, then each page
+//! of tracks is appended and flushed as it arrives. Combined with
+//! [`YTMusicClient::export_playlist`](crate::YTMusicClient::export_playlist),
+//! which streams continuation pages instead of collecting them, playlists of
+//! any length are exported in constant memory.
+//!
+//! ```no_run
+//! # async fn demo(client: &ytmusicapi::YTMusicClient) -> ytmusicapi::Result<()> {
+//! use std::fs::File;
+//! use std::io::BufWriter;
+//!
+//! use ytmusicapi::export::ExportFormat;
+//!
+//! let file = BufWriter::new(File::create("liked.csv")?);
+//! let written = client.export_liked_songs(ExportFormat::Csv, file).await?;
+//! println!("exported {written} tracks");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::{self, Write};
+
+use crate::types::PlaylistTrack;
+
+/// Output format of an export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row: video ID, title, artists,
+    /// album and duration in seconds. Every track is written.
+    Csv,
+    /// An extended M3U playlist of `music.youtube.com` watch URLs. Tracks
+    /// without a video ID cannot be linked and are skipped.
+    M3u,
+}
+
+/// Writes tracks to `W` one page at a time.
+#[derive(Debug)]
+pub struct TrackWriter<W: Write> {
+    writer: W,
+    format: ExportFormat,
+    started: bool,
+    written: usize,
+}
+
+impl<W: Write> TrackWriter<W> {
+    /// Create a writer. Nothing is written until the first page or
+    /// [`finish`](Self::finish).
+    pub fn new(writer: W, format: ExportFormat) -> Self {
+        Self {
+            writer,
+            format,
+            started: false,
+            written: 0,
+        }
+    }
+
+    /// Append a page of tracks and flush, writing the header first if this is
+    /// the first page.
+    pub fn write_tracks(&mut self, tracks: &[PlaylistTrack]) -> io::Result<()> {
+        self.start()?;
+        for track in tracks {
+            let written = match self.format {
+                ExportFormat::Csv => write_csv_row(&mut self.writer, track)?,
+                ExportFormat::M3u => write_m3u_entry(&mut self.writer, track)?,
+            };
+            self.written += usize::from(written);
+        }
+        self.writer.flush()
+    }
+
+    /// Number of tracks written so far.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Write the header if no page was written, flush, and return the sink.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.start()?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn start(&mut self) -> io::Result<()> {
+        if !self.started {
+            self.started = true;
+            match self.format {
+                ExportFormat::Csv => {
+                    writeln!(self.writer, "video_id,title,artists,album,duration_seconds")?
+                }
+                ExportFormat::M3u => writeln!(self.writer, "#EXTM3U")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn artist_names(track: &PlaylistTrack) -> String {
+    track
+        .artists
+        .iter()
+        .map(|artist| artist.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn write_csv_row(writer: &mut impl Write, track: &PlaylistTrack) -> io::Result<bool> {
+    writeln!(
+        writer,
+        "{},{},{},{},{}",
+        csv_field(track.video_id.as_deref().unwrap_or_default()),
+        csv_field(track.title.as_deref().unwrap_or_default()),
+        csv_field(&artist_names(track)),
+        csv_field(track.album.as_ref().map_or("", |album| album.name.as_str())),
+        track
+            .duration_seconds
+            .map_or_else(String::new, |seconds| seconds.to_string()),
+    )?;
+    Ok(true)
+}
+
+fn write_m3u_entry(writer: &mut impl Write, track: &PlaylistTrack) -> io::Result<bool> {
+    let Some(video_id) = track.video_id.as_deref() else {
+        return Ok(false);
+    };
+    // Line breaks would end the #EXTINF line early.
+    let label = format!(
+        "{} - {}",
+        artist_names(track),
+        track.title.as_deref().unwrap_or(video_id)
+    )
+    .replace(['\n', '\r'], " ");
+    let duration = track.duration_seconds.map_or(-1, i64::from);
+    writeln!(writer, "#EXTINF:{duration},{label}")?;
+    writeln!(writer, "https://music.youtube.com/watch?v={video_id}")?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Album, Artist};
+
+    fn track(video_id: Option<&str>, title: &str) -> PlaylistTrack {
+        PlaylistTrack {
+            video_id: video_id.map(str::to_string),
+            title: Some(title.to_string()),
+            artists: vec![
+                Artist {
+                    name: "Ana".to_string(),
+                    id: None,
+                },
+                Artist {
+                    name: "Bo".to_string(),
+                    id: None,
+                },
+            ],
+            album: Some(Album {
+                name: "Live, \"Loud\"".to_string(),
+                id: None,
+            }),
+            duration_seconds: Some(222),
+            ..Default::default()
+        }
+    }
+
+    fn export(format: ExportFormat, pages: &[Vec<PlaylistTrack>]) -> String {
+        let mut writer = TrackWriter::new(Vec::new(), format);
+        for page in pages {
+            writer.write_tracks(page).unwrap();
+        }
+        String::from_utf8(writer.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_csv_writes_header_once_and_quotes_fields() {
+        let pages = [
+            vec![track(Some("v1"), "One")],
+            vec![track(None, "Two\nLines")],
+        ];
+        assert_eq!(
+            export(ExportFormat::Csv, &pages),
+            "video_id,title,artists,album,duration_seconds\n\
+             v1,One,\"Ana, Bo\",\"Live, \"\"Loud\"\"\",222\n\
+             ,\"Two\nLines\",\"Ana, Bo\",\"Live, \"\"Loud\"\"\",222\n"
+        );
+        assert_eq!(
+            export(ExportFormat::Csv, &[]),
+            "video_id,title,artists,album,duration_seconds\n"
+        );
+    }
+
+    #[test]
+    fn test_m3u_skips_tracks_without_video_id() {
+        let mut writer = TrackWriter::new(Vec::new(), ExportFormat::M3u);
+        writer
+            .write_tracks(&[track(Some("v1"), "One"), track(None, "Gone")])
+            .unwrap();
+        assert_eq!(writer.written(), 1);
+        assert_eq!(
+            String::from_utf8(writer.finish().unwrap()).unwrap(),
+            "#EXTM3U\n#EXTINF:222,Ana, Bo - One\nhttps://music.youtube.com/watch?v=v1\n"
+        );
+    }
+}
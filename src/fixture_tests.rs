@@ -0,0 +1,385 @@
+//! Snapshot tests running the parsers over recorded responses.
+//!
+//! Each directory under `tests/fixtures/` holds responses of one kind, parsed
+//! by the matching entry in [`KINDS`]. Next to every `<name>.json` lives
+//! `<name>.expected.json`, the parser output serialized as JSON. To add a
+//! fixture, drop in the (anonymized) response and run
+//!
+//! ```text
+//! UPDATE_FIXTURES=1 cargo test fixture
+//! ```
+//!
+//! to write its expected output, then review the result before committing.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::future::{Future, Ready, ready};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::client::{
+    account_challenge, api_error, collect_library_playlists, collect_playlist_suggestions,
+    collect_related_playlists, rejects_client_version, reorder_actions,
+};
+use crate::continuation::continuation_rows;
+use crate::error::{Error, ResponseMeta, Result};
+use crate::parsers::account::{parse_account_info, parse_capabilities};
+use crate::parsers::album::parse_album_page;
+use crate::parsers::artist::{parse_artist_page, parse_artist_releases, parse_release_items};
+use crate::parsers::explore::{parse_charts, parse_mood_categories, parse_mood_playlists};
+use crate::parsers::history::{parse_history, parse_history_sections};
+use crate::parsers::home::{parse_home, parse_home_sections};
+use crate::parsers::library::{
+    parse_library_album_items, parse_library_albums, parse_library_artist_rows,
+    parse_library_artists, parse_library_song_rows, parse_library_songs,
+    parse_library_track_artist_rows, parse_library_track_artists,
+};
+use crate::parsers::locale::ENGLISH;
+use crate::parsers::lyrics::{parse_lyrics, parse_timed_lyrics};
+use crate::parsers::playlist::parse_liked_music_summary;
+use crate::parsers::song::parse_song;
+use crate::parsers::upload::{
+    parse_upload_albums, parse_upload_artists, parse_upload_song_rows, parse_upload_songs,
+};
+use crate::parsers::user::{parse_user_page, parse_user_playlists};
+use crate::parsers::watch::{
+    parse_queue_like_statuses, parse_song_related, parse_watch_playlist, parse_watch_rows,
+    watch_continuation_rows,
+};
+use crate::parsers::{
+    parse_library_playlists_continuation, parse_library_playlists_page, parse_playlist_response,
+    parse_playlist_track_rows, parse_search_results, parse_search_rows, parse_search_suggestions,
+};
+
+/// Runs a parser over a response and serializes its output.
+type Parse = fn(&Value) -> Value;
+
+/// Fixture directory name and the parser its responses are run through.
+const KINDS: &[(&str, Parse)] = &[
+    ("playlist", |response| {
+        let (playlist, continuation) = parse_playlist_response(response, "VLfixture", "en");
+        json!({ "playlist": playlist, "continuation": continuation })
+    }),
+    ("playlist_continuation", |response| {
+        let (rows, continuation) = continuation_rows(response);
+        let mut warnings = Vec::new();
+        let tracks = parse_playlist_track_rows(rows, &ENGLISH, &mut warnings);
+        json!({ "tracks": tracks, "continuation": continuation, "warnings": warnings })
+    }),
+    ("library", |response| {
+        to_value(parse_library_playlists_page(response))
+    }),
+    ("library_continuation", |response| {
+        to_value(parse_library_playlists_continuation(response))
+    }),
+    ("liked_summary", |response| {
+        to_value(parse_liked_music_summary(response))
+    }),
+    ("library_pages", |request| {
+        let replay = Replay(request, RefCell::default());
+        let playlists = replay.run(collect_library_playlists(
+            &request["first"],
+            replay.limit(),
+            |token| replay.fetch(token),
+        ));
+        json!({ "playlists": playlists, "requested": replay.1.take() })
+    }),
+    ("playlist_suggestions", |request| {
+        let replay = Replay(request, RefCell::default());
+        let suggestions = replay.run(collect_playlist_suggestions(
+            &request["first"],
+            replay.limit(),
+            |token| replay.fetch(token),
+        ));
+        json!({ "suggestions": suggestions, "requested": replay.1.take() })
+    }),
+    ("playlist_related", |request| {
+        let replay = Replay(request, RefCell::default());
+        let related = replay.run(collect_related_playlists(&request["first"], |token| {
+            replay.fetch(token)
+        }));
+        json!({ "related": related, "requested": replay.1.take() })
+    }),
+    ("album", |response| {
+        to_value(parse_album_page(response, "MPREb_fixture", "en"))
+    }),
+    ("library_artists", |response| {
+        let (artists, continuation) = parse_library_artists(response);
+        json!({ "artists": artists, "continuation": continuation })
+    }),
+    ("library_artists_continuation", |response| {
+        let (rows, continuation) = continuation_rows(response);
+        json!({ "artists": parse_library_artist_rows(rows), "continuation": continuation })
+    }),
+    ("library_songs", |response| {
+        let (tracks, continuation) = parse_library_songs(response);
+        json!({ "tracks": tracks, "continuation": continuation })
+    }),
+    ("library_songs_continuation", |response| {
+        let (rows, continuation) = continuation_rows(response);
+        json!({ "tracks": parse_library_song_rows(rows), "continuation": continuation })
+    }),
+    ("new_releases", |response| {
+        let (albums, continuation) = parse_library_albums(response);
+        json!({ "albums": albums, "continuation": continuation })
+    }),
+    ("library_albums", |response| {
+        let (albums, continuation) = parse_library_albums(response);
+        json!({ "albums": albums, "continuation": continuation })
+    }),
+    ("library_albums_continuation", |response| {
+        let (rows, continuation) = continuation_rows(response);
+        json!({ "albums": parse_library_album_items(rows), "continuation": continuation })
+    }),
+    ("library_track_artists", |response| {
+        let (artists, continuation) = parse_library_track_artists(response);
+        json!({ "artists": artists, "continuation": continuation })
+    }),
+    ("library_track_artists_continuation", |response| {
+        let (rows, continuation) = continuation_rows(response);
+        json!({ "artists": parse_library_track_artist_rows(rows), "continuation": continuation })
+    }),
+    ("upload_songs", |response| {
+        let (tracks, continuation) = parse_upload_songs(response);
+        json!({ "tracks": tracks, "continuation": continuation })
+    }),
+    ("upload_songs_continuation", |response| {
+        let (rows, continuation) = continuation_rows(response);
+        json!({ "tracks": parse_upload_song_rows(rows), "continuation": continuation })
+    }),
+    ("upload_albums", |response| {
+        let (albums, continuation) = parse_upload_albums(response);
+        json!({ "albums": albums, "continuation": continuation })
+    }),
+    ("upload_artists", |response| {
+        let (artists, continuation) = parse_upload_artists(response);
+        json!({ "artists": artists, "continuation": continuation })
+    }),
+    ("history", |response| to_value(parse_history(response))),
+    ("lyrics", |response| to_value(parse_lyrics(response))),
+    ("timed_lyrics", |response| {
+        to_value(parse_timed_lyrics(response))
+    }),
+    ("charts", |response| to_value(parse_charts(response))),
+    ("home", |response| {
+        let (sections, continuation) = parse_home(response);
+        json!({ "sections": sections, "continuation": continuation })
+    }),
+    ("home_continuation", |response| {
+        let (rows, continuation) = continuation_rows(response);
+        json!({ "sections": parse_home_sections(rows), "continuation": continuation })
+    }),
+    ("mood_categories", |response| {
+        to_value(parse_mood_categories(response))
+    }),
+    ("mood_playlists", |response| {
+        let (playlists, continuation) = parse_mood_playlists(response);
+        json!({ "playlists": playlists, "continuation": continuation })
+    }),
+    ("queue", |response| {
+        let statuses: BTreeMap<_, _> = parse_queue_like_statuses(response).into_iter().collect();
+        to_value(statuses)
+    }),
+    ("song_related", |response| {
+        to_value(parse_song_related(response))
+    }),
+    ("watch", |response| {
+        let (playlist, continuation) = parse_watch_playlist(response);
+        json!({ "playlist": playlist, "continuation": continuation })
+    }),
+    ("watch_continuation", |response| {
+        let (rows, continuation) = watch_continuation_rows(response);
+        json!({ "tracks": parse_watch_rows(rows), "continuation": continuation })
+    }),
+    ("history_continuation", |response| {
+        let (rows, continuation) = continuation_rows(response);
+        json!({ "buckets": parse_history_sections(rows), "continuation": continuation })
+    }),
+    ("artist", |response| {
+        to_value(parse_artist_page(response, "UCfixture", "en"))
+    }),
+    ("artist_albums", |response| {
+        let (releases, continuation) = parse_artist_releases(response);
+        json!({ "releases": releases, "continuation": continuation })
+    }),
+    ("artist_albums_continuation", |response| {
+        let (rows, continuation) = continuation_rows(response);
+        json!({ "releases": parse_release_items(rows), "continuation": continuation })
+    }),
+    ("search", |response| {
+        let (results, continuation) = parse_search_results(response);
+        json!({ "results": results, "continuation": continuation })
+    }),
+    ("search_continuation", |response| {
+        let (rows, continuation) = continuation_rows(response);
+        json!({ "results": parse_search_rows(rows), "continuation": continuation })
+    }),
+    ("search_suggestions", |response| {
+        to_value(parse_search_suggestions(response))
+    }),
+    ("account_menu", |response| {
+        json!({
+            "account": to_value(parse_account_info(response).expect("account header")),
+            "capabilities": to_value(parse_capabilities(response)),
+        })
+    }),
+    ("player", |response| {
+        to_value(parse_song(response, "en").expect("player response"))
+    }),
+    ("user", |response| {
+        to_value(parse_user_page(response, "UCfixture"))
+    }),
+    ("user_playlists", |response| {
+        let (playlists, continuation) = parse_user_playlists(response);
+        json!({ "playlists": playlists, "continuation": continuation })
+    }),
+    ("reorder_playlist", |request| {
+        let ids = |key: &str| -> Vec<String> {
+            serde_json::from_value(request[key].clone()).expect("list of set video IDs")
+        };
+        match reorder_actions(&ids("current"), &ids("target")) {
+            Ok(actions) => json!({ "actions": actions }),
+            Err(error) => json!({ "error": error.to_string() }),
+        }
+    }),
+    ("errors", |response| {
+        if rejects_client_version(response) {
+            return json!({ "client_version_rejected": true });
+        }
+        match api_error(response, None).or_else(|| account_challenge(response)) {
+            Some(Error::Server {
+                status,
+                message,
+                meta,
+            }) => json!({ "status": status, "message": message, "meta": meta }),
+            Some(Error::AccountChallenge { url }) => json!({ "account_challenge": url }),
+            other => json!({
+                "error": other.map(|e| e.to_string()),
+                "meta": ResponseMeta::from_response(response),
+            }),
+        }
+    }),
+];
+
+/// A recorded multi-page request: `{"first": .., "continuations": {token:
+/// ..}, "limit": ..}`, and the tokens requested so far.
+struct Replay<'a>(&'a Value, RefCell<Vec<String>>);
+
+impl Replay<'_> {
+    fn limit(&self) -> usize {
+        self.0["limit"].as_u64().map_or(usize::MAX, |n| n as usize)
+    }
+
+    fn fetch(&self, token: String) -> Ready<Result<Value>> {
+        self.1.borrow_mut().push(token.clone());
+        let page = self.0["continuations"].get(&token).cloned();
+        ready(page.ok_or(Error::InvalidInput(format!("unknown token {token}"))))
+    }
+
+    fn run<T>(&self, collect: impl Future<Output = Result<T>>) -> T {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(collect)
+            .expect("every requested page is recorded")
+    }
+}
+
+fn to_value(value: impl Serialize) -> Value {
+    serde_json::to_value(value).expect("parser output serializes")
+}
+
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// Response files in `dir`, skipping expected outputs.
+fn responses(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "json")
+                && !path.to_string_lossy().ends_with(".expected.json")
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+fn read_json(path: &Path) -> Value {
+    let text = fs::read_to_string(path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+    serde_json::from_str(&text).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+#[test]
+fn test_fixtures_match_expected_output() {
+    let update = std::env::var_os("UPDATE_FIXTURES").is_some();
+    let mut failures = Vec::new();
+    let mut checked = 0;
+
+    let mut dirs: Vec<_> = fs::read_dir(fixtures_dir())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+
+    for dir in dirs {
+        let kind = dir.file_name().unwrap().to_string_lossy().into_owned();
+        let Some((_, parse)) = KINDS.iter().find(|(name, _)| *name == kind) else {
+            failures.push(format!("{kind}: no parser registered in KINDS"));
+            continue;
+        };
+
+        for path in responses(&dir) {
+            let name = format!("{kind}/{}", path.file_name().unwrap().to_string_lossy());
+            let actual = parse(&read_json(&path));
+            let expected_path = path.with_extension("expected.json");
+
+            if update {
+                let text = serde_json::to_string_pretty(&actual).unwrap() + "\n";
+                fs::write(&expected_path, text).unwrap();
+            } else if !expected_path.exists() {
+                failures.push(format!(
+                    "{name}: missing {}; run with UPDATE_FIXTURES=1 to create it",
+                    expected_path.display()
+                ));
+            } else if actual != read_json(&expected_path) {
+                failures.push(format!(
+                    "{name}: output differs from {}:\n{}",
+                    expected_path.display(),
+                    serde_json::to_string_pretty(&actual).unwrap()
+                ));
+            }
+            checked += 1;
+        }
+    }
+
+    assert!(
+        checked > 0,
+        "no fixtures found in {}",
+        fixtures_dir().display()
+    );
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
+
+/// Empty states (fresh accounts, channels without playlists) are data, not
+/// errors: only the `errors` fixtures may trip the response checks.
+#[test]
+fn test_only_error_fixtures_are_errors() {
+    for entry in fs::read_dir(fixtures_dir()).unwrap() {
+        let dir = entry.unwrap().path();
+        if !dir.is_dir() || dir.ends_with("errors") {
+            continue;
+        }
+        for path in responses(&dir) {
+            let response = read_json(&path);
+            let error = api_error(&response, None).or_else(|| account_challenge(&response));
+            assert!(error.is_none(), "{}: {error:?}", path.display());
+        }
+    }
+}
@@ -7,14 +7,81 @@
 //! ## Supported Operations
 //!
 //! - Read library playlists: [`YTMusicClient::get_library_playlists`]
-//! - Fetch playlist metadata and tracks: [`YTMusicClient::get_playlist`]
-//! - Fetch your "Liked Songs": [`YTMusicClient::get_liked_songs`]
-//! - Create/delete playlists: [`YTMusicClient::create_playlist`], [`YTMusicClient::delete_playlist`]
+//! - Fetch playlist metadata, tracks and related playlists: [`YTMusicClient::get_playlist`],
+//!   [`YTMusicClient::get_playlist_with`]
+//! - Fetch your "Liked Songs": [`YTMusicClient::get_liked_songs`],
+//!   [`YTMusicClient::get_liked_songs_summary`]
+//! - Stream a playlist page by page, or export it to CSV or M3U in constant memory:
+//!   [`YTMusicClient::stream_playlist_tracks`], [`YTMusicClient::export_playlist`],
+//!   [`YTMusicClient::export_liked_songs`]
+//! - List songs saved to your library, in a chosen order: [`YTMusicClient::get_library_songs`]
+//! - Read, add to and scrub your listening history: [`YTMusicClient::get_history`],
+//!   [`YTMusicClient::get_history_since`],
+//!   [`YTMusicClient::add_history_item`], [`YTMusicClient::remove_history_items`]
+//! - Find a saved track across all library playlists by fuzzy title or artist:
+//!   [`YTMusicClient::search_library_tracks`]
+//! - List library albums and artists: [`YTMusicClient::get_library_albums`],
+//!   [`YTMusicClient::get_library_artists`]
+//! - List uploaded songs, albums and artists: [`YTMusicClient::get_library_upload_songs`],
+//!   [`YTMusicClient::get_library_upload_albums`], [`YTMusicClient::get_library_upload_artists`]
+//! - Upload and delete songs: [`YTMusicClient::upload_song`], [`YTMusicClient::delete_upload_entity`]
+//! - Create/edit/delete playlists: [`YTMusicClient::create_playlist`],
+//!   [`YTMusicClient::create_playlist_with`], [`YTMusicClient::edit_playlist`],
+//!   [`YTMusicClient::delete_playlist`]
 //! - Add/remove/move playlist items: [`YTMusicClient::add_playlist_items`],
 //!   [`YTMusicClient::remove_playlist_items`], [`YTMusicClient::move_playlist_items`]
+//! - Add only tracks passing a [`TrackFilter`] (explicitness, availability,
+//!   duration, video type): [`YTMusicClient::add_playlist_tracks_filtered`]
+//! - Get the songs suggested for an owned playlist: [`YTMusicClient::get_playlist_suggestions`]
+//! - Append a whole playlist to another in one request:
+//!   [`YTMusicClient::add_playlist_to_playlist`]
+//! - Reorder a playlist: [`YTMusicClient::move_item_within_playlist`],
+//!   [`YTMusicClient::reorder_playlist`]
+//! - Remove a run of playlist items by position: [`YTMusicClient::remove_playlist_range`]
+//! - Undo playlist edits from an [`UndoToken`]: [`YTMusicClient::undo`]
 //! - Rate songs: [`YTMusicClient::rate_song`], [`YTMusicClient::like_song`],
 //!   [`YTMusicClient::unlike_song`]
+//! - Look up the like status of many tracks in a few requests:
+//!   [`YTMusicClient::get_like_statuses`]
+//! - Save community playlists to your library by liking them: [`YTMusicClient::rate_playlist`]
 //! - Fetch song metadata (no auth required): [`YTMusicClient::get_song`]
+//! - Get the up-next queue or radio of a song or playlist, with the browse IDs
+//!   of its lyrics and related content (no auth required):
+//!   [`YTMusicClient::get_watch_playlist`]
+//! - Fetch the lyrics of a track, plain or line-timed (no auth required):
+//!   [`YTMusicClient::get_lyrics`], [`YTMusicClient::get_timed_lyrics`]
+//! - Fetch the songs, artists and playlists related to a track (no auth
+//!   required): [`YTMusicClient::get_song_related`]
+//! - Fetch artist pages and discographies (no auth required): [`YTMusicClient::get_artist`],
+//!   [`YTMusicClient::get_artist_albums`]
+//! - Follow artists: [`YTMusicClient::subscribe_artists`], [`YTMusicClient::unsubscribe_artists`],
+//!   [`YTMusicClient::get_library_subscriptions`]
+//! - Fetch albums with their tracks (no auth required): [`YTMusicClient::get_album`]
+//! - Browse other users' channels and public playlists: [`YTMusicClient::get_user`],
+//!   [`YTMusicClient::get_user_playlists`]
+//! - Read the home feed, personalized with auth: [`YTMusicClient::get_home`]
+//! - Browse moods and genres and their playlists (no auth required):
+//!   [`YTMusicClient::get_mood_categories`], [`YTMusicClient::get_mood_playlists`]
+//! - Read the top songs, videos and artists of a country (no auth required):
+//!   [`YTMusicClient::get_charts`]
+//! - List new albums and singles (no auth required): [`YTMusicClient::get_new_releases`]
+//! - Search (no auth required): [`YTMusicClient::search`], [`YTMusicClient::search_filtered`]
+//! - Search your library or uploads: [`YTMusicClient::search_scoped`]
+//! - Autocomplete queries: [`YTMusicClient::get_search_suggestions`]
+//! - Identify the signed-in account: [`YTMusicClient::get_account_info`]
+//! - Check account capabilities: [`YTMusicClient::get_capabilities`]
+//! - Check that the cookies still work: [`YTMusicClient::probe_auth`]; with the `monitor`
+//!   feature, `YTMusicClient::spawn_auth_monitor` does so periodically and reports changes
+//! - Swap in refreshed cookies without rebuilding the client: [`YTMusicClient::replace_auth`]
+//! - Audit every change the client makes: [`YTMusicClientBuilder::with_audit_hook`]
+//! - Get responses in one language for every account:
+//!   [`YTMusicClientBuilder::with_response_language_override`]
+//! - Confirm playlist edits took effect: [`YTMusicClientBuilder::with_edit_verification`]
+//! - Multi-account workflows: [`workflows::transfer_playlist`]; with the `pool` feature,
+//!   `pool::ClientPool` manages named clients under one shared rate limit
+//! - Pace requests, across clients if shared: [`RateLimiter`]
+//! - Page through any browse endpoint: [`YTMusicClient::fetch_continuation`] with the
+//!   [`continuation`] helpers
 //!
 //! ## Installation
 //!
@@ -79,6 +146,33 @@
 //! }
 //! ```
 //!
+//! ## Stability
+//!
+//! Changed APIs keep their old form for one minor release, marked
+//! `#[deprecated]`; see [`compat`] for the policy and the current shims.
+//!
+//! Option structs ([`GetPlaylistOptions`], [`CreatePlaylistOptions`],
+//! [`EditPlaylistOptions`], [`PruneOptions`]) are `#[non_exhaustive]`, so new
+//! options can be added without a breaking release. Build them with `new()`
+//! and the chained setters, and pass them to the method with the `_with`
+//! suffix where a shorter form exists (e.g. [`YTMusicClient::get_playlist_with`]):
+//!
+//! ```
+//! use ytmusicapi::GetPlaylistOptions;
+//!
+//! let options = GetPlaylistOptions::new().limit(200).include_unavailable(false);
+//! # let _ = options;
+//! ```
+//!
+//! Struct literals do not compile outside the crate:
+//!
+//! ```compile_fail
+//! let options = ytmusicapi::GetPlaylistOptions {
+//!     limit: Some(200),
+//!     include_unavailable: false,
+//! };
+//! ```
+//!
 //! ## Error Behavior
 //!
 //! All fallible APIs return [`Result`](crate::Result), backed by [`Error`](crate::Error).
@@ -88,17 +182,38 @@
 //! - HTTP and network failures surface as [`Error::Http`](crate::Error::Http).
 //! - Non-2xx responses or API error payloads surface as
 //!   [`Error::Server`](crate::Error::Server).
+//!   A 401 on an authenticated request while the local clock is more than
+//!   five minutes off the server's says so in the message and in
+//!   [`ResponseMeta::clock_skew_seconds`](crate::ResponseMeta::clock_skew_seconds);
+//!   see [`YTMusicClientBuilder::with_clock_correction`].
+//! - Non-JSON responses (consent pages, captchas, proxy errors) surface as
+//!   [`Error::UnexpectedContent`](crate::Error::UnexpectedContent).
+//! - Account verification prompts surface as
+//!   [`Error::AccountChallenge`](crate::Error::AccountChallenge) with the URL to complete them.
 //! - Response decode failures surface as [`Error::Json`](crate::Error::Json).
 //! - Input validation failures surface as [`Error::InvalidInput`](crate::Error::InvalidInput).
+//! - Invalid builder options surface together as [`Error::Config`](crate::Error::Config).
 //! - Credential parsing failures surface as [`Error::InvalidAuth`](crate::Error::InvalidAuth).
+//! - A `clientVersion` the server no longer accepts surfaces as
+//!   [`Error::ClientVersionRejected`](crate::Error::ClientVersionRejected)
+//!   after one retry with the previous day's version.
+//! - Features with limited rollout surface as [`Error::Unsupported`](crate::Error::Unsupported)
+//!   when the backend does not know their action; other rejections keep
+//!   their usual error.
 //!
 //! **Timeouts, retries, and polling:** this crate does not configure request
-//! timeouts, retry failed requests, or poll for completion. Any timeouts are
+//! timeouts, retry failed requests (except once on a rejected client
+//! version), or poll for completion. Any timeouts are
 //! determined by the underlying HTTP client defaults and the network stack.
 //!
 //! **External system failures:** because this client depends on the YouTube Music
 //! web API, changes or outages on Google's side can cause `Error::Server` or
 //! parsing errors. The API is unofficial and may change without notice.
+//!
+//! **Partial parses:** rows a parser cannot read are skipped rather than
+//! failing the whole listing. Playlists and library playlist pages list each
+//! skipped row in their `warnings` field as a [`ParseWarning`](crate::ParseWarning);
+//! monitoring it shows layout changes before they break every row.
 macro_rules! path {
     ($($segment:expr),* $(,)?) => {
         [$($crate::nav::PathSegment::from($segment)),*]
@@ -107,13 +222,32 @@ macro_rules! path {
 
 mod auth;
 mod client;
+mod clock;
+pub mod compat;
 mod context;
+pub mod continuation;
 mod error;
+pub mod export;
+// Expected snapshots record parsed "Added" dates.
+#[cfg(all(test, feature = "chrono"))]
+mod fixture_tests;
+mod matching;
+#[cfg(feature = "monitor")]
+mod monitor;
 mod nav;
+mod pagination;
 mod parsers;
+#[cfg(feature = "pool")]
+pub mod pool;
+mod rate_limit;
+mod transport;
 mod types;
+pub mod workflows;
 
 pub use auth::BrowserAuth;
 pub use client::{YTMusicClient, YTMusicClientBuilder};
-pub use error::{Error, Result};
+pub use error::{ConfigError, Error, ResponseMeta, Result};
+#[cfg(feature = "monitor")]
+pub use monitor::AuthMonitor;
+pub use rate_limit::RateLimiter;
 pub use types::*;
@@ -0,0 +1,216 @@
+//! Fuzzy matching of library tracks against free-text queries.
+
+use crate::types::{LibraryTrackMatch, Playlist, TrackLike, TrackLocation};
+
+/// Score how well `track` matches `query`, from 0.0 (no query word found) to
+/// 1.0 (every query word found).
+///
+/// Words are compared case- and punctuation-insensitively against the words of
+/// the title and the artist names. A query word counts fully when equal to a
+/// track word, nearly fully when it starts one, and partially when it is within
+/// one edit of one (two for words of eight letters or more).
+pub(crate) fn match_score(query: &str, track: &impl TrackLike) -> f32 {
+    let query = words(query);
+    if query.is_empty() {
+        return 0.0;
+    }
+    let mut candidates = words(track.title().unwrap_or_default());
+    for artist in track.artists() {
+        candidates.extend(words(&artist.name));
+    }
+
+    let total: f32 = query
+        .iter()
+        .map(|word| {
+            candidates
+                .iter()
+                .map(|candidate| word_similarity(word, candidate))
+                .fold(0.0, f32::max)
+        })
+        .sum();
+    total / query.len() as f32
+}
+
+/// Collect the tracks of `playlists` scoring at least `threshold`, best first.
+///
+/// A track saved in several playlists is reported once, with every location,
+/// in the order the playlists are given. Ties keep library order.
+pub(crate) fn find_matches(
+    query: &str,
+    threshold: f32,
+    playlists: &[Playlist],
+) -> Vec<LibraryTrackMatch> {
+    let mut matches: Vec<LibraryTrackMatch> = Vec::new();
+    for playlist in playlists {
+        for track in &playlist.tracks {
+            let score = match_score(query, track);
+            if score == 0.0 || score < threshold {
+                continue;
+            }
+            let location = TrackLocation {
+                playlist_id: playlist.id.clone(),
+                playlist_title: playlist.title.clone(),
+                set_video_id: track.set_video_id.clone(),
+            };
+            let existing = track.video_id.as_deref().and_then(|id| {
+                matches
+                    .iter_mut()
+                    .find(|m| m.track.video_id.as_deref() == Some(id))
+            });
+            match existing {
+                Some(existing) => existing.locations.push(location),
+                None => matches.push(LibraryTrackMatch {
+                    track: track.clone(),
+                    score,
+                    locations: vec![location],
+                }),
+            }
+        }
+    }
+    matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+    matches
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn word_similarity(query: &str, candidate: &str) -> f32 {
+    if query == candidate {
+        return 1.0;
+    }
+    let query_len = query.chars().count();
+    if query_len >= 3 && candidate.starts_with(query) {
+        return 0.9;
+    }
+    let allowed = match query_len {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    };
+    let distance = edit_distance(query, candidate);
+    if distance > allowed {
+        return 0.0;
+    }
+    let longest = query_len.max(candidate.chars().count());
+    1.0 - distance as f32 / longest as f32
+}
+
+/// Levenshtein distance over characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Artist, PlaylistTrack};
+
+    fn track(video_id: &str, title: &str, artist: &str) -> PlaylistTrack {
+        PlaylistTrack {
+            video_id: Some(video_id.to_string()),
+            title: Some(title.to_string()),
+            artists: vec![Artist {
+                name: artist.to_string(),
+                id: None,
+            }],
+            set_video_id: Some(format!("set-{video_id}")),
+            ..Default::default()
+        }
+    }
+
+    fn playlist(id: &str, title: &str, tracks: Vec<PlaylistTrack>) -> Playlist {
+        Playlist {
+            id: id.to_string(),
+            title: title.to_string(),
+            tracks,
+            ..Default::default()
+        }
+    }
+
+    fn library() -> Vec<Playlist> {
+        vec![
+            playlist(
+                "LM",
+                "Liked Music",
+                vec![
+                    track("vid1", "Midnight City", "M83"),
+                    track("vid2", "Midnight City (Live)", "M83"),
+                ],
+            ),
+            playlist(
+                "PLroad",
+                "Road Trip",
+                vec![
+                    track("vid3", "Wait", "M83"),
+                    track("vid1", "Midnight City", "M83"),
+                    track("vid4", "City of Stars", "Ryan Gosling"),
+                ],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_match_score() {
+        let song = track("v", "Midnight City", "M83");
+        assert_eq!(match_score("midnight city", &song), 1.0);
+        assert_eq!(match_score("MIDNIGHT, city!", &song), 1.0);
+        assert_eq!(match_score("m83 midnight", &song), 1.0);
+        assert!(match_score("midnigt city", &song) > 0.8);
+        assert!(match_score("midn", &song) > 0.8);
+        assert_eq!(match_score("yesterday", &song), 0.0);
+        assert_eq!(match_score("", &song), 0.0);
+        assert_eq!(match_score("midnight sun", &song), 0.5);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("für", "fur"), 1);
+    }
+
+    #[test]
+    fn test_find_matches_groups_locations() {
+        let matches = find_matches("midnight city", 0.8, &library());
+        let ids: Vec<_> = matches
+            .iter()
+            .map(|m| m.track.video_id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, ["vid1", "vid2"]);
+
+        let locations: Vec<_> = matches[0]
+            .locations
+            .iter()
+            .map(|l| (l.playlist_id.as_str(), l.set_video_id.as_deref()))
+            .collect();
+        assert_eq!(
+            locations,
+            [("LM", Some("set-vid1")), ("PLroad", Some("set-vid1"))]
+        );
+    }
+
+    #[test]
+    fn test_find_matches_ranks_by_score() {
+        let matches = find_matches("city stars", 0.4, &library());
+        assert_eq!(matches[0].track.video_id.as_deref(), Some("vid4"));
+        assert_eq!(matches[0].score, 1.0);
+        assert!(matches[1..].iter().all(|m| m.score == 0.5));
+        assert_eq!(matches.len(), 3);
+
+        assert!(find_matches("city stars", 0.9, &library()).len() == 1);
+    }
+}
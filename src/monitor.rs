@@ -0,0 +1,154 @@
+//! Watching credentials from long-running services (requires the `monitor`
+//! feature).
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+use crate::client::YTMusicClient;
+use crate::types::AuthState;
+
+/// A running auth monitor, stopped when dropped.
+///
+/// Returned by [`YTMusicClient::spawn_auth_monitor`].
+#[derive(Debug)]
+pub struct AuthMonitor {
+    task: JoinHandle<()>,
+}
+
+impl AuthMonitor {
+    /// Stop probing. Dropping the monitor does the same.
+    pub fn stop(self) {}
+
+    /// Whether the monitor has stopped, e.g. because its callback panicked.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl Drop for AuthMonitor {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl YTMusicClient {
+    /// Probe the cookies every `interval` and report when they stop or start
+    /// working again.
+    ///
+    /// For services that hold one client for weeks: `on_change` is called
+    /// with the new state each time [`probe_auth`](Self::probe_auth) gives a
+    /// different answer than the one before, so cookies can be refreshed
+    /// before user-facing requests fail. The credentials are assumed valid at
+    /// the start, so the first call is [`AuthState::Expired`]. The first probe
+    /// is sent right away. Probes go through the client's rate limiter;
+    /// probes that fail for other reasons, such as network errors, are
+    /// skipped and change nothing.
+    ///
+    /// The monitor runs until the returned [`AuthMonitor`] is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero or if called outside a Tokio runtime.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn demo(client: std::sync::Arc<ytmusicapi::YTMusicClient>) {
+    /// use std::time::Duration;
+    /// use ytmusicapi::AuthState;
+    ///
+    /// let _monitor = client.spawn_auth_monitor(Duration::from_secs(3600), |state| {
+    ///     if state == AuthState::Expired {
+    ///         eprintln!("YouTube Music cookies expired; export new headers");
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn spawn_auth_monitor<F>(
+        self: &Arc<Self>,
+        interval: Duration,
+        mut on_change: F,
+    ) -> AuthMonitor
+    where
+        F: FnMut(AuthState) + Send + 'static,
+    {
+        let client = Arc::clone(self);
+        let mut ticks = tokio::time::interval(interval);
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let task = tokio::spawn(async move {
+            let mut state = AuthState::Valid;
+            loop {
+                ticks.tick().await;
+                let Ok(probed) = client.probe_auth().await else {
+                    continue;
+                };
+                if probed != state {
+                    state = probed;
+                    on_change(state);
+                }
+            }
+        });
+        AuthMonitor { task }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::transport::{ScratchDir, fixture_client};
+
+    const SIGNED_IN: &str = r#"{"actions": [{"openPopupAction": {"popup": {"multiPageMenuRenderer": {"header": {"activeAccountHeaderRenderer": {"accountName": {"runs": [{"text": "Fixture"}]}}}}}}}]}"#;
+    const REJECTED: &str =
+        r#"{"error": {"code": 401, "message": "Request had invalid authentication credentials."}}"#;
+
+    fn record(dir: &ScratchDir, response: Option<&str>) {
+        let path = "account/account_menu/default.json";
+        match response {
+            Some(response) => dir.write(path, response),
+            None => std::fs::remove_file(dir.path().join(path)).unwrap(),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_monitor_reports_transitions_until_dropped() {
+        let dir = ScratchDir::new("monitor");
+        record(&dir, Some(SIGNED_IN));
+        let client = Arc::new(fixture_client(&dir));
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let monitor = client.spawn_auth_monitor(Duration::from_secs(60), move |state| {
+            sink.lock().unwrap().push(state)
+        });
+        let step = || tokio::time::sleep(Duration::from_secs(60));
+
+        // Check halfway between probes, the first of which is sent at once.
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        assert!(seen.lock().unwrap().is_empty());
+        record(&dir, Some(REJECTED));
+        step().await;
+        assert_eq!(*seen.lock().unwrap(), [AuthState::Expired]);
+        // A failing probe neither changes the state nor stops the monitor.
+        record(&dir, None);
+        step().await;
+        record(&dir, Some(SIGNED_IN));
+        step().await;
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [AuthState::Expired, AuthState::Valid]
+        );
+        assert!(!monitor.is_finished());
+
+        drop(monitor);
+        record(&dir, Some(REJECTED));
+        step().await;
+        step().await;
+        assert_eq!(seen.lock().unwrap().len(), 2);
+        assert_eq!(Arc::strong_count(&client), 1);
+    }
+}
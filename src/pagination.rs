@@ -0,0 +1,453 @@
+//! Shared continuation loop for listing endpoints.
+
+use std::collections::HashSet;
+use std::future::Future;
+use std::ops::ControlFlow;
+
+use serde_json::Value;
+
+use crate::error::Result;
+use crate::parsers::locale::Vocabulary;
+use crate::types::ParseWarning;
+
+/// How to read one listing endpoint's continuation responses.
+pub(crate) struct PageShape<T> {
+    /// Locate the raw rows and next token without parsing the rows.
+    pub rows: fn(&Value) -> (&[Value], Option<String>),
+    /// Parse raw rows into items, reading labels in `words` and recording
+    /// rows it had to drop.
+    pub parse: fn(&[Value], &Vocabulary, &mut Vec<ParseWarning>) -> Vec<T>,
+    /// Words of the language the pages were requested in.
+    pub words: &'static Vocabulary,
+    /// Identity used to drop an item repeated across a page seam. Items
+    /// without a key are never treated as repeats.
+    pub seam_key: fn(&T) -> Option<&str>,
+}
+
+/// Follow continuation tokens, appending parsed items until `limit` items are
+/// held or no token remains.
+///
+/// `items` holds whatever the caller parsed from the first response, whose
+/// shape usually differs from the continuation pages. Each token only arrives
+/// with the previous page, so pages are fetched in sequence; to hide the parse
+/// cost, the next request is started concurrently with parsing the current
+/// page when the page cannot reach `limit` on its own. Stops if a token repeats
+/// or a page is empty.
+///
+/// Returns the token for the first page not yet appended, if any, so callers
+/// can resume later. Items of the last appended page beyond `limit` are
+/// dropped and not returned again on resume.
+pub(crate) async fn paginate<T, F, Fut>(
+    items: &mut Vec<T>,
+    token: Option<String>,
+    limit: usize,
+    shape: &PageShape<T>,
+    fetch_next: F,
+) -> Result<Option<String>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<Value>>,
+{
+    paginate_with_warnings(items, token, limit, shape, fetch_next, &mut Vec::new()).await
+}
+
+/// Like [`paginate`], appending the parse warnings of each continuation page
+/// to `warnings`, numbered from page `1`.
+pub(crate) async fn paginate_with_warnings<T, F, Fut>(
+    items: &mut Vec<T>,
+    token: Option<String>,
+    limit: usize,
+    shape: &PageShape<T>,
+    fetch_next: F,
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<Option<String>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<Value>>,
+{
+    let mut seen = HashSet::new();
+    let mut page_number = 0;
+    let mut response = match token {
+        Some(token) if items.len() < limit => {
+            seen.insert(token.clone());
+            fetch_next(token).await?
+        }
+        token => {
+            items.truncate(limit);
+            return Ok(token);
+        }
+    };
+
+    loop {
+        let (rows, token) = (shape.rows)(&response);
+        let mut token = token.filter(|token| seen.insert(token.clone()));
+        let prefetch = token.take_if(|_| !rows.is_empty() && items.len() + rows.len() < limit);
+
+        page_number += 1;
+        let mut page_warnings = Vec::new();
+        let (page, prefetched) = match prefetch {
+            Some(token) => {
+                let (response, page) = tokio::join!(fetch_next(token.clone()), async {
+                    (shape.parse)(rows, shape.words, &mut page_warnings)
+                });
+                (page, Some((token, response?)))
+            }
+            None => ((shape.parse)(rows, shape.words, &mut page_warnings), None),
+        };
+        warnings.extend(number_page(page_warnings, page_number));
+
+        if page.is_empty() {
+            return Ok(None);
+        }
+        append_page(items, page, shape.seam_key);
+        if items.len() >= limit {
+            items.truncate(limit);
+            return Ok(prefetched.map(|(token, _)| token).or(token));
+        }
+
+        response = match (prefetched, token) {
+            (Some((_, response)), _) => response,
+            (None, Some(token)) => fetch_next(token).await?,
+            (None, None) => return Ok(None),
+        };
+    }
+}
+
+/// Follow continuation tokens, handing each parsed page to `on_page` instead
+/// of collecting the items.
+///
+/// Only one page is held at a time, so listings of any length run in constant
+/// memory. `first` is the page the caller parsed from the first response and
+/// is passed on unchanged. A leading item repeating the previous page's last
+/// item is dropped as in [`append_page`]. Stops if a token repeats, a page is
+/// empty, or `on_page` fails or breaks. Parse warnings of continuation pages
+/// are appended to `warnings` as in [`paginate_with_warnings`].
+pub(crate) async fn for_each_page<T, F, Fut, P>(
+    first: Vec<T>,
+    token: Option<String>,
+    shape: &PageShape<T>,
+    fetch_next: F,
+    mut on_page: P,
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<()>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<Value>>,
+    P: FnMut(Vec<T>) -> Result<ControlFlow<()>>,
+{
+    let mut seen = HashSet::new();
+    let mut last_key = first.last().and_then(shape.seam_key).map(str::to_string);
+    if on_page(first)?.is_break() {
+        return Ok(());
+    }
+
+    let mut token = token;
+    let mut page_number = 0;
+    while let Some(current) = token.take().filter(|t| seen.insert(t.clone())) {
+        let response = fetch_next(current).await?;
+        let (rows, next) = (shape.rows)(&response);
+        let mut page_warnings = Vec::new();
+        let mut page = (shape.parse)(rows, shape.words, &mut page_warnings);
+        page_number += 1;
+        warnings.extend(number_page(page_warnings, page_number));
+        if page.is_empty() {
+            break;
+        }
+        if let Some(key) = &last_key
+            && page.first().and_then(shape.seam_key) == Some(key.as_str())
+        {
+            page.remove(0);
+        }
+        if let Some(last) = page.last() {
+            last_key = (shape.seam_key)(last).map(str::to_string);
+        }
+        if on_page(page)?.is_break() {
+            break;
+        }
+        token = next;
+    }
+    Ok(())
+}
+
+/// Stamp the page number on warnings a page parser recorded.
+fn number_page(warnings: Vec<ParseWarning>, page: usize) -> impl Iterator<Item = ParseWarning> {
+    warnings
+        .into_iter()
+        .map(move |warning| ParseWarning { page, ..warning })
+}
+
+/// Append a page to the items already fetched, preserving server order.
+///
+/// Some responses repeat the last item of the previous page as the first item
+/// of the next one; such a leading item (same seam key) is dropped.
+pub(crate) fn append_page<T>(items: &mut Vec<T>, page: Vec<T>, seam_key: fn(&T) -> Option<&str>) {
+    let mut page = page.into_iter().peekable();
+    if let (Some(last), Some(first)) = (items.last(), page.peek())
+        && let Some(key) = seam_key(last)
+        && seam_key(first) == Some(key)
+    {
+        page.next();
+    }
+    items.extend(page);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::parsers::locale::ENGLISH;
+
+    const STRINGS: PageShape<String> = PageShape {
+        rows: |response| {
+            let rows = response["items"].as_array().map_or(&[][..], Vec::as_slice);
+            (rows, response["next"].as_str().map(str::to_string))
+        },
+        parse: |rows, _, warnings| {
+            let mut items = Vec::new();
+            for (index, row) in rows.iter().enumerate() {
+                match row.as_str() {
+                    Some(item) => items.push(item.to_string()),
+                    None => warnings.push(ParseWarning::new(index, "", "not a string")),
+                }
+            }
+            items
+        },
+        words: &ENGLISH,
+        seam_key: |item| Some(item.as_str()),
+    };
+
+    /// Run `paginate` over canned pages keyed by token, returning the items,
+    /// the tokens requested and the resume token.
+    async fn run(
+        pages: &[(&str, Value)],
+        first: Vec<&str>,
+        token: &str,
+        limit: usize,
+    ) -> (Vec<String>, Vec<String>, Option<String>) {
+        let pages: HashMap<_, _> = pages.iter().cloned().collect();
+        let requested = RefCell::new(Vec::new());
+        let mut items: Vec<String> = first.into_iter().map(str::to_string).collect();
+
+        let resume = paginate(
+            &mut items,
+            Some(token.to_string()),
+            limit,
+            &STRINGS,
+            |token| {
+                requested.borrow_mut().push(token.clone());
+                let page = pages[token.as_str()].clone();
+                async move { Ok(page) }
+            },
+        )
+        .await
+        .unwrap();
+
+        (items, requested.into_inner(), resume)
+    }
+
+    #[tokio::test]
+    async fn test_paginate_follows_tokens_and_drops_seam_repeats() {
+        let pages = [
+            ("t1", json!({ "items": ["b", "c"], "next": "t2" })),
+            ("t2", json!({ "items": ["c", "d"] })),
+        ];
+        let (items, requested, resume) = run(&pages, vec!["a", "b"], "t1", 100).await;
+        assert_eq!(items, ["a", "b", "c", "d"]);
+        assert_eq!(requested, ["t1", "t2"]);
+        assert_eq!(resume, None);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_respects_limit() {
+        let pages = [
+            ("t1", json!({ "items": ["b", "c"], "next": "t2" })),
+            ("t2", json!({ "items": ["d", "e"], "next": "t3" })),
+        ];
+        let (items, requested, resume) = run(&pages, vec!["a"], "t1", 2).await;
+        assert_eq!(items, ["a", "b"]);
+        assert_eq!(requested, ["t1"]);
+        assert_eq!(resume.as_deref(), Some("t2"));
+
+        let (items, requested, resume) = run(&pages, vec!["a"], "t1", 1).await;
+        assert_eq!(items, ["a"]);
+        assert!(requested.is_empty());
+        assert_eq!(resume.as_deref(), Some("t1"));
+
+        // Limit reached inside a page: resume after that page.
+        let (items, requested, resume) = run(&pages, vec!["a"], "t1", 4).await;
+        assert_eq!(items, ["a", "b", "c", "d"]);
+        assert_eq!(requested, ["t1", "t2"]);
+        assert_eq!(resume.as_deref(), Some("t3"));
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_on_repeated_token() {
+        let pages = [
+            ("t1", json!({ "items": ["b"], "next": "t2" })),
+            ("t2", json!({ "items": ["c"], "next": "t1" })),
+        ];
+        let (items, requested, resume) = run(&pages, vec!["a"], "t1", 100).await;
+        assert_eq!(items, ["a", "b", "c"]);
+        assert_eq!(requested, ["t1", "t2"]);
+        assert_eq!(resume, None);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_on_empty_page() {
+        let pages = [("t1", json!({ "items": [], "next": "t2" }))];
+        let (items, requested, resume) = run(&pages, vec!["a"], "t1", 100).await;
+        assert_eq!(items, ["a"]);
+        assert_eq!(requested, ["t1"]);
+        assert_eq!(resume, None);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_overlaps_fetching_with_parsing() {
+        thread_local! {
+            static EVENTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+        }
+        /// [`STRINGS`], logging when each page's parse finishes.
+        const LOGGED_STRINGS: PageShape<String> = PageShape {
+            parse: |rows, words, warnings| {
+                let page = (STRINGS.parse)(rows, words, warnings);
+                EVENTS.with(|events| events.borrow_mut().push(format!("parsed {}", page[0])));
+                page
+            },
+            ..STRINGS
+        };
+
+        let mut items = Vec::new();
+        paginate(
+            &mut items,
+            Some("1".to_string()),
+            usize::MAX,
+            &LOGGED_STRINGS,
+            |token| async move {
+                EVENTS.with(|events| events.borrow_mut().push(format!("fetch {token}")));
+                let next = (token != "3").then(|| (token.parse::<u32>().unwrap() + 1).to_string());
+                Ok(json!({ "items": [token], "next": next }))
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(items, ["1", "2", "3"]);
+        // Each next page is requested before the current one is parsed; the
+        // last page has no next page to request.
+        assert_eq!(
+            EVENTS.with(RefCell::take),
+            [
+                "fetch 1", "fetch 2", "parsed 1", "fetch 3", "parsed 2", "parsed 3"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginate_numbers_warnings_by_page() {
+        let pages: HashMap<_, _> = [
+            ("t1", json!({ "items": ["b", 1], "next": "t2" })),
+            ("t2", json!({ "items": [null, "c", 2] })),
+        ]
+        .into_iter()
+        .collect();
+        let mut items = vec!["a".to_string()];
+        let mut warnings = Vec::new();
+
+        paginate_with_warnings(
+            &mut items,
+            Some("t1".to_string()),
+            100,
+            &STRINGS,
+            |token| {
+                let page = pages[token.as_str()].clone();
+                async move { Ok(page) }
+            },
+            &mut warnings,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(items, ["a", "b", "c"]);
+        let positions: Vec<_> = warnings.iter().map(|w| (w.page, w.index)).collect();
+        assert_eq!(positions, [(1, 1), (2, 0), (2, 2)]);
+    }
+
+    #[tokio::test]
+    async fn test_for_each_page_hands_over_pages_in_order() {
+        let pages: HashMap<_, _> = [
+            ("t1", json!({ "items": ["b", "c"], "next": "t2" })),
+            ("t2", json!({ "items": ["c", "d"], "next": "t1" })),
+        ]
+        .into_iter()
+        .collect();
+        let mut seen_pages = Vec::new();
+
+        for_each_page(
+            vec!["a".to_string(), "b".to_string()],
+            Some("t1".to_string()),
+            &STRINGS,
+            |token| {
+                let page = pages[token.as_str()].clone();
+                async move { Ok(page) }
+            },
+            |page| {
+                seen_pages.push(page);
+                Ok(ControlFlow::Continue(()))
+            },
+            &mut Vec::new(),
+        )
+        .await
+        .unwrap();
+
+        // The seam repeats are dropped and the repeated token ends the loop.
+        assert_eq!(seen_pages, [vec!["a", "b"], vec!["c"], vec!["d"]]);
+    }
+
+    /// Split `count` items into pages of `page_size`, optionally repeating the
+    /// previous page's last item at the start of each following page.
+    fn paged_items(count: usize, page_size: usize, repeat_seam: bool) -> Vec<Vec<String>> {
+        let all: Vec<_> = (0..count).map(|i| i.to_string()).collect();
+        all.chunks(page_size)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let mut page = Vec::new();
+                if repeat_seam && i > 0 {
+                    page.push(all[i * page_size - 1].clone());
+                }
+                page.extend_from_slice(chunk);
+                page
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_append_page_preserves_order_without_seam_duplicates() {
+        for count in [1, 2, 99, 100, 101, 250] {
+            for page_size in [1, 7, 100] {
+                for repeat_seam in [false, true] {
+                    let mut items = Vec::new();
+                    for page in paged_items(count, page_size, repeat_seam) {
+                        append_page(&mut items, page, STRINGS.seam_key);
+                    }
+
+                    let expected: Vec<_> = (0..count).map(|i| i.to_string()).collect();
+                    assert_eq!(
+                        items, expected,
+                        "count={count} page_size={page_size} repeat_seam={repeat_seam}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_append_page_keeps_items_without_seam_key() {
+        let mut items = vec![String::new()];
+        append_page(&mut items, vec![String::new()], |_| None);
+        assert_eq!(items.len(), 2);
+    }
+}
@@ -0,0 +1,190 @@
+//! Account menu parsing.
+
+use serde_json::Value;
+
+use crate::error::{Error, Result};
+use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::playlist::parse_thumbnail_list;
+use crate::types::{AccountInfo, Capabilities};
+
+/// Browse ID of the "Get Music Premium" upsell page.
+const PREMIUM_UPSELL_BROWSE_ID: &str = "SPunlimited";
+
+/// Path of the active account header in an `account/account_menu` response.
+const ACCOUNT_HEADER_PATH: &str =
+    "actions[0].openPopupAction.popup.multiPageMenuRenderer.header.activeAccountHeaderRenderer";
+
+/// Parse an `account/account_menu` response into [`AccountInfo`].
+///
+/// Returns [`Error::Navigation`] naming the missing path if the menu has no
+/// active account header or the header has no account name.
+pub fn parse_account_info(response: &Value) -> Result<AccountInfo> {
+    let header = nav(
+        response,
+        &path![
+            "actions",
+            0,
+            "openPopupAction",
+            "popup",
+            "multiPageMenuRenderer",
+            "header",
+            "activeAccountHeaderRenderer"
+        ],
+    )
+    .ok_or_else(|| Error::Navigation {
+        path: ACCOUNT_HEADER_PATH.to_string(),
+    })?;
+
+    let name = nav_str(header, &path!["accountName", "runs", 0, "text"]).ok_or_else(|| {
+        Error::Navigation {
+            path: format!("{ACCOUNT_HEADER_PATH}.accountName.runs[0].text"),
+        }
+    })?;
+
+    Ok(AccountInfo {
+        name: name.to_string(),
+        channel_handle: nav_str(header, &path!["channelHandle", "runs", 0, "text"])
+            .map(str::to_string),
+        thumbnails: nav_array(header, &path!["accountPhoto", "thumbnails"])
+            .map(|thumbs| parse_thumbnail_list(thumbs))
+            .unwrap_or_default(),
+    })
+}
+
+/// Parse an `account/account_menu` response into [`Capabilities`].
+///
+/// The menu links to the user's channel when one exists, offers a Premium
+/// upsell to free accounts and links to paid memberships for subscribers.
+/// Signals are read from endpoints rather than labels, so this does not
+/// depend on the client language.
+pub fn parse_capabilities(response: &Value) -> Capabilities {
+    let Some(menu) = nav(
+        response,
+        &path![
+            "actions",
+            0,
+            "openPopupAction",
+            "popup",
+            "multiPageMenuRenderer"
+        ],
+    ) else {
+        return Capabilities::default();
+    };
+
+    let header = nav(menu, &path!["header", "activeAccountHeaderRenderer"]);
+    let mut capabilities = Capabilities {
+        has_channel: header.is_some_and(|h| nav(h, &path!["channelHandle"]).is_some()),
+        is_premium: None,
+    };
+
+    let endpoints = nav_array(menu, &path!["sections"])
+        .into_iter()
+        .flatten()
+        .filter_map(|section| nav_array(section, &path!["multiPageMenuSectionRenderer", "items"]))
+        .flatten()
+        .filter_map(|item| nav(item, &path!["compactLinkRenderer", "navigationEndpoint"]));
+
+    for endpoint in endpoints {
+        match nav_str(endpoint, &path!["browseEndpoint", "browseId"]) {
+            Some(PREMIUM_UPSELL_BROWSE_ID) => capabilities.is_premium = Some(false),
+            Some(id) if id.starts_with("UC") => capabilities.has_channel = true,
+            _ => {}
+        }
+        if nav_str(endpoint, &path!["urlEndpoint", "url"])
+            .is_some_and(|url| url.contains("paid_memberships"))
+        {
+            capabilities.is_premium = Some(true);
+        }
+    }
+
+    capabilities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn menu(header: Value, items: Vec<Value>) -> Value {
+        json!({
+            "actions": [{
+                "openPopupAction": {
+                    "popup": {
+                        "multiPageMenuRenderer": {
+                            "header": { "activeAccountHeaderRenderer": header },
+                            "sections": [{
+                                "multiPageMenuSectionRenderer": { "items": items }
+                            }]
+                        }
+                    }
+                }
+            }]
+        })
+    }
+
+    fn link(endpoint: Value) -> Value {
+        json!({ "compactLinkRenderer": { "navigationEndpoint": endpoint } })
+    }
+
+    #[test]
+    fn test_parse_capabilities_free_account_with_channel() {
+        let response = menu(
+            json!({
+                "accountName": { "runs": [{ "text": "Me" }] },
+                "channelHandle": { "runs": [{ "text": "@me" }] }
+            }),
+            vec![link(
+                json!({ "browseEndpoint": { "browseId": "SPunlimited" } }),
+            )],
+        );
+        assert_eq!(
+            parse_capabilities(&response),
+            Capabilities {
+                has_channel: true,
+                is_premium: Some(false),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_capabilities_premium_account_without_channel() {
+        let response = menu(
+            json!({ "accountName": { "runs": [{ "text": "Me" }] } }),
+            vec![link(json!({
+                "urlEndpoint": { "url": "https://www.youtube.com/paid_memberships" }
+            }))],
+        );
+        assert_eq!(
+            parse_capabilities(&response),
+            Capabilities {
+                has_channel: false,
+                is_premium: Some(true),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_account_info_reports_missing_paths() {
+        let err = parse_account_info(&json!({ "actions": [] })).unwrap_err();
+        assert!(
+            matches!(&err, Error::Navigation { path } if path == ACCOUNT_HEADER_PATH),
+            "{err}"
+        );
+
+        let err = parse_account_info(&menu(json!({}), vec![])).unwrap_err();
+        assert!(
+            matches!(&err, Error::Navigation { path } if path.ends_with(".accountName.runs[0].text")),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_parse_capabilities_channel_link() {
+        let response = menu(
+            json!({}),
+            vec![link(json!({ "browseEndpoint": { "browseId": "UCabc" } }))],
+        );
+        assert!(parse_capabilities(&response).has_channel);
+        assert_eq!(parse_capabilities(&json!({})), Capabilities::default());
+    }
+}
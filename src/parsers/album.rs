@@ -0,0 +1,326 @@
+//! Album page parsing.
+
+use serde_json::Value;
+
+use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::artist::release_type;
+use crate::parsers::date::parse_year_run;
+use crate::parsers::locale::{Vocabulary, vocabulary};
+use crate::parsers::navigation::paths;
+use crate::parsers::playlist::{
+    header_play_playlist_id, parse_description_runs, parse_thumbnails, parse_track_count,
+};
+use crate::parsers::track::{
+    get_fixed_column_item, get_flex_column_item, get_item_text, parse_artist_runs, parse_duration,
+    parse_external_ids, parse_like_status,
+};
+use crate::types::{AlbumPage, AlbumTrack, Artist, MusicVideoType};
+
+/// Parse an album `browse` response.
+///
+/// Tracks without their own artist column are credited to the album artists.
+/// Header labels are read in `language`, the language the response was
+/// requested in.
+pub fn parse_album_page(response: &Value, browse_id: &str, language: &str) -> AlbumPage {
+    let mut album = AlbumPage {
+        browse_id: browse_id.to_string(),
+        ..Default::default()
+    };
+
+    let Some(two_col) = nav(response, paths::TWO_COLUMN_RENDERER) else {
+        return album;
+    };
+
+    if let Some(header) = nav(two_col, paths::TAB_CONTENT)
+        .and_then(|v| nav(v, &path!["sectionListRenderer", "contents", 0]))
+        .and_then(|v| nav(v, paths::RESPONSIVE_HEADER))
+    {
+        parse_album_header(header, &mut album, vocabulary(language));
+    }
+    if album.audio_playlist_id.is_none() {
+        album.audio_playlist_id = canonical_playlist_id(response);
+    }
+
+    // Albums with several discs have a track shelf per disc, or one shelf
+    // whose numbering starts over.
+    let shelves: Vec<&[Value]> = nav_array(
+        two_col,
+        &path!["secondaryContents", "sectionListRenderer", "contents"],
+    )
+    .into_iter()
+    .flatten()
+    .filter_map(|section| nav_array(section, &path!["musicShelfRenderer", "contents"]))
+    .map(Vec::as_slice)
+    .collect();
+    let mut shelf_starts = Vec::new();
+    for rows in shelves {
+        shelf_starts.push(album.tracks.len());
+        album.tracks.extend(
+            rows.iter()
+                .filter_map(|row| parse_album_track(row.get(paths::MRLIR)?, &album.artists)),
+        );
+    }
+    number_discs(&mut album.tracks, &shelf_starts);
+
+    album.duration_seconds = Some(album.tracks.iter().filter_map(|t| t.duration_seconds).sum());
+
+    album
+}
+
+fn parse_album_header(header: &Value, album: &mut AlbumPage, words: &Vocabulary) {
+    album.title = nav_str(header, paths::TITLE_TEXT)
+        .unwrap_or_default()
+        .to_string();
+    album.thumbnails = parse_thumbnails(header);
+    album.is_explicit = nav(header, paths::BADGE_LABEL).is_some()
+        || nav(
+            header,
+            &path![
+                "subtitleBadge",
+                0,
+                "musicInlineBadgeRenderer",
+                "accessibilityData"
+            ],
+        )
+        .is_some();
+
+    // Subtitle, e.g. "Album • 2019"
+    if let Some(runs) = nav_array(header, &path!["subtitle", "runs"]) {
+        album.album_type = release_type(runs);
+        album.year = parse_year_run(runs);
+    }
+
+    if let Some(runs) = nav_array(header, &path!["straplineTextOne", "runs"]) {
+        album.artists = parse_artist_runs(runs);
+    }
+
+    if let Some(runs) = nav_array(
+        header,
+        &path![
+            "description",
+            "musicDescriptionShelfRenderer",
+            "description",
+            "runs"
+        ],
+    ) {
+        album.description_runs = parse_description_runs(runs);
+        album.description = Some(
+            album
+                .description_runs
+                .iter()
+                .map(|run| run.text.as_str())
+                .collect(),
+        );
+    }
+
+    // Second subtitle, e.g. "12 songs • 45 minutes"
+    for text in nav_array(header, &path!["secondSubtitle", "runs"])
+        .into_iter()
+        .flatten()
+        .filter_map(|run| nav_str(run, &path!["text"]))
+    {
+        if Vocabulary::matches(text, words.songs) {
+            album.track_count = parse_track_count(text);
+        } else if Vocabulary::matches(text, words.duration) {
+            album.duration = Some(text.to_string());
+        }
+    }
+
+    album.audio_playlist_id = header_play_playlist_id(header).map(str::to_string);
+    parse_header_menu(header, album);
+}
+
+/// Number the discs of `tracks`. A new disc starts at each later shelf,
+/// whose first track is at one of `shelf_starts`, and wherever the track
+/// numbers start over. Tracks without a number stay on the current disc.
+/// Leaves `disc` unset if there is only one disc.
+fn number_discs(tracks: &mut [AlbumTrack], shelf_starts: &[usize]) {
+    let mut disc = 1;
+    let mut last_number = 0;
+    for (index, track) in tracks.iter_mut().enumerate() {
+        let new_shelf = index > 0 && shelf_starts.contains(&index);
+        let restarts = track
+            .track_number
+            .is_some_and(|number| number <= last_number);
+        if new_shelf || restarts {
+            disc += 1;
+            last_number = 0;
+        }
+        last_number = track.track_number.unwrap_or(last_number);
+        track.disc = Some(disc);
+    }
+    if disc == 1 {
+        tracks.iter_mut().for_each(|track| track.disc = None);
+    }
+}
+
+/// Read the shuffle and radio playlist IDs from the header's menu, telling
+/// the items apart by icon rather than by their localized labels.
+fn parse_header_menu(header: &Value, album: &mut AlbumPage) {
+    let items = nav_array(header, &path!["buttons"])
+        .into_iter()
+        .flatten()
+        .filter_map(|button| nav_array(button, &path!["menuRenderer", "items"]))
+        .flatten()
+        .filter_map(|item| item.get("menuNavigationItemRenderer"));
+    for item in items {
+        let Some(playlist_id) = nav_str(
+            item,
+            &path!["navigationEndpoint", "watchPlaylistEndpoint", "playlistId"],
+        ) else {
+            continue;
+        };
+        match nav_str(item, &path!["icon", "iconType"]) {
+            Some("MUSIC_SHUFFLE") => album.shuffle_playlist_id = Some(playlist_id.to_string()),
+            Some("MIX") => album.radio_playlist_id = Some(playlist_id.to_string()),
+            _ => {}
+        }
+    }
+}
+
+/// Album playlist ID from the page's canonical URL (`...playlist?list=OLAK5uy_...`).
+fn canonical_playlist_id(response: &Value) -> Option<String> {
+    let url = nav_str(
+        response,
+        &path!["microformat", "microformatDataRenderer", "urlCanonical"],
+    )?;
+    let (_, query) = url.split_once("list=")?;
+    let id = query.split(['&', '#']).next()?;
+    (!id.is_empty()).then(|| id.to_string())
+}
+
+/// Parse an album track row.
+///
+/// Unlike playlist rows these have no thumbnail, carry the track number in
+/// `index`, and show an artist column only for tracks whose artists differ
+/// from `album_artists`.
+fn parse_album_track(data: &Value, album_artists: &[Artist]) -> Option<AlbumTrack> {
+    let title = get_item_text(data, 0)?.to_string();
+
+    let video_id = nav_str(data, &path!["playlistItemData", "videoId"])
+        .or_else(|| {
+            nav(data, paths::PLAY_BUTTON).and_then(|button| {
+                nav_str(
+                    button,
+                    &path!["playNavigationEndpoint", "watchEndpoint", "videoId"],
+                )
+            })
+        })
+        .map(str::to_string);
+
+    // The artist column is present but empty when the album artists apply.
+    let artists = get_flex_column_item(data, 1)
+        .and_then(|column| nav_array(column, &path!["text", "runs"]))
+        .map(|runs| parse_artist_runs(runs))
+        .filter(|artists| !artists.is_empty())
+        .unwrap_or_else(|| album_artists.to_vec());
+
+    let duration = get_fixed_column_item(data, 0).and_then(|column| {
+        nav_str(column, &path!["text", "simpleText"])
+            .or_else(|| nav_str(column, &path!["text", "runs", 0, "text"]))
+    });
+
+    Some(AlbumTrack {
+        video_id,
+        title,
+        track_number: nav_str(data, &path!["index", "runs", 0, "text"])
+            .and_then(|n| n.trim().parse().ok()),
+        disc: None,
+        artists,
+        duration: duration.map(str::to_string),
+        duration_seconds: duration.and_then(parse_duration),
+        is_available: data
+            .get("musicItemRendererDisplayPolicy")
+            .and_then(Value::as_str)
+            != Some("MUSIC_ITEM_RENDERER_DISPLAY_POLICY_GREY_OUT"),
+        is_explicit: nav(data, paths::BADGE_LABEL).is_some(),
+        like_status: nav_str(data, paths::MENU_LIKE_STATUS).and_then(parse_like_status),
+        video_type: nav_str(
+            data,
+            &path![
+                "flexColumns",
+                0,
+                "musicResponsiveListItemFlexColumnRenderer",
+                "text",
+                "runs",
+                0,
+                "navigationEndpoint",
+                "watchEndpoint",
+                "watchEndpointMusicSupportedConfigs",
+                "watchEndpointMusicConfig",
+                "musicVideoType"
+            ],
+        )
+        .map(MusicVideoType::from),
+        external_ids: parse_external_ids(data),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn row(artist_runs: Value) -> Value {
+        json!({
+            "flexColumns": [
+                { "musicResponsiveListItemFlexColumnRenderer": { "text": { "runs": [{ "text": "Intro" }] } } },
+                { "musicResponsiveListItemFlexColumnRenderer": { "text": artist_runs } }
+            ],
+            "index": { "runs": [{ "text": "3" }] },
+            "playlistItemData": { "videoId": "vid" }
+        })
+    }
+
+    #[test]
+    fn test_album_track_falls_back_to_album_artists() {
+        let album_artists = [Artist {
+            name: "Band".to_string(),
+            id: Some("UCband".to_string()),
+        }];
+
+        let track = parse_album_track(&row(json!({ "runs": [] })), &album_artists).unwrap();
+        assert_eq!(track.artists[0].name, "Band");
+        assert_eq!(track.track_number, Some(3));
+        assert_eq!(track.video_id.as_deref(), Some("vid"));
+        assert!(track.is_available);
+
+        let track = parse_album_track(
+            &row(json!({ "runs": [{ "text": "Guest" }] })),
+            &album_artists,
+        )
+        .unwrap();
+        assert_eq!(track.artists[0].name, "Guest");
+    }
+
+    #[test]
+    fn test_discs_start_over_with_track_numbers() {
+        let mut tracks: Vec<AlbumTrack> = [Some(1), Some(2), None, Some(1), Some(2)]
+            .into_iter()
+            .map(|number| {
+                let mut track = parse_album_track(&row(json!({ "runs": [] })), &[]).unwrap();
+                track.track_number = number;
+                track
+            })
+            .collect();
+        number_discs(&mut tracks, &[0]);
+        let discs: Vec<_> = tracks.iter().map(|track| track.disc).collect();
+        assert_eq!(discs, [Some(1), Some(1), Some(1), Some(2), Some(2)]);
+
+        number_discs(&mut tracks[..3], &[0]);
+        assert!(tracks[..3].iter().all(|track| track.disc.is_none()));
+    }
+
+    #[test]
+    fn test_canonical_playlist_id() {
+        let response = json!({
+            "microformat": { "microformatDataRenderer": {
+                "urlCanonical": "https://music.youtube.com/playlist?list=OLAK5uy_abc&feature=x"
+            }}
+        });
+        assert_eq!(
+            canonical_playlist_id(&response).as_deref(),
+            Some("OLAK5uy_abc")
+        );
+    }
+}
@@ -0,0 +1,397 @@
+//! Artist page parsing.
+
+use serde_json::Value;
+
+use crate::continuation::get_continuation_token;
+use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::date::parse_year_run;
+use crate::parsers::locale::{Vocabulary, vocabulary};
+use crate::parsers::navigation::paths;
+use crate::parsers::number::parse_abbreviated_number;
+use crate::parsers::playlist::{parse_playlist_tracks, parse_thumbnails};
+use crate::parsers::track::{get_flex_column_item, get_item_text};
+use crate::types::{
+    ArtistPage, ArtistRelease, ArtistSection, ArtistVideo, MusicVideoType, RelatedArtist,
+    SubscriptionResult,
+};
+
+/// Parse an artist `browse` response.
+///
+/// Carousels are told apart by what their items link to rather than by their
+/// localized headings. Albums and singles both link to album pages; a
+/// carousel whose heading mentions singles in `language`, or any album
+/// carousel after the first, is taken as singles.
+pub fn parse_artist_page(response: &Value, channel_id: &str, language: &str) -> ArtistPage {
+    let mut artist = ArtistPage {
+        channel_id: channel_id.to_string(),
+        ..Default::default()
+    };
+
+    let header = nav(response, paths::HEADER).and_then(|h| {
+        h.get("musicImmersiveHeaderRenderer")
+            .or_else(|| h.get("musicVisualHeaderRenderer"))
+    });
+    if let Some(header) = header {
+        artist.name = nav_str(header, paths::TITLE_TEXT)
+            .unwrap_or_default()
+            .to_string();
+        artist.description =
+            nav_array(header, &path!["description", "runs"]).map(|runs| join_runs(runs));
+        artist.thumbnails = parse_thumbnails(header);
+
+        if let Some(button) = nav(
+            header,
+            &path!["subscriptionButton", "subscribeButtonRenderer"],
+        ) {
+            if let Some(id) = nav_str(button, &path!["channelId"]) {
+                artist.channel_id = id.to_string();
+            }
+            artist.subscribed = button
+                .get("subscribed")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            artist.subscribers = nav_str(button, &path!["subscriberCountText", "runs", 0, "text"])
+                .and_then(parse_abbreviated_number);
+        }
+    }
+
+    let sections = nav(response, paths::SINGLE_COLUMN)
+        .and_then(|v| nav(v, paths::TAB_CONTENT))
+        .and_then(|v| nav_array(v, paths::SECTION_LIST))
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    for section in sections {
+        if let Some(shelf) = section.get("musicShelfRenderer") {
+            if artist.songs.is_none() {
+                let rows = nav_array(shelf, &path!["contents"])
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                artist.songs = Some(section_from(
+                    nav(shelf, &path!["title", "runs", 0]),
+                    parse_playlist_tracks(rows),
+                ));
+            }
+        } else if let Some(carousel) = section.get("musicCarouselShelfRenderer") {
+            parse_carousel(carousel, &mut artist, vocabulary(language));
+        } else if let Some(shelf) = nav(section, paths::DESCRIPTION_SHELF)
+            && artist.description.is_none()
+        {
+            artist.description =
+                nav_array(shelf, &path!["description", "runs"]).map(|runs| join_runs(runs));
+        }
+    }
+
+    artist
+}
+
+/// Sort a carousel into the matching artist page section.
+fn parse_carousel(carousel: &Value, artist: &mut ArtistPage, words: &Vocabulary) {
+    let title_run = nav(
+        carousel,
+        &path![
+            "header",
+            "musicCarouselShelfBasicHeaderRenderer",
+            "title",
+            "runs",
+            0
+        ],
+    );
+    let items: Vec<&Value> = nav_array(carousel, &path!["contents"])
+        .into_iter()
+        .flatten()
+        .filter_map(|item| item.get(paths::MTRIR))
+        .collect();
+    let Some(first) = items.first() else {
+        return;
+    };
+
+    if nav(first, &path!["navigationEndpoint", "watchEndpoint"]).is_some() {
+        if artist.videos.is_none() {
+            let videos = items.iter().filter_map(|i| parse_video(i)).collect();
+            artist.videos = Some(section_from(title_run, videos));
+        }
+        return;
+    }
+
+    match page_type(first) {
+        Some("MUSIC_PAGE_TYPE_ALBUM") => {
+            let releases = items.iter().filter_map(|i| parse_release(i)).collect();
+            let heading = title_run
+                .and_then(|run| nav_str(run, &path!["text"]))
+                .unwrap_or_default();
+            let slot = if artist.albums.is_none() && !Vocabulary::matches(heading, words.singles) {
+                &mut artist.albums
+            } else {
+                &mut artist.singles
+            };
+            if slot.is_none() {
+                *slot = Some(section_from(title_run, releases));
+            }
+        }
+        Some("MUSIC_PAGE_TYPE_ARTIST") if artist.related.is_none() => {
+            let related = items.iter().filter_map(|i| parse_related(i)).collect();
+            artist.related = Some(section_from(title_run, related));
+        }
+        _ => {}
+    }
+}
+
+/// Build a section from its heading run, which links to the full listing.
+pub fn section_from<T>(title_run: Option<&Value>, items: Vec<T>) -> ArtistSection<T> {
+    let endpoint =
+        title_run.and_then(|run| nav(run, &path!["navigationEndpoint", "browseEndpoint"]));
+    let field = |key: &'static str| {
+        endpoint
+            .and_then(|e| nav_str(e, &path![key]))
+            .map(str::to_string)
+    };
+    ArtistSection {
+        title: title_run
+            .and_then(|run| nav_str(run, &path!["text"]))
+            .unwrap_or_default()
+            .to_string(),
+        browse_id: field("browseId"),
+        params: field("params"),
+        items,
+    }
+}
+
+/// Parse the first page of an artist's full album or singles listing (the
+/// target of a section's "See all" link).
+///
+/// The listing is served either as a grid of two-row items or as a list of
+/// rows; both are handled. Returns the releases and the continuation token.
+pub fn parse_artist_releases(response: &Value) -> (Vec<ArtistRelease>, Option<String>) {
+    let Some(section) = nav(response, paths::SINGLE_COLUMN)
+        .and_then(|v| nav(v, paths::TAB_CONTENT))
+        .and_then(|v| nav(v, paths::SECTION_LIST))
+        .and_then(|v| v.get(0))
+    else {
+        return (Vec::new(), None);
+    };
+
+    let (container, key) = if let Some(grid) = section.get("gridRenderer") {
+        (grid, "items")
+    } else if let Some(shelf) = section.get("musicShelfRenderer") {
+        (shelf, "contents")
+    } else if let Some(carousel) = section.get("musicCarouselShelfRenderer") {
+        (carousel, "contents")
+    } else {
+        return (Vec::new(), None);
+    };
+
+    let rows = nav_array(container, &path![key])
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    (parse_release_items(rows), get_continuation_token(container))
+}
+
+/// Parse release grid items or list rows, skipping anything else.
+pub fn parse_release_items(rows: &[Value]) -> Vec<ArtistRelease> {
+    rows.iter()
+        .filter_map(|row| match row.get(paths::MTRIR) {
+            Some(item) => parse_release(item),
+            None => parse_release_row(row.get(paths::MRLIR)?),
+        })
+        .collect()
+}
+
+/// Parse a list-layout release row.
+fn parse_release_row(data: &Value) -> Option<ArtistRelease> {
+    let subtitle = get_flex_column_item(data, 1).and_then(|c| nav_array(c, &path!["text", "runs"]));
+    Some(ArtistRelease {
+        title: get_item_text(data, 0)?.to_string(),
+        browse_id: nav_str(data, paths::NAVIGATION_BROWSE_ID)?.to_string(),
+        year: subtitle.and_then(|runs| parse_year_run(runs)),
+        release_type: subtitle.and_then(|runs| release_type(runs)),
+        thumbnails: parse_thumbnails(data),
+        is_explicit: nav(data, paths::BADGE_LABEL).is_some(),
+    })
+}
+
+/// The leading subtitle run when it is a plain label rather than a year or
+/// an artist link, e.g. `"EP"` in `"EP • 2019"`.
+pub fn release_type(runs: &[Value]) -> Option<String> {
+    let first = runs.first()?;
+    let text = nav_str(first, &path!["text"])?;
+    (first.get("navigationEndpoint").is_none()
+        && parse_year_run(std::slice::from_ref(first)).is_none())
+    .then(|| text.to_string())
+}
+
+fn parse_release(item: &Value) -> Option<ArtistRelease> {
+    let subtitle = nav_array(item, paths::SUBTITLE_RUNS);
+    Some(ArtistRelease {
+        title: nav_str(item, paths::TITLE_TEXT)?.to_string(),
+        browse_id: nav_str(item, paths::NAVIGATION_BROWSE_ID)?.to_string(),
+        year: subtitle.and_then(|runs| parse_year_run(runs)),
+        release_type: subtitle.and_then(|runs| release_type(runs)),
+        thumbnails: parse_thumbnails(item),
+        is_explicit: nav(
+            item,
+            &path![
+                "subtitleBadges",
+                0,
+                "musicInlineBadgeRenderer",
+                "accessibilityData"
+            ],
+        )
+        .is_some(),
+    })
+}
+
+pub fn parse_video(item: &Value) -> Option<ArtistVideo> {
+    let watch = nav(item, &path!["navigationEndpoint", "watchEndpoint"])?;
+    Some(ArtistVideo {
+        title: nav_str(item, paths::TITLE_TEXT)?.to_string(),
+        video_id: nav_str(watch, &path!["videoId"])?.to_string(),
+        // The view count is the last subtitle run, after the artist names.
+        views: nav_array(item, paths::SUBTITLE_RUNS)
+            .and_then(|runs| runs.last())
+            .and_then(|run| nav_str(run, &path!["text"]))
+            .and_then(parse_abbreviated_number),
+        thumbnails: parse_thumbnails(item),
+        video_type: nav_str(
+            watch,
+            &path![
+                "watchEndpointMusicSupportedConfigs",
+                "watchEndpointMusicConfig",
+                "musicVideoType"
+            ],
+        )
+        .map(MusicVideoType::from),
+    })
+}
+
+fn parse_related(item: &Value) -> Option<RelatedArtist> {
+    Some(RelatedArtist {
+        name: nav_str(item, paths::TITLE_TEXT)?.to_string(),
+        browse_id: nav_str(item, paths::NAVIGATION_BROWSE_ID)?.to_string(),
+        subscribers: nav_str(item, &path!["subtitle", "runs", 0, "text"])
+            .and_then(parse_abbreviated_number),
+        thumbnails: parse_thumbnails(item),
+    })
+}
+
+pub fn page_type(item: &Value) -> Option<&str> {
+    nav_str(
+        item,
+        &path![
+            "navigationEndpoint",
+            "browseEndpoint",
+            "browseEndpointContextSupportedConfigs",
+            "browseEndpointContextMusicConfig",
+            "pageType"
+        ],
+    )
+}
+
+/// Sort requested channels by whether a `subscription/subscribe` or
+/// `subscription/unsubscribe` response confirmed them.
+///
+/// Each confirmed channel comes back as an `updateSubscribeButtonAction`
+/// carrying its new `subscribed` state.
+pub fn parse_subscription_result(
+    response: &Value,
+    channel_ids: &[&str],
+    subscribed: bool,
+) -> SubscriptionResult {
+    let confirmed: Vec<&str> = nav_array(response, &path!["actions"])
+        .into_iter()
+        .flatten()
+        .filter_map(|action| action.get("updateSubscribeButtonAction"))
+        .filter(|update| update.get("subscribed").and_then(Value::as_bool) == Some(subscribed))
+        .filter_map(|update| nav_str(update, &path!["channelId"]))
+        .collect();
+
+    let (succeeded, failed): (Vec<_>, Vec<_>) = channel_ids
+        .iter()
+        .map(|id| id.to_string())
+        .partition(|id| confirmed.contains(&id.as_str()));
+    SubscriptionResult { succeeded, failed }
+}
+
+fn join_runs(runs: &[Value]) -> String {
+    runs.iter()
+        .filter_map(|run| nav_str(run, &path!["text"]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn album_carousel(title: &str, browse_id: &str) -> Value {
+        json!({
+            "musicCarouselShelfRenderer": {
+                "header": {
+                    "musicCarouselShelfBasicHeaderRenderer": { "title": { "runs": [{ "text": title }] } }
+                },
+                "contents": [{
+                    "musicTwoRowItemRenderer": {
+                        "title": { "runs": [{ "text": browse_id }] },
+                        "navigationEndpoint": {
+                            "browseEndpoint": {
+                                "browseId": browse_id,
+                                "browseEndpointContextSupportedConfigs": {
+                                    "browseEndpointContextMusicConfig": { "pageType": "MUSIC_PAGE_TYPE_ALBUM" }
+                                }
+                            }
+                        }
+                    }
+                }]
+            }
+        })
+    }
+
+    fn artist_response(sections: Vec<Value>) -> Value {
+        json!({
+            "contents": {
+                "singleColumnBrowseResultsRenderer": {
+                    "tabs": [{ "tabRenderer": { "content": { "sectionListRenderer": { "contents": sections } } } }]
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_album_carousels_by_order_when_headings_are_localized() {
+        let response = artist_response(vec![
+            album_carousel("Alben", "MPREa"),
+            album_carousel("Auskopplungen", "MPREb"),
+        ]);
+        let artist = parse_artist_page(&response, "UCx", "en");
+        assert_eq!(artist.albums.unwrap().items[0].browse_id, "MPREa");
+        assert_eq!(artist.singles.unwrap().items[0].browse_id, "MPREb");
+        assert!(artist.songs.is_none());
+        assert_eq!(artist.channel_id, "UCx");
+    }
+
+    #[test]
+    fn test_subscription_result_matches_requested_state() {
+        let response = json!({
+            "actions": [
+                { "updateSubscribeButtonAction": { "subscribed": true, "channelId": "UCa" } },
+                { "updateSubscribeButtonAction": { "subscribed": false, "channelId": "UCb" } },
+                { "addToGuideSectionAction": {} }
+            ]
+        });
+        let result = parse_subscription_result(&response, &["UCa", "UCb", "UCc"], true);
+        assert_eq!(result.succeeded, ["UCa"]);
+        assert_eq!(result.failed, ["UCb", "UCc"]);
+        assert!(!result.is_complete());
+    }
+
+    #[test]
+    fn test_singles_heading_without_albums() {
+        let artist = parse_artist_page(
+            &artist_response(vec![album_carousel("Singles", "MPREa")]),
+            "UCx",
+            "en",
+        );
+        assert!(artist.albums.is_none());
+        assert_eq!(artist.singles.unwrap().title, "Singles");
+    }
+}
@@ -0,0 +1,268 @@
+//! Year and localized date parsing.
+
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use serde_json::Value;
+
+/// Month name prefixes, matched case-insensitively against the start of a word.
+///
+/// Each entry is long enough to be unambiguous across the listed languages.
+#[cfg(feature = "chrono")]
+const MONTH_PREFIXES: &[(&str, u32)] = &[
+    // en
+    ("jan", 1),
+    ("feb", 2),
+    ("mar", 3),
+    ("apr", 4),
+    ("may", 5),
+    ("jun", 6),
+    ("jul", 7),
+    ("aug", 8),
+    ("sep", 9),
+    ("oct", 10),
+    ("nov", 11),
+    ("dec", 12),
+    // de
+    ("mär", 3),
+    ("mai", 5),
+    ("okt", 10),
+    ("dez", 12),
+];
+
+/// Weekday names, matched case-insensitively against the whole word.
+#[cfg(feature = "chrono")]
+const WEEKDAYS: &[(&str, Weekday)] = &[
+    // en
+    ("monday", Weekday::Mon),
+    ("tuesday", Weekday::Tue),
+    ("wednesday", Weekday::Wed),
+    ("thursday", Weekday::Thu),
+    ("friday", Weekday::Fri),
+    ("saturday", Weekday::Sat),
+    ("sunday", Weekday::Sun),
+    // de
+    ("montag", Weekday::Mon),
+    ("dienstag", Weekday::Tue),
+    ("mittwoch", Weekday::Wed),
+    ("donnerstag", Weekday::Thu),
+    ("freitag", Weekday::Fri),
+    ("samstag", Weekday::Sat),
+    ("sonntag", Weekday::Sun),
+];
+
+/// Relative history section headers.
+#[cfg(feature = "chrono")]
+const RELATIVE_PERIODS: &[(&str, RelativePeriod)] = &[
+    // en
+    ("today", RelativePeriod::Today),
+    ("yesterday", RelativePeriod::Yesterday),
+    ("this week", RelativePeriod::ThisWeek),
+    ("last week", RelativePeriod::LastWeek),
+    // de
+    ("heute", RelativePeriod::Today),
+    ("gestern", RelativePeriod::Yesterday),
+    ("diese woche", RelativePeriod::ThisWeek),
+    ("letzte woche", RelativePeriod::LastWeek),
+    ("vorige woche", RelativePeriod::LastWeek),
+];
+
+#[cfg(feature = "chrono")]
+#[derive(Clone, Copy)]
+enum RelativePeriod {
+    Today,
+    Yesterday,
+    ThisWeek,
+    LastWeek,
+}
+
+/// Find the year in a list of subtitle runs, e.g. `["Album", " • ", "2019"]`.
+///
+/// Used for both album and playlist headers.
+pub fn parse_year_run(runs: &[Value]) -> Option<u16> {
+    runs.iter()
+        .filter_map(|run| run.get("text").and_then(Value::as_str))
+        .find_map(parse_year)
+}
+
+/// Parse text that is exactly a four-digit year.
+fn parse_year(text: &str) -> Option<u16> {
+    let text = text.trim();
+    if text.len() == 4 && text.bytes().all(|b| b.is_ascii_digit()) {
+        text.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Parse a date such as `"March 5, 2021"`, `"5. März 2021"` or `"2021-03-05"`.
+///
+/// Used for album release dates and playlist "Added" labels.
+///
+/// Month names may be localized (English and German are recognised); the
+/// order of day and month does not matter.
+#[cfg(feature = "chrono")]
+pub fn parse_localized_date(text: &str) -> Option<NaiveDate> {
+    let text = text.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        return Some(date);
+    }
+
+    let mut year = None;
+    let mut month = None;
+    let mut day = None;
+    for word in text
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '.')
+        .filter(|word| !word.is_empty())
+    {
+        if word.bytes().all(|b| b.is_ascii_digit()) {
+            match word.len() {
+                4 => year = word.parse().ok(),
+                1 | 2 => day = word.parse().ok(),
+                _ => return None,
+            }
+        } else if month.is_none() {
+            month = parse_month(word);
+        }
+    }
+
+    NaiveDate::from_ymd_opt(year?, month?, day?)
+}
+
+/// The most recent date a play listed under a history section header can
+/// have, e.g. the last day of the month for `"March 2024"`.
+///
+/// Relative headers (`"Today"`, `"Last week"`, weekday names) are resolved
+/// against `today`. Where the first day of the week depends on the locale,
+/// the later of Monday and Sunday is assumed, so the result is never too
+/// early. Returns `None` for headers that are not recognised.
+#[cfg(feature = "chrono")]
+pub fn parse_history_period(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let text = text.trim().to_lowercase();
+    if let Some((_, period)) = RELATIVE_PERIODS.iter().find(|(name, _)| *name == text) {
+        let days_back = match period {
+            RelativePeriod::Today | RelativePeriod::ThisWeek => 0,
+            RelativePeriod::Yesterday => 1,
+            RelativePeriod::LastWeek => {
+                let weekday = today.weekday();
+                1 + weekday
+                    .num_days_from_monday()
+                    .min(weekday.num_days_from_sunday())
+            }
+        };
+        return today.checked_sub_days(Days::new(days_back.into()));
+    }
+    if let Some((_, weekday)) = WEEKDAYS.iter().find(|(name, _)| *name == text) {
+        let days_back =
+            (7 + today.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+        return today.checked_sub_days(Days::new(days_back.into()));
+    }
+    if let Some(date) = parse_localized_date(&text) {
+        return Some(date);
+    }
+    parse_month_year(&text).map(|last_day| last_day.min(today))
+}
+
+/// Parse a month such as `"March 2024"` into its last day.
+#[cfg(feature = "chrono")]
+fn parse_month_year(text: &str) -> Option<NaiveDate> {
+    let mut year = None;
+    let mut month = None;
+    for word in text.split_whitespace() {
+        if word.len() == 4 && word.bytes().all(|b| b.is_ascii_digit()) {
+            year = word.parse().ok();
+        } else if month.is_none() {
+            month = Some(parse_month(word)?);
+        } else {
+            return None;
+        }
+    }
+    let first = NaiveDate::from_ymd_opt(year?, month?, 1)?;
+    first.checked_add_months(Months::new(1))?.pred_opt()
+}
+
+/// Look up a possibly localized month name.
+#[cfg(feature = "chrono")]
+fn parse_month(word: &str) -> Option<u32> {
+    let word = word.to_lowercase();
+    MONTH_PREFIXES
+        .iter()
+        .find(|(prefix, _)| word.starts_with(prefix))
+        .map(|(_, month)| *month)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_year_run() {
+        let runs = json!([{ "text": "Album" }, { "text": " • " }, { "text": "2019" }]);
+        assert_eq!(parse_year_run(runs.as_array().unwrap()), Some(2019));
+
+        let runs = json!([{ "text": "Playlist" }, { "text": " • " }, { "text": "12 songs" }]);
+        assert_eq!(parse_year_run(runs.as_array().unwrap()), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_parse_localized_date() {
+        let date = NaiveDate::from_ymd_opt(2021, 3, 5);
+        let cases = [
+            // en
+            "March 5, 2021",
+            "Mar 5, 2021",
+            "5 March 2021",
+            // de
+            "5. März 2021",
+            "05. März 2021",
+            // iso
+            "2021-03-05",
+        ];
+        for text in cases {
+            assert_eq!(parse_localized_date(text), date, "{text}");
+        }
+
+        assert_eq!(
+            parse_localized_date("1. Dezember 1999"),
+            NaiveDate::from_ymd_opt(1999, 12, 1)
+        );
+        assert_eq!(
+            parse_localized_date("Oktober 12, 2004"),
+            NaiveDate::from_ymd_opt(2004, 10, 12)
+        );
+        assert_eq!(parse_localized_date("2021"), None);
+        assert_eq!(parse_localized_date("yesterday"), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_parse_history_period() {
+        // A Wednesday.
+        let today = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d);
+        let cases = [
+            ("Today", date(5, 15)),
+            ("Gestern", date(5, 14)),
+            ("This week", date(5, 15)),
+            // Sunday, ending the Monday-based week.
+            ("Last week", date(5, 12)),
+            ("Monday", date(5, 13)),
+            ("Donnerstag", date(5, 9)),
+            ("March 2024", date(3, 31)),
+            ("Februar 2024", date(2, 29)),
+            // The current month ends today.
+            ("May 2024", date(5, 15)),
+            ("May 3, 2024", date(5, 3)),
+            ("Earlier", None),
+            ("12 songs", None),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(parse_history_period(text, today), expected, "{text}");
+        }
+
+        // On a Sunday, last week ends yesterday for a Sunday-based week.
+        let sunday = NaiveDate::from_ymd_opt(2024, 5, 19).unwrap();
+        assert_eq!(parse_history_period("Last week", sunday), date(5, 18));
+    }
+}
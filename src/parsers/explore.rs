@@ -0,0 +1,261 @@
+//! Explore page parsing: moods and genres, charts.
+
+use serde_json::Value;
+
+use crate::continuation::get_continuation_token;
+use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::locale::is_track_count;
+use crate::parsers::navigation::paths;
+use crate::parsers::playlist::parse_library_playlist_item_rows;
+use crate::parsers::search::{SUBTITLE_SEPARATOR, parse_search_result, parse_two_row_result};
+use crate::types::{
+    Author, ChartEntry, ChartSection, ChartTrend, Charts, MoodCategory, MoodSection, ParseWarning,
+    PlaylistSummary, SearchResultType,
+};
+
+/// Parse the `FEmusic_moods_and_genres` browse response.
+///
+/// Buttons without browse `params` are skipped, as are sections left empty.
+pub fn parse_mood_categories(response: &Value) -> Vec<MoodSection> {
+    sections(response)
+        .filter_map(|section| section.get("gridRenderer"))
+        .filter_map(|grid| {
+            let categories: Vec<MoodCategory> = nav_array(grid, &path!["items"])?
+                .iter()
+                .filter_map(parse_mood_category)
+                .collect();
+            if categories.is_empty() {
+                return None;
+            }
+            Some(MoodSection {
+                title: nav_str(
+                    grid,
+                    &path!["header", "gridHeaderRenderer", "title", "runs", 0, "text"],
+                )
+                .unwrap_or_default()
+                .to_string(),
+                categories,
+            })
+        })
+        .collect()
+}
+
+fn parse_mood_category(item: &Value) -> Option<MoodCategory> {
+    let button = item.get("musicNavigationButtonRenderer")?;
+    Some(MoodCategory {
+        title: nav_str(button, &path!["buttonText", "runs", 0, "text"])?.to_string(),
+        params: nav_str(button, &path!["clickCommand", "browseEndpoint", "params"])?.to_string(),
+    })
+}
+
+/// Parse the playlists of a `FEmusic_moods_and_genres_category` browse
+/// response, from its grids and carousels in page order.
+///
+/// Returns the playlists and the continuation token of the last grid that
+/// has one.
+pub fn parse_mood_playlists(response: &Value) -> (Vec<PlaylistSummary>, Option<String>) {
+    let mut playlists = Vec::new();
+    let mut continuation = None;
+    for section in sections(response) {
+        if let Some(grid) = section.get("gridRenderer") {
+            let items = nav_array(grid, &path!["items"])
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            playlists.extend(parse_mood_playlist_rows(items, &mut Vec::new()));
+            continuation = get_continuation_token(grid).or(continuation);
+        } else if let Some(items) =
+            nav_array(section, &path!["musicCarouselShelfRenderer", "contents"]).or_else(|| {
+                nav_array(
+                    section,
+                    &path!["musicImmersiveCarouselShelfRenderer", "contents"],
+                )
+            })
+        {
+            playlists.extend(parse_mood_playlist_rows(items, &mut Vec::new()));
+        }
+    }
+    (playlists, continuation)
+}
+
+/// Parse playlist cards. Playlists whose subtitle reads "Name • 50 songs"
+/// without a channel link, as editorial playlists do, are credited to that
+/// name.
+pub fn parse_mood_playlist_rows(
+    items: &[Value],
+    warnings: &mut Vec<ParseWarning>,
+) -> Vec<PlaylistSummary> {
+    let mut playlists = parse_library_playlist_item_rows(items, warnings);
+    for playlist in playlists.iter_mut().filter(|p| p.author.is_none()) {
+        playlist.author = items
+            .iter()
+            .filter_map(|item| item.get(paths::MTRIR))
+            .find(|card| card_playlist_id(card) == Some(playlist.playlist_id.as_str()))
+            .and_then(subtitle_author);
+    }
+    playlists
+}
+
+fn card_playlist_id(card: &Value) -> Option<&str> {
+    nav_str(card, paths::NAVIGATION_PLAYLIST_ID)
+        .or_else(|| nav_str(card, paths::NAVIGATION_BROWSE_ID))
+        .map(|id| id.trim_start_matches("VL"))
+}
+
+/// The name before the track count of a two-field subtitle.
+fn subtitle_author(card: &Value) -> Option<Author> {
+    let runs = nav_array(card, paths::SUBTITLE_RUNS)?;
+    let groups: Vec<String> = runs
+        .split(|run| nav_str(run, &path!["text"]) == Some(SUBTITLE_SEPARATOR))
+        .map(|group| {
+            group
+                .iter()
+                .filter_map(|run| nav_str(run, &path!["text"]))
+                .collect()
+        })
+        .collect();
+    match groups.as_slice() {
+        [name, count] if !name.trim().is_empty() && is_track_count(count) => Some(Author {
+            name: name.trim().to_string(),
+            id: None,
+        }),
+        _ => None,
+    }
+}
+
+/// Parse the `FEmusic_charts` browse response.
+///
+/// Charts are told apart by what their entries are rather than by their
+/// localized headings: playlist cards are the video charts, artist rows the
+/// artist chart, and song or video rows the song chart before the other
+/// charts and the trending chart after them. Other carousels, such as the
+/// genre charts of the US page, are skipped.
+pub fn parse_charts(response: &Value) -> Charts {
+    let mut charts = Charts {
+        countries: nav_array(
+            response,
+            &path!["frameworkUpdates", "entityBatchUpdate", "mutations"],
+        )
+        .into_iter()
+        .flatten()
+        .filter_map(|mutation| {
+            nav_str(
+                mutation,
+                &path!["payload", "musicFormBooleanChoice", "opaqueToken"],
+            )
+        })
+        .map(str::to_string)
+        .collect(),
+        ..Default::default()
+    };
+
+    for section in sections(response) {
+        if let Some(shelf) = section.get("musicShelfRenderer") {
+            charts.country = charts.country.or_else(|| {
+                nav_str(
+                    shelf,
+                    &path![
+                        "subheaders",
+                        0,
+                        "musicSideAlignedItemRenderer",
+                        "startItems",
+                        0,
+                        "musicSortFilterButtonRenderer",
+                        "title",
+                        "runs",
+                        0,
+                        "text"
+                    ],
+                )
+                .map(str::to_string)
+            });
+            continue;
+        }
+        let Some(carousel) = section.get("musicCarouselShelfRenderer") else {
+            continue;
+        };
+        let Some(chart) = parse_chart(carousel) else {
+            continue;
+        };
+        let first = &chart.entries[0];
+        let slot = match first.item.result_type {
+            SearchResultType::Playlist if first.rank.is_none() => &mut charts.videos,
+            SearchResultType::Artist => &mut charts.artists,
+            SearchResultType::Song | SearchResultType::Video => {
+                if charts.videos.is_none() && charts.artists.is_none() {
+                    &mut charts.songs
+                } else {
+                    &mut charts.trending
+                }
+            }
+            _ => continue,
+        };
+        if slot.is_none() {
+            *slot = Some(chart);
+        }
+    }
+    charts
+}
+
+fn parse_chart(carousel: &Value) -> Option<ChartSection> {
+    let entries: Vec<ChartEntry> = nav_array(carousel, &path!["contents"])?
+        .iter()
+        .filter_map(parse_chart_entry)
+        .collect();
+    if entries.is_empty() {
+        return None;
+    }
+    let title_run = nav(
+        carousel,
+        &path![
+            "header",
+            "musicCarouselShelfBasicHeaderRenderer",
+            "title",
+            "runs",
+            0
+        ],
+    );
+    Some(ChartSection {
+        title: title_run
+            .and_then(|run| nav_str(run, &path!["text"]))
+            .unwrap_or_default()
+            .to_string(),
+        playlist_id: title_run
+            .and_then(|run| nav_str(run, paths::NAVIGATION_BROWSE_ID))
+            .and_then(|id| id.strip_prefix("VL"))
+            .map(str::to_string),
+        entries,
+    })
+}
+
+fn parse_chart_entry(item: &Value) -> Option<ChartEntry> {
+    let index = nav(
+        item,
+        &path![
+            paths::MRLIR,
+            "customIndexColumn",
+            "musicCustomIndexColumnRenderer"
+        ],
+    );
+    Some(ChartEntry {
+        rank: index
+            .and_then(|index| nav_str(index, &path!["text", "runs", 0, "text"]))
+            .and_then(|rank| rank.trim().parse().ok()),
+        trend: index
+            .and_then(|index| nav_str(index, &path!["icon", "iconType"]))
+            .and_then(|icon| match icon {
+                "ARROW_DROP_UP" => Some(ChartTrend::Up),
+                "ARROW_DROP_DOWN" => Some(ChartTrend::Down),
+                "ARROW_CHART_NEUTRAL" => Some(ChartTrend::Neutral),
+                _ => None,
+            }),
+        item: parse_search_result(item).or_else(|| parse_two_row_result(item))?,
+    })
+}
+
+fn sections(response: &Value) -> impl Iterator<Item = &Value> {
+    nav(response, paths::SINGLE_COLUMN)
+        .and_then(|v| nav(v, paths::TAB_CONTENT))
+        .and_then(|v| nav_array(v, paths::SECTION_LIST))
+        .into_iter()
+        .flatten()
+}
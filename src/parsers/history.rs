@@ -0,0 +1,154 @@
+//! Listening history parsing.
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+use serde_json::Value;
+
+use crate::continuation::get_continuation_token;
+use crate::nav::{nav, nav_array, nav_str};
+#[cfg(feature = "chrono")]
+use crate::parsers::date::parse_history_period;
+use crate::parsers::navigation::paths;
+use crate::parsers::playlist::parse_playlist_track;
+use crate::types::{HistoryBucket, HistoryTrack};
+
+/// Parse a `FEmusic_history` browse response.
+///
+/// The history is split into one shelf per period; each track is tagged with
+/// its shelf's header. A paused or empty history yields no tracks.
+pub fn parse_history(response: &Value) -> Vec<HistoryTrack> {
+    parse_history_buckets(response)
+        .0
+        .into_iter()
+        .flat_map(|bucket| bucket.tracks)
+        .collect()
+}
+
+/// Parse a `FEmusic_history` browse response into its periods, with the
+/// token of the section list continuation that loads older periods.
+pub fn parse_history_buckets(response: &Value) -> (Vec<HistoryBucket>, Option<String>) {
+    let Some(section_list) = nav(response, paths::SINGLE_COLUMN)
+        .and_then(|v| nav(v, paths::TAB_CONTENT))
+        .and_then(|v| nav(v, &path!["sectionListRenderer"]))
+    else {
+        return (Vec::new(), None);
+    };
+    let sections = nav_array(section_list, &path!["contents"])
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    (
+        parse_history_sections(sections),
+        get_continuation_token(section_list),
+    )
+}
+
+/// Parse history sections, from the first page or a section list
+/// continuation. Sections other than track shelves are skipped.
+pub fn parse_history_sections(sections: &[Value]) -> Vec<HistoryBucket> {
+    sections
+        .iter()
+        .filter_map(|s| nav(s, paths::MUSIC_SHELF))
+        .map(|shelf| {
+            let period = nav_str(shelf, paths::TITLE_TEXT).unwrap_or_default();
+            let tracks = nav_array(shelf, &path!["contents"])
+                .into_iter()
+                .flatten()
+                .filter_map(|row| {
+                    Some(HistoryTrack {
+                        track: parse_playlist_track(row)?,
+                        played: period.to_string(),
+                        feedback_token: removal_token(row).map(str::to_string),
+                    })
+                })
+                .collect();
+            HistoryBucket {
+                period: period.to_string(),
+                #[cfg(feature = "chrono")]
+                latest: None,
+                tracks,
+            }
+        })
+        .collect()
+}
+
+/// Resolve the dates of `buckets` against `today` and drop those entirely
+/// before `cutoff`, along with every older one after them.
+///
+/// Returns whether a bucket was dropped, i.e. the cutoff was reached.
+/// Buckets with an unrecognised header are kept.
+#[cfg(feature = "chrono")]
+pub fn truncate_history_at(
+    buckets: &mut Vec<HistoryBucket>,
+    cutoff: NaiveDate,
+    today: NaiveDate,
+) -> bool {
+    for bucket in buckets.iter_mut() {
+        bucket.latest = parse_history_period(&bucket.period, today);
+    }
+    match buckets
+        .iter()
+        .position(|bucket| bucket.latest.is_some_and(|latest| latest < cutoff))
+    {
+        Some(older) => {
+            buckets.truncate(older);
+            true
+        }
+        None => false,
+    }
+}
+
+/// The feedback token of the row's "Remove from history" menu item.
+fn removal_token(row: &Value) -> Option<&str> {
+    nav_array(row, &path![paths::MRLIR, "menu", "menuRenderer", "items"])?
+        .iter()
+        .find_map(|item| {
+            nav_str(
+                item,
+                &path![
+                    "menuServiceItemRenderer",
+                    "serviceEndpoint",
+                    "feedbackEndpoint",
+                    "feedbackToken"
+                ],
+            )
+        })
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use super::*;
+
+    fn bucket(period: &str) -> HistoryBucket {
+        HistoryBucket {
+            period: period.to_string(),
+            latest: None,
+            tracks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_truncate_history_at_cutoff() {
+        let today = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+        let mut buckets = [
+            "Today",
+            "Yesterday",
+            "Earlier",
+            "Monday",
+            "Last week",
+            "April 2024",
+        ]
+        .map(bucket)
+        .to_vec();
+
+        let cutoff = NaiveDate::from_ymd_opt(2024, 5, 13).unwrap();
+        assert!(truncate_history_at(&mut buckets, cutoff, today));
+        let periods: Vec<_> = buckets.iter().map(|b| b.period.as_str()).collect();
+        assert_eq!(periods, ["Today", "Yesterday", "Earlier", "Monday"]);
+        assert_eq!(buckets[1].latest, NaiveDate::from_ymd_opt(2024, 5, 14));
+        assert_eq!(buckets[2].latest, None);
+
+        let cutoff = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert!(!truncate_history_at(&mut buckets, cutoff, today));
+        assert_eq!(buckets.len(), 4);
+    }
+}
@@ -0,0 +1,93 @@
+//! Home feed parsing.
+
+use serde_json::Value;
+
+use crate::continuation::get_continuation_token;
+use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::navigation::paths;
+use crate::parsers::playlist::parse_playlist_item;
+use crate::parsers::search::{parse_search_result, parse_two_row_result};
+use crate::types::{HomeItem, HomeSection, SearchResultType};
+
+/// Parse the `FEmusic_home` browse response into its carousels and the token
+/// for the sections below them.
+pub fn parse_home(response: &Value) -> (Vec<HomeSection>, Option<String>) {
+    let Some(section_list) = nav(response, paths::SINGLE_COLUMN)
+        .and_then(|v| nav(v, paths::TAB_CONTENT))
+        .and_then(|v| v.get("sectionListRenderer"))
+    else {
+        return (Vec::new(), None);
+    };
+    let sections = nav_array(section_list, &path!["contents"])
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    (
+        parse_home_sections(sections),
+        get_continuation_token(section_list),
+    )
+}
+
+/// Parse home feed sections. Sections other than carousels, such as the
+/// mood chips, and carousels without any item this crate can read are
+/// skipped.
+pub fn parse_home_sections(sections: &[Value]) -> Vec<HomeSection> {
+    sections.iter().filter_map(parse_home_section).collect()
+}
+
+fn parse_home_section(section: &Value) -> Option<HomeSection> {
+    let carousel = section
+        .get("musicCarouselShelfRenderer")
+        .or_else(|| section.get("musicImmersiveCarouselShelfRenderer"))?;
+    let contents: Vec<HomeItem> = nav_array(carousel, &path!["contents"])?
+        .iter()
+        .filter_map(parse_home_item)
+        .collect();
+    if contents.is_empty() {
+        return None;
+    }
+    Some(HomeSection {
+        title: nav_str(
+            carousel,
+            &path![
+                "header",
+                "musicCarouselShelfBasicHeaderRenderer",
+                "title",
+                "runs",
+                0,
+                "text"
+            ],
+        )
+        .unwrap_or_default()
+        .to_string(),
+        contents,
+    })
+}
+
+fn parse_home_item(item: &Value) -> Option<HomeItem> {
+    if item.get(paths::MTRIR).is_some_and(is_playlist_card) {
+        return parse_playlist_item(item).map(HomeItem::Playlist);
+    }
+    let result = parse_search_result(item).or_else(|| parse_two_row_result(item))?;
+    match result.result_type {
+        SearchResultType::Song | SearchResultType::Video => Some(HomeItem::Song(result)),
+        SearchResultType::Album => Some(HomeItem::Album(result)),
+        SearchResultType::Artist => Some(HomeItem::Artist(result)),
+        _ => None,
+    }
+}
+
+/// Whether a card links to a playlist page or, as mixes do, starts playing a
+/// playlist directly.
+fn is_playlist_card(card: &Value) -> bool {
+    nav(card, &path!["navigationEndpoint", "watchPlaylistEndpoint"]).is_some()
+        || nav_str(
+            card,
+            &path![
+                "navigationEndpoint",
+                "browseEndpoint",
+                "browseEndpointContextSupportedConfigs",
+                "browseEndpointContextMusicConfig",
+                "pageType"
+            ],
+        ) == Some("MUSIC_PAGE_TYPE_PLAYLIST")
+}
@@ -0,0 +1,186 @@
+//! Library listing parsing (other than playlists).
+
+use serde_json::Value;
+
+use crate::continuation::get_continuation_token;
+use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::artist::release_type;
+use crate::parsers::date::parse_year_run;
+use crate::parsers::navigation::paths;
+use crate::parsers::number::parse_abbreviated_number;
+use crate::parsers::playlist::{find_library_grid, parse_playlist_track, parse_thumbnails};
+use crate::parsers::search::{SUBTITLE_SEPARATOR, is_artist_run};
+use crate::parsers::track::{get_item_text, parse_artist_runs};
+use crate::types::{LibraryAlbum, LibraryArtist, PlaylistTrack};
+
+/// Parse the first page of the user's artist subscriptions.
+///
+/// Returns the artists and the continuation token. An empty library yields
+/// no items.
+pub fn parse_library_artists(response: &Value) -> (Vec<LibraryArtist>, Option<String>) {
+    let (rows, token) = library_shelf_rows(response);
+    (parse_library_artist_rows(rows), token)
+}
+
+/// Parse the first page of the library's Artists tab, the artists of saved
+/// songs.
+///
+/// Returns the artists and the continuation token.
+pub fn parse_library_track_artists(response: &Value) -> (Vec<LibraryArtist>, Option<String>) {
+    let (rows, token) = library_shelf_rows(response);
+    (parse_library_track_artist_rows(rows), token)
+}
+
+/// Parse the first page of the library's albums.
+///
+/// Returns the albums and the continuation token.
+pub fn parse_library_albums(response: &Value) -> (Vec<LibraryAlbum>, Option<String>) {
+    let Some(grid) = find_library_grid(response) else {
+        return (Vec::new(), None);
+    };
+    let items = nav_array(grid, &path!["items"])
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    (
+        parse_library_album_items(items),
+        get_continuation_token(grid),
+    )
+}
+
+/// Parse the first page of the library's songs.
+///
+/// Returns the tracks and the continuation token.
+pub fn parse_library_songs(response: &Value) -> (Vec<PlaylistTrack>, Option<String>) {
+    let (rows, token) = library_shelf_rows(response);
+    (parse_library_song_rows(rows), token)
+}
+
+/// Parse library song rows, skipping the "Shuffle all" row that heads the
+/// first page.
+pub fn parse_library_song_rows(rows: &[Value]) -> Vec<PlaylistTrack> {
+    rows.iter()
+        .filter(|row| {
+            nav(
+                row,
+                &path![paths::MRLIR, "navigationEndpoint", "watchPlaylistEndpoint"],
+            )
+            .is_none()
+        })
+        .filter_map(parse_playlist_track)
+        .collect()
+}
+
+/// Rows and continuation token of a library list page.
+pub fn library_shelf_rows(response: &Value) -> (&[Value], Option<String>) {
+    let Some(shelf) = find_library_shelf(response) else {
+        return (&[], None);
+    };
+    let rows = nav_array(shelf, &path!["contents"])
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    (rows, get_continuation_token(shelf))
+}
+
+/// Find the list shelf of a library page, which may be wrapped in an
+/// `itemSectionRenderer`.
+fn find_library_shelf(response: &Value) -> Option<&Value> {
+    nav(response, paths::SINGLE_COLUMN)
+        .and_then(|v| nav(v, paths::TAB_CONTENT))
+        .and_then(|v| nav_array(v, paths::SECTION_LIST))?
+        .iter()
+        .find_map(|section| {
+            section.get("musicShelfRenderer").or_else(|| {
+                nav(
+                    section,
+                    &path!["itemSectionRenderer", "contents", 0, "musicShelfRenderer"],
+                )
+            })
+        })
+}
+
+/// Parse subscription rows, skipping anything else.
+pub fn parse_library_artist_rows(rows: &[Value]) -> Vec<LibraryArtist> {
+    rows.iter()
+        .filter_map(|row| parse_library_artist(row.get(paths::MRLIR)?, ArtistCount::Subscribers))
+        .collect()
+}
+
+/// Parse library Artists tab rows, skipping anything else.
+pub fn parse_library_track_artist_rows(rows: &[Value]) -> Vec<LibraryArtist> {
+    rows.iter()
+        .filter_map(|row| parse_library_artist(row.get(paths::MRLIR)?, ArtistCount::Songs))
+        .collect()
+}
+
+/// What the count in an artist row's subtitle counts.
+#[derive(Clone, Copy)]
+enum ArtistCount {
+    Subscribers,
+    Songs,
+}
+
+/// Parse one artist row: the name in flex column 0 and a subtitle such as
+/// `"1.2M subscribers"` or `"12 songs"` in flex column 1.
+fn parse_library_artist(data: &Value, count: ArtistCount) -> Option<LibraryArtist> {
+    let browse_id = nav_str(data, paths::NAVIGATION_BROWSE_ID)?;
+    let subtitle_count = get_item_text(data, 1).and_then(parse_abbreviated_number);
+    Some(LibraryArtist {
+        name: get_item_text(data, 0)?.to_string(),
+        browse_id: browse_id
+            .strip_prefix("MPLA")
+            .unwrap_or(browse_id)
+            .to_string(),
+        subscribers: subtitle_count.filter(|_| matches!(count, ArtistCount::Subscribers)),
+        songs: subtitle_count
+            .filter(|_| matches!(count, ArtistCount::Songs))
+            .and_then(|n| u32::try_from(n).ok()),
+        thumbnails: parse_thumbnails(data),
+    })
+}
+
+/// Parse library album grid items, skipping anything else.
+pub fn parse_library_album_items(items: &[Value]) -> Vec<LibraryAlbum> {
+    items
+        .iter()
+        .filter_map(|item| parse_library_album(item.get(paths::MTRIR)?))
+        .collect()
+}
+
+/// Parse one album tile, whose subtitle reads e.g. `"Album • Artist • 2019"`.
+fn parse_library_album(item: &Value) -> Option<LibraryAlbum> {
+    let subtitle = nav_array(item, paths::SUBTITLE_RUNS)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let album_type = release_type(subtitle);
+    let groups = || subtitle.split(|run| nav_str(run, &path!["text"]) == Some(SUBTITLE_SEPARATOR));
+    // Prefer linked artists; otherwise take the first group that is neither
+    // the type label nor the year (e.g. an unlinked "Various Artists").
+    let artists = groups()
+        .find(|group| group.iter().any(is_artist_run))
+        .or_else(|| {
+            groups()
+                .skip(usize::from(album_type.is_some()))
+                .find(|group| !group.is_empty() && parse_year_run(group).is_none())
+        })
+        .map(parse_artist_runs)
+        .unwrap_or_default();
+
+    Some(LibraryAlbum {
+        title: nav_str(item, paths::TITLE_TEXT)?.to_string(),
+        browse_id: nav_str(item, paths::NAVIGATION_BROWSE_ID)?.to_string(),
+        album_type,
+        artists,
+        year: parse_year_run(subtitle),
+        thumbnails: parse_thumbnails(item),
+        is_explicit: nav(
+            item,
+            &path![
+                "subtitleBadges",
+                0,
+                "musicInlineBadgeRenderer",
+                "accessibilityData"
+            ],
+        )
+        .is_some(),
+    })
+}
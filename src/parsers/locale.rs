@@ -0,0 +1,163 @@
+//! Words the locale-sensitive parsers look for, per response language.
+//!
+//! Header labels such as `"12 songs • 45 minutes"` are translated by the
+//! server. Parsers pick the words to match from the language the client asked
+//! for, rather than guessing the language from the response; the client makes
+//! sure responses come back in that language (see
+//! [`YTMusicClientBuilder::with_response_language_override`](crate::YTMusicClientBuilder::with_response_language_override)).
+
+/// Lowercase word stems identifying header labels in one language.
+#[derive(Debug)]
+pub struct Vocabulary {
+    /// Track counts, e.g. `"12 songs"`.
+    pub songs: &'static [&'static str],
+    /// Total durations, e.g. `"45 minutes"`.
+    pub duration: &'static [&'static str],
+    /// View counts, e.g. `"1.2M views"`.
+    pub views: &'static [&'static str],
+    /// Like counts, e.g. `"34K likes"`.
+    pub likes: &'static [&'static str],
+    /// Leading word of a playlist row's added date, e.g. `"Added 2 weeks ago"`.
+    pub added: &'static [&'static str],
+    /// Headings of singles shelves on artist pages.
+    pub singles: &'static [&'static str],
+    /// Player errors asking to confirm the viewer's age.
+    pub age_restricted: &'static [&'static str],
+    /// Player errors offering a paid membership.
+    pub premium: &'static [&'static str],
+    /// Player errors for content blocked in the viewer's country.
+    pub region_blocked: &'static [&'static str],
+}
+
+impl Vocabulary {
+    /// Whether `text` contains any of `stems`, ignoring case.
+    pub fn matches(text: &str, stems: &[&str]) -> bool {
+        let text = text.to_lowercase();
+        stems.iter().any(|stem| text.contains(stem))
+    }
+}
+
+/// The English vocabulary, also used for languages without one.
+pub const ENGLISH: Vocabulary = Vocabulary {
+    songs: &["song", "track"],
+    duration: &["hour", "minute"],
+    views: &["view"],
+    likes: &["like"],
+    added: &["added"],
+    singles: &["single"],
+    age_restricted: &["your age", "age-restricted", "inappropriate for some users"],
+    premium: &["premium"],
+    region_blocked: &["your country", "in your region"],
+};
+
+const VOCABULARIES: &[(&str, Vocabulary)] = &[
+    ("en", ENGLISH),
+    (
+        "de",
+        Vocabulary {
+            songs: &["song", "titel", "track"],
+            duration: &["stunde", "std", "minute", "min"],
+            views: &["aufruf"],
+            likes: &["like", "mag"],
+            added: &["hinzugefügt"],
+            singles: &["single"],
+            age_restricted: &["dein alter", "altersbeschränk"],
+            premium: &["premium"],
+            region_blocked: &["deinem land", "in deiner region"],
+        },
+    ),
+    (
+        "fr",
+        Vocabulary {
+            songs: &["titre", "morceau", "chanson"],
+            duration: &["heure", "minute"],
+            views: &["vue"],
+            likes: &["j'aime", "j’aime", "like"],
+            added: &["ajouté"],
+            singles: &["single"],
+            age_restricted: &["votre âge", "limite d'âge"],
+            premium: &["premium"],
+            region_blocked: &["votre pays"],
+        },
+    ),
+    (
+        "es",
+        Vocabulary {
+            songs: &["canción", "canciones", "tema"],
+            duration: &["hora", "minuto"],
+            views: &["visualizaci", "reproducci", "vista"],
+            likes: &["me gusta"],
+            added: &["añadido", "agregado"],
+            singles: &["sencillo", "single"],
+            age_restricted: &["tu edad", "restricción de edad"],
+            premium: &["premium"],
+            region_blocked: &["tu país"],
+        },
+    ),
+    (
+        "pt",
+        Vocabulary {
+            songs: &["música", "musica", "faixa"],
+            duration: &["hora", "minuto"],
+            views: &["visualiza"],
+            likes: &["curtida", "gostei"],
+            added: &["adicionado"],
+            singles: &["single"],
+            age_restricted: &["sua idade", "restrição de idade"],
+            premium: &["premium"],
+            region_blocked: &["seu país"],
+        },
+    ),
+];
+
+/// The vocabulary for a language tag such as `"en"` or `"pt-BR"`.
+///
+/// Only the primary subtag is considered. Languages without a vocabulary fall
+/// back to English, so their header counts stay unset rather than misread.
+pub fn vocabulary(language: &str) -> &'static Vocabulary {
+    let primary = language.split(['-', '_']).next().unwrap_or_default();
+    VOCABULARIES
+        .iter()
+        .find(|(tag, _)| tag.eq_ignore_ascii_case(primary))
+        .map_or(&ENGLISH, |(_, vocabulary)| vocabulary)
+}
+
+/// Whether `text` is a track count such as `"12 songs"` in any language with
+/// a vocabulary, for layouts that put a count where a name could be.
+pub fn is_track_count(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_digit())
+        && VOCABULARIES
+            .iter()
+            .any(|(_, vocabulary)| Vocabulary::matches(text, vocabulary.songs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vocabulary_uses_primary_subtag() {
+        assert!(Vocabulary::matches(
+            "1.234 Aufrufe",
+            vocabulary("de-AT").views
+        ));
+        assert!(Vocabulary::matches(
+            "120 músicas",
+            vocabulary("pt_BR").songs
+        ));
+        assert!(!Vocabulary::matches(
+            "1.234 Aufrufe",
+            vocabulary("en").views
+        ));
+        // Unknown languages read English labels.
+        assert!(Vocabulary::matches("12 songs", vocabulary("xx").songs));
+    }
+
+    #[test]
+    fn test_track_count_in_any_language() {
+        assert!(is_track_count("80 songs"));
+        assert!(is_track_count("1.024 Titel"));
+        assert!(!is_track_count("1.2M views"));
+        assert!(!is_track_count("Songs to sing along"));
+    }
+}
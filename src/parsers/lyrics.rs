@@ -0,0 +1,100 @@
+//! Lyrics parsing.
+
+use serde_json::Value;
+
+use crate::nav::{nav, nav_array, nav_str};
+use crate::types::{Lyrics, TimedLyricLine, TimedLyrics};
+
+/// Parse a lyrics browse response (`MPLY...`).
+///
+/// Returns `None` when the track has no lyrics, in which case the page holds
+/// only a message such as "Lyrics not available".
+pub fn parse_lyrics(response: &Value) -> Option<Lyrics> {
+    let shelf = nav_array(
+        response,
+        &path!["contents", "sectionListRenderer", "contents"],
+    )?
+    .iter()
+    .find_map(|section| section.get("musicDescriptionShelfRenderer"))?;
+    let text: String = nav_array(shelf, &path!["description", "runs"])?
+        .iter()
+        .filter_map(|run| nav_str(run, &path!["text"]))
+        .collect();
+    if text.trim().is_empty() {
+        return None;
+    }
+    // The footer credits the provider, sometimes followed by copyright
+    // lines.
+    let footer: String = nav_array(shelf, &path!["footer", "runs"])
+        .into_iter()
+        .flatten()
+        .filter_map(|run| nav_str(run, &path!["text"]))
+        .collect();
+    let (copyright, source): (Vec<_>, Vec<_>) = footer
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .partition(|line| is_copyright_line(line));
+    let join = |lines: Vec<&str>| (!lines.is_empty()).then(|| lines.join("\n"));
+    Some(Lyrics {
+        text,
+        source: join(source),
+        copyright: join(copyright),
+    })
+}
+
+fn is_copyright_line(line: &str) -> bool {
+    line.contains(['©', '℗']) || line.to_lowercase().starts_with("copyright")
+}
+
+/// Parse a lyrics browse response requested as the Android client.
+///
+/// Prefers the line-timed `timedLyricsData`, falling back to the plain
+/// lyrics. Returns `None` when the track has no lyrics.
+pub fn parse_timed_lyrics(response: &Value) -> Option<TimedLyrics> {
+    let data = nav(
+        response,
+        &path![
+            "contents",
+            "elementRenderer",
+            "newElement",
+            "type",
+            "componentType",
+            "model",
+            "timedLyricsModel",
+            "lyricsData"
+        ],
+    );
+    let lines: Vec<_> = data
+        .and_then(|data| nav_array(data, &path!["timedLyricsData"]))
+        .into_iter()
+        .flatten()
+        .filter_map(parse_timed_line)
+        .collect();
+    if lines.is_empty() {
+        return parse_lyrics(response).map(TimedLyrics::Plain);
+    }
+    Some(TimedLyrics::Timed {
+        lines,
+        source: data
+            .and_then(|data| nav_str(data, &path!["sourceMessage"]))
+            .map(str::to_string),
+    })
+}
+
+/// Parse one `timedLyricsData` entry. Times and IDs may be sent as strings.
+fn parse_timed_line(line: &Value) -> Option<TimedLyricLine> {
+    let cue = line.get("cueRange")?;
+    let number = |value: Option<&Value>| match value? {
+        Value::String(text) => text.parse().ok(),
+        value => value.as_u64(),
+    };
+    Some(TimedLyricLine {
+        text: nav_str(line, &path!["lyricLine"])
+            .unwrap_or_default()
+            .to_string(),
+        start_ms: number(cue.get("startTimeMilliseconds"))?,
+        end_ms: number(cue.get("endTimeMilliseconds"))?,
+        id: number(nav(cue, &path!["metadata", "id"])).unwrap_or_default(),
+    })
+}
@@ -1,9 +1,27 @@
 //! Response parsers.
 
+pub mod account;
+pub mod album;
+pub mod artist;
+pub mod date;
+pub mod explore;
+pub mod history;
+pub mod home;
+pub mod library;
+pub mod locale;
+pub mod lyrics;
 pub mod navigation;
+pub mod number;
 pub mod playlist;
+pub mod search;
+pub mod song;
 pub mod track;
+pub mod upload;
+pub mod user;
+pub mod watch;
 
 pub use playlist::{
-    get_continuation_token, parse_library_playlists, parse_playlist_response, parse_playlist_tracks,
+    parse_library_playlists_continuation, parse_library_playlists_page, parse_playlist_response,
+    parse_playlist_track_rows,
 };
+pub use search::{parse_search_results, parse_search_rows, parse_search_suggestions};
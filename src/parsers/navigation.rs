@@ -64,6 +64,13 @@ pub mod paths {
         PathSegment::Key("thumbnails"),
     ];
 
+    pub const THUMBNAIL_RENDERER: &[PathSegment] = &[
+        PathSegment::Key("thumbnailRenderer"),
+        PathSegment::Key("musicThumbnailRenderer"),
+        PathSegment::Key("thumbnail"),
+        PathSegment::Key("thumbnails"),
+    ];
+
     pub const TITLE_TEXT: &[PathSegment] = &[
         PathSegment::Key("title"),
         PathSegment::Key("runs"),
@@ -80,6 +87,16 @@ pub mod paths {
         PathSegment::Key("browseId"),
     ];
 
+    /// Like button of a track row's menu.
+    pub const MENU_LIKE_STATUS: &[PathSegment] = &[
+        PathSegment::Key("menu"),
+        PathSegment::Key("menuRenderer"),
+        PathSegment::Key("topLevelButtons"),
+        PathSegment::Index(0),
+        PathSegment::Key("likeButtonRenderer"),
+        PathSegment::Key("likeStatus"),
+    ];
+
     pub const NAVIGATION_PLAYLIST_ID: &[PathSegment] = &[
         PathSegment::Key("navigationEndpoint"),
         PathSegment::Key("watchEndpoint"),
@@ -0,0 +1,126 @@
+//! Localized number parsing.
+
+/// Multipliers for abbreviated counts, matched case-insensitively against the
+/// word following the number (trailing periods ignored).
+const WORD_SUFFIXES: &[(&str, u64)] = &[
+    // en
+    ("k", 1_000),
+    ("m", 1_000_000),
+    ("b", 1_000_000_000),
+    // de
+    ("tsd", 1_000),
+    ("mio", 1_000_000),
+    ("mrd", 1_000_000_000),
+    // fr
+    ("md", 1_000_000_000),
+];
+
+/// Multipliers for CJK suffixes, which attach directly to the number.
+const CHAR_SUFFIXES: &[(char, u64)] = &[('千', 1_000), ('万', 10_000), ('億', 100_000_000)];
+
+/// Parse an abbreviated, possibly localized count such as `"1.2M views"`,
+/// `"1,2 Mio. Aufrufe"`, `"3,4 k vues"` or `"120万回視聴"`.
+///
+/// When a magnitude suffix is present, the last `.` or `,` is treated as the
+/// decimal separator. Without a suffix, all separators are treated as digit
+/// grouping, so `"1,234"` and `"1.234"` both parse as `1234`.
+pub fn parse_abbreviated_number(text: &str) -> Option<u64> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let rest = &text[start..];
+    let end = rest
+        .find(|c: char| !(c.is_ascii_digit() || is_separator(c)))
+        .unwrap_or(rest.len());
+    let number = rest[..end].trim_end_matches(is_separator);
+    let multiplier = suffix_multiplier(&rest[number.len()..]);
+
+    let (integer, fraction) = match multiplier {
+        Some(_) => match number.rfind(['.', ',']) {
+            Some(idx) => (&number[..idx], &number[idx + 1..]),
+            None => (number, ""),
+        },
+        None => (number, ""),
+    };
+
+    let integer: u64 = digits(integer).parse().ok()?;
+    let multiplier = multiplier.unwrap_or(1);
+    let fraction_digits = digits(fraction);
+    let fraction_value = if fraction_digits.is_empty() {
+        0
+    } else {
+        let scale = 10u64.checked_pow(fraction_digits.len() as u32)?;
+        fraction_digits
+            .parse::<u64>()
+            .ok()?
+            .checked_mul(multiplier)?
+            / scale
+    };
+
+    integer.checked_mul(multiplier)?.checked_add(fraction_value)
+}
+
+fn is_separator(c: char) -> bool {
+    matches!(c, '.' | ',' | '\'' | '\u{a0}' | '\u{202f}')
+}
+
+fn digits(text: &str) -> String {
+    text.chars().filter(char::is_ascii_digit).collect()
+}
+
+fn suffix_multiplier(text: &str) -> Option<u64> {
+    let text = text.trim_start();
+    let first = text.chars().next()?;
+    if let Some((_, multiplier)) = CHAR_SUFFIXES.iter().find(|(c, _)| *c == first) {
+        return Some(*multiplier);
+    }
+
+    let word = text
+        .split(|c: char| c.is_whitespace())
+        .next()?
+        .trim_end_matches('.')
+        .to_lowercase();
+    WORD_SUFFIXES
+        .iter()
+        .find(|(suffix, _)| *suffix == word)
+        .map(|(_, multiplier)| *multiplier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_abbreviated_number() {
+        let cases = [
+            // en
+            ("1.2M views", Some(1_200_000)),
+            ("3.4K", Some(3_400)),
+            ("2B views", Some(2_000_000_000)),
+            ("12,345 views", Some(12_345)),
+            ("987 likes", Some(987)),
+            // de
+            ("1,2 Mio. Aufrufe", Some(1_200_000)),
+            ("3,4 Tsd. Aufrufe", Some(3_400)),
+            ("1,5 Mrd. Aufrufe", Some(1_500_000_000)),
+            ("1.234 Aufrufe", Some(1_234)),
+            // fr
+            ("1,2\u{a0}M de vues", Some(1_200_000)),
+            ("3,4 k vues", Some(3_400)),
+            ("1,1 Md de vues", Some(1_100_000_000)),
+            // ja
+            ("120万 回視聴", Some(1_200_000)),
+            ("1.5万回視聴", Some(15_000)),
+            ("3億回視聴", Some(300_000_000)),
+            // no number
+            ("No views", None),
+            ("", None),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                parse_abbreviated_number(input),
+                expected,
+                "input: {input:?}"
+            );
+        }
+    }
+}
@@ -2,101 +2,312 @@
 
 use serde_json::Value;
 
+use crate::continuation::{continuation_rows, get_continuation_token};
 use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::date::parse_year_run;
+use crate::parsers::locale::{ENGLISH, Vocabulary, vocabulary};
 use crate::parsers::navigation::paths;
+use crate::parsers::number::parse_abbreviated_number;
 use crate::parsers::track::{
-    get_fixed_column_item, get_item_text, parse_duration, parse_song_album, parse_song_artists,
+    get_fixed_column_item, get_item_text, parse_added_at, parse_duration, parse_song_album,
+    parse_song_artists,
+};
+use crate::types::{
+    Author, CoverSource, DescriptionRun, LibraryPlaylistsPage, MusicVideoType, ParseWarning,
+    Playlist, PlaylistSummary, PlaylistTrack, Privacy, Thumbnail, sort_thumbnails,
 };
-use crate::types::{Author, Playlist, PlaylistSummary, PlaylistTrack, Privacy, Thumbnail};
 
-/// Parse library playlists from browse response.
-pub fn parse_library_playlists(response: &Value) -> Vec<PlaylistSummary> {
-    // Navigate to grid items
-    // Path: contents.singleColumnBrowseResultsRenderer.tabs[0].tabRenderer.content
-    //       .sectionListRenderer.contents[0].gridRenderer.items
-    let single_column = nav(response, paths::SINGLE_COLUMN);
-    let single_column = match single_column {
-        Some(v) => v,
-        None => return Vec::new(),
+/// Parse the first page of library playlists, including the continuation
+/// token and total count hint when present.
+pub fn parse_library_playlists_page(response: &Value) -> LibraryPlaylistsPage {
+    let grid = match find_library_grid(response) {
+        Some(grid) => grid,
+        None => return LibraryPlaylistsPage::default(),
     };
 
-    let tab_content = nav(single_column, paths::TAB_CONTENT);
-    let tab_content = match tab_content {
-        Some(v) => v,
-        None => return Vec::new(),
-    };
+    let items = grid
+        .get("items")
+        .and_then(|v| v.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let mut warnings = Vec::new();
+    LibraryPlaylistsPage {
+        playlists: parse_library_playlist_item_rows(items, &mut warnings),
+        total_hint: parse_library_total_hint(response, grid),
+        next_token: get_continuation_token(grid),
+        warnings,
+    }
+}
+
+/// Parse a library playlists continuation response.
+///
+/// Handles both the `continuationContents.gridContinuation` form and the
+/// `appendContinuationItemsAction` form.
+pub fn parse_library_playlists_continuation(response: &Value) -> LibraryPlaylistsPage {
+    let (items, next_token) = continuation_rows(response);
+    let mut warnings = Vec::new();
+    LibraryPlaylistsPage {
+        playlists: parse_library_playlist_item_rows(items, &mut warnings),
+        total_hint: None,
+        next_token,
+        warnings,
+    }
+}
 
-    let section_list = nav(tab_content, paths::SECTION_LIST);
-    let section_list = match section_list {
-        Some(Value::Array(arr)) => arr,
-        _ => return Vec::new(),
+/// Find the liked songs auto-playlist on the first library playlists page.
+///
+/// Its title is localized and its ID is not `LM` on every account (some
+/// brand accounts list an `LL…` playlist instead), so the tile is recognized
+/// by its thumbs-up icon, falling back to the `LM` ID.
+pub fn parse_liked_music_summary(response: &Value) -> Option<PlaylistSummary> {
+    let items = nav_array(find_library_grid(response)?, &path!["items"])?;
+    let tile = |wanted: fn(&Value, &PlaylistSummary) -> bool| {
+        items.iter().find_map(|item| {
+            parse_playlist_item(item).filter(|summary| wanted(&item[paths::MTRIR], summary))
+        })
     };
+    tile(|renderer, _| has_like_icon(renderer))
+        .or_else(|| tile(|_, summary| summary.playlist_id == "LM"))
+}
+
+/// Icon types the web client marks the liked songs tile with.
+const LIKE_ICONS: [&str; 2] = ["LIKE", "THUMB_UP"];
+
+/// Whether the artwork, subtitle or badges of a tile show a thumbs-up icon.
+/// Menus are not searched: their "Like" entries say nothing about the tile.
+fn has_like_icon(renderer: &Value) -> bool {
+    fn contains_icon(value: &Value) -> bool {
+        match value {
+            Value::Object(object) => object.iter().any(|(key, value)| {
+                (key == "iconType"
+                    && value
+                        .as_str()
+                        .is_some_and(|icon| LIKE_ICONS.contains(&icon)))
+                    || contains_icon(value)
+            }),
+            Value::Array(values) => values.iter().any(contains_icon),
+            _ => false,
+        }
+    }
+    [
+        "thumbnailRenderer",
+        "thumbnailOverlay",
+        "subtitle",
+        "subtitleBadges",
+    ]
+    .into_iter()
+    .filter_map(|key| renderer.get(key))
+    .any(contains_icon)
+}
+
+/// Find the grid renderer holding library playlists.
+pub fn find_library_grid(response: &Value) -> Option<&Value> {
+    // Path: contents.singleColumnBrowseResultsRenderer.tabs[0].tabRenderer.content
+    //       .sectionListRenderer.contents[0].gridRenderer
+    let single_column = nav(response, paths::SINGLE_COLUMN)?;
+    let tab_content = nav(single_column, paths::TAB_CONTENT)?;
+    let section_list = nav_array(tab_content, paths::SECTION_LIST)?;
 
     // Find the grid in section list
     // The structure can be:
-    // 1. gridRenderer -> items (direct)
-    // 2. itemSectionRenderer -> contents[0] -> gridRenderer -> items (wrapper)
-    let grid_items = section_list.iter().find_map(|item| {
-        // Option 1: Direct gridRenderer
-        if let Some(grid) = item.get("gridRenderer") {
-            return nav(grid, &path!["items"])?.as_array();
-        }
+    // 1. gridRenderer (direct)
+    // 2. itemSectionRenderer -> contents[0] -> gridRenderer (wrapper)
+    section_list.iter().find_map(|item| {
+        item.get("gridRenderer").or_else(|| {
+            nav(
+                item,
+                &path!["itemSectionRenderer", "contents", 0, "gridRenderer"],
+            )
+        })
+    })
+}
 
-        // Option 2: Nested in itemSectionRenderer
-        if let Some(item_section) = item.get("itemSectionRenderer")
-            && let Some(grid_items) =
-                nav(item_section, &path!["contents", 0, "gridRenderer", "items"])
-        {
-            return grid_items.as_array();
-        }
+/// Extract the total playlist count shown in the library header, if any.
+fn parse_library_total_hint(response: &Value, grid: &Value) -> Option<u32> {
+    let candidates = [
+        nav_array(
+            grid,
+            &path!["header", "gridHeaderRenderer", "title", "runs"],
+        ),
+        nav_array(
+            response,
+            &path!["header", "musicHeaderRenderer", "subtitle", "runs"],
+        ),
+    ];
 
-        None
-    });
+    candidates
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|run| run.get("text").and_then(|v| v.as_str()))
+        .find_map(parse_abbreviated_number)
+        .and_then(|count| u32::try_from(count).ok())
+}
 
-    let items = match grid_items {
-        Some(arr) => arr,
-        None => return Vec::new(),
-    };
+/// Parse library grid items, skipping pseudo-items.
+///
+/// Pseudo-items such as the "New playlist" tile are detected structurally
+/// (they carry a create-playlist command or no playlist ID) rather than by
+/// position, so this works for first pages and continuation pages alike.
+pub fn parse_library_playlist_items(items: &[Value]) -> Vec<PlaylistSummary> {
+    parse_library_playlist_item_rows(items, &mut Vec::new())
+}
+
+/// Like [`parse_library_playlist_items`], recording items that are neither
+/// playlists nor known pseudo-items in `warnings`.
+pub fn parse_library_playlist_item_rows(
+    items: &[Value],
+    warnings: &mut Vec<ParseWarning>,
+) -> Vec<PlaylistSummary> {
+    let mut playlists = Vec::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+        if let Some(playlist) = parse_playlist_item(item) {
+            playlists.push(playlist);
+        } else if item.get(paths::MTRIR).is_none() {
+            warnings.extend(unknown_row(index, item));
+        }
+    }
+    playlists
+}
 
-    items.iter().filter_map(parse_playlist_item).collect()
+/// A warning for a row rendered by something other than the expected
+/// renderer, unless it is a continuation marker.
+fn unknown_row(index: usize, row: &Value) -> Option<ParseWarning> {
+    let renderer = row
+        .as_object()
+        .and_then(|row| row.keys().next())
+        .map_or("", String::as_str);
+    (renderer != "continuationItemRenderer")
+        .then(|| ParseWarning::new(index, renderer, "unknown row renderer"))
 }
 
 /// Parse a single playlist item from library listing.
-fn parse_playlist_item(item: &Value) -> Option<PlaylistSummary> {
+pub fn parse_playlist_item(item: &Value) -> Option<PlaylistSummary> {
     let renderer = item.get(paths::MTRIR)?;
 
-    let title = nav_str(renderer, paths::TITLE_TEXT)?.to_string();
+    if nav(
+        renderer,
+        &path!["navigationEndpoint", "createPlaylistEndpoint"],
+    )
+    .is_some()
+    {
+        return None;
+    }
 
+    // The playlist ID is the only required field; everything else defaults.
     let playlist_id = nav_str(renderer, paths::NAVIGATION_PLAYLIST_ID)
         .or_else(|| nav_str(renderer, paths::NAVIGATION_BROWSE_ID))
-        .map(|s| s.trim_start_matches("VL").to_string())?;
+        .or_else(|| {
+            nav_str(
+                renderer,
+                &path!["navigationEndpoint", "watchPlaylistEndpoint", "playlistId"],
+            )
+        })
+        .or_else(|| {
+            nav_str(
+                renderer,
+                &path![
+                    "thumbnailOverlay",
+                    "musicItemThumbnailOverlayRenderer",
+                    "content",
+                    "musicPlayButtonRenderer",
+                    "playNavigationEndpoint",
+                    "watchPlaylistEndpoint",
+                    "playlistId"
+                ],
+            )
+        })
+        .map(|s| s.trim_start_matches("VL").trim())
+        .filter(|s| !s.is_empty())?
+        .to_string();
+
+    // Some auto-generated entries only carry an accessibility label for the title
+    let title = nav_str(renderer, paths::TITLE_TEXT)
+        .or_else(|| {
+            nav_str(
+                renderer,
+                &path![
+                    "title",
+                    "runs",
+                    0,
+                    "accessibility",
+                    "accessibilityData",
+                    "label"
+                ],
+            )
+        })
+        .or_else(|| {
+            nav_str(
+                renderer,
+                &path!["title", "accessibility", "accessibilityData", "label"],
+            )
+        })
+        .unwrap_or_default()
+        .to_string();
 
     let thumbnails = parse_thumbnails(renderer);
 
-    // Count is in subtitle
-    let count = nav_str(renderer, &path!["subtitle", "runs", 0, "text"]).and_then(|s| {
-        // Parse "123 songs" or similar
-        s.split_whitespace().next()?.parse().ok()
-    });
+    // Count is in the subtitle, e.g. "123 songs" or "Playlist • 1,024 songs"
+    let count = nav_array(renderer, &path!["subtitle", "runs"])
+        .into_iter()
+        .flatten()
+        .filter_map(|run| nav_str(run, &path!["text"]))
+        .find_map(|text| {
+            let mut words = text.split_whitespace();
+            let count = words.next()?.replace([',', '.'], "").parse().ok()?;
+            words.next().map(|_| count)
+        });
+
+    let author = nav_array(renderer, &path!["subtitle", "runs"])
+        .into_iter()
+        .flatten()
+        .find_map(|run| {
+            let id = nav_str(
+                run,
+                &path!["navigationEndpoint", "browseEndpoint", "browseId"],
+            )?;
+            id.starts_with("UC").then(|| Author {
+                name: nav_str(run, &path!["text"]).unwrap_or_default().to_string(),
+                id: Some(id.to_string()),
+            })
+        });
 
     Some(PlaylistSummary {
         playlist_id,
         title,
         thumbnails,
         count,
+        author,
     })
 }
 
-/// Parse thumbnails from a renderer.
+/// Parse thumbnails from a renderer (list items, two-row items and headers).
 pub fn parse_thumbnails(data: &Value) -> Vec<Thumbnail> {
-    let thumbs = nav_array(data, paths::THUMBNAILS).or_else(|| nav_array(data, paths::THUMBNAIL));
-
-    let thumbs = match thumbs {
-        Some(arr) => arr,
-        None => return Vec::new(),
-    };
+    let thumbs = nav_array(data, paths::THUMBNAILS)
+        .or_else(|| nav_array(data, paths::THUMBNAIL_RENDERER))
+        .or_else(|| nav_array(data, paths::THUMBNAIL))
+        .or_else(|| {
+            nav_array(
+                data,
+                &path![
+                    "foregroundThumbnail",
+                    "musicThumbnailRenderer",
+                    "thumbnail",
+                    "thumbnails"
+                ],
+            )
+        });
 
     thumbs
+        .map(|arr| parse_thumbnail_list(arr))
+        .unwrap_or_default()
+}
+
+/// Parse a bare `thumbnails` array, deduplicated and sorted as described on
+/// [`Thumbnails`](crate::Thumbnails).
+pub fn parse_thumbnail_list(thumbs: &[Value]) -> Vec<Thumbnail> {
+    let thumbnails = thumbs
         .iter()
         .filter_map(|t| {
             let url = t.get("url")?.as_str()?.to_string();
@@ -104,33 +315,267 @@ pub fn parse_thumbnails(data: &Value) -> Vec<Thumbnail> {
             let height = t.get("height").and_then(|v| v.as_u64()).map(|v| v as u32);
             Some(Thumbnail { url, width, height })
         })
+        .collect();
+    sort_thumbnails(thumbnails)
+}
+
+/// Parse description runs, keeping link targets.
+pub fn parse_description_runs(runs: &[Value]) -> Vec<DescriptionRun> {
+    runs.iter()
+        .filter_map(|run| {
+            let text = nav_str(run, &path!["text"])?.to_string();
+            let url = nav_str(run, &path!["navigationEndpoint", "urlEndpoint", "url"])
+                .map(str::to_string)
+                .or_else(|| {
+                    nav_str(run, paths::NAVIGATION_BROWSE_ID)
+                        .map(|id| format!("https://music.youtube.com/browse/{id}"))
+                });
+            Some(DescriptionRun { text, url })
+        })
         .collect()
 }
 
+/// Classify a playlist cover from its header thumbnails.
+///
+/// Auto-generated covers are served from track artwork (`i.ytimg.com/vi/<videoId>/...`),
+/// while uploaded covers are hosted elsewhere. For collages, one URL is kept per
+/// contributing track (the largest size offered), in header order.
+pub fn parse_cover_source(thumbnails: &[Thumbnail]) -> Option<CoverSource> {
+    if thumbnails.is_empty() {
+        return None;
+    }
+
+    let mut components: Vec<(&str, &Thumbnail)> = Vec::new();
+    for thumbnail in thumbnails {
+        let video_id = match track_artwork_video_id(&thumbnail.url) {
+            Some(id) => id,
+            None => return Some(CoverSource::Custom),
+        };
+
+        match components.iter_mut().find(|(id, _)| *id == video_id) {
+            Some(existing) => {
+                if thumbnail.width.unwrap_or(0) > existing.1.width.unwrap_or(0) {
+                    existing.1 = thumbnail;
+                }
+            }
+            None => components.push((video_id, thumbnail)),
+        }
+    }
+
+    Some(CoverSource::AutoCollage {
+        components: components
+            .into_iter()
+            .map(|(_, thumbnail)| thumbnail.url.clone())
+            .collect(),
+    })
+}
+
+/// Extract the video ID from a track artwork URL, if the URL points at one.
+fn track_artwork_video_id(url: &str) -> Option<&str> {
+    let rest = url
+        .split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .strip_prefix("i.ytimg.com/vi/")?;
+    let video_id = rest.split('/').next()?;
+    (!video_id.is_empty()).then_some(video_id)
+}
+
 /// Parse full playlist response.
-pub fn parse_playlist_response(response: &Value, playlist_id: &str) -> Playlist {
+///
+/// `playlist_id` is used as [`Playlist::id`] unless the track shelf or the
+/// header's play button names the playlist. Header labels are read in
+/// `language`, the language the response was requested in. Returns the
+/// playlist along with the continuation token of the track shelf, if the
+/// first page indicated that more tracks are available.
+pub fn parse_playlist_response(
+    response: &Value,
+    playlist_id: &str,
+    language: &str,
+) -> (Playlist, Option<String>) {
     let mut playlist = Playlist {
         id: playlist_id.trim_start_matches("VL").to_string(),
         ..Default::default()
     };
 
+    let words = vocabulary(language);
+    parse_playlist_header(response, &mut playlist, words);
+
+    let mut continuation = None;
+    if let Some(shelf) = find_playlist_shelf(response) {
+        if let Some(id) = nav_str(shelf, &path!["playlistId"]) {
+            playlist.id = id.trim_start_matches("VL").to_string();
+        }
+        if let Some(Value::Array(contents)) = shelf.get("contents") {
+            playlist.tracks = parse_playlist_track_rows(contents, words, &mut playlist.warnings);
+        }
+        continuation = get_continuation_token(shelf);
+    }
+
+    playlist.related = parse_related_playlists(response).unwrap_or_default();
+
+    // Calculate total duration
+    playlist.duration_seconds = Some(
+        playlist
+            .tracks
+            .iter()
+            .filter_map(|t| t.duration_seconds)
+            .map(u64::from)
+            .sum(),
+    );
+
+    (playlist, continuation)
+}
+
+/// Locate the shelf holding the playlist tracks.
+///
+/// The shelf normally lives in the two-column layout's `secondaryContents`, but
+/// some layouts place it in the primary tab content instead.
+fn find_playlist_shelf(response: &Value) -> Option<&Value> {
+    let two_col = nav(response, paths::TWO_COLUMN_RENDERER);
+    let candidates = [
+        two_col.and_then(|v| {
+            nav(
+                v,
+                &path!["secondaryContents", "sectionListRenderer", "contents"],
+            )
+        }),
+        two_col
+            .and_then(|v| nav(v, paths::TAB_CONTENT))
+            .and_then(|v| nav(v, paths::SECTION_LIST)),
+        nav(response, paths::SINGLE_COLUMN)
+            .and_then(|v| nav(v, paths::TAB_CONTENT))
+            .and_then(|v| nav(v, paths::SECTION_LIST)),
+    ];
+
+    let items = || {
+        candidates
+            .into_iter()
+            .flatten()
+            .filter_map(|v| v.as_array())
+            .flatten()
+    };
+
+    items()
+        .find_map(|item| item.get("musicPlaylistShelfRenderer"))
+        .or_else(|| items().find_map(|item| item.get("musicShelfRenderer")))
+}
+
+/// Token of the section list continuation that loads the sections below the
+/// track shelf of a playlist page, such as the suggestions of an owned
+/// playlist. Accepts the playlist page or a section list continuation, which
+/// may point to further sections.
+pub fn playlist_sections_continuation(response: &Value) -> Option<String> {
+    let section_list = nav(response, paths::TWO_COLUMN_RENDERER)
+        .and_then(|two_col| nav(two_col, &path!["secondaryContents", "sectionListRenderer"]))
+        .or_else(|| {
+            nav(
+                response,
+                &path!["continuationContents", "sectionListContinuation"],
+            )
+        })?;
+    nav_str(
+        section_list,
+        &path!["continuations", 0, "nextContinuationData", "continuation"],
+    )
+    .map(str::to_string)
+}
+
+/// The sections below the track shelf, from a playlist page or a section
+/// list continuation.
+fn sections_below_tracks(response: &Value) -> Option<&[Value]> {
+    nav_array(
+        response,
+        &path![
+            "continuationContents",
+            "sectionListContinuation",
+            "contents"
+        ],
+    )
+    .or_else(|| {
+        // On the playlist page itself the track shelf comes first.
+        nav_array(
+            nav(response, paths::TWO_COLUMN_RENDERER)?,
+            &path!["secondaryContents", "sectionListRenderer", "contents"],
+        )
+        .filter(|sections| {
+            sections
+                .iter()
+                .any(|section| section.get("musicPlaylistShelfRenderer").is_some())
+        })
+    })
+    .map(Vec::as_slice)
+}
+
+/// Whether a shelf's first item is rendered by `renderer`.
+fn shelf_holds(shelf: &Value, renderer: &str) -> bool {
+    nav(shelf, &path!["contents", 0]).is_some_and(|item| item.get(renderer).is_some())
+}
+
+/// Parse the "Suggestions" shelf of an owned playlist.
+///
+/// Accepts a playlist page holding the shelf below the track shelf, the
+/// section list continuation that loads it, or a refresh of the shelf.
+/// Returns the suggested tracks, which have no `set_video_id`, and the token
+/// that loads a fresh batch. Returns `None` if the response has no
+/// suggestions shelf.
+pub fn parse_playlist_suggestions(
+    response: &Value,
+) -> Option<(Vec<PlaylistTrack>, Option<String>)> {
+    let shelf = nav(
+        response,
+        &path!["continuationContents", "musicShelfContinuation"],
+    )
+    .or_else(|| {
+        sections_below_tracks(response)?.iter().find_map(|section| {
+            section.get("musicShelfRenderer").or_else(|| {
+                section
+                    .get("musicCarouselShelfRenderer")
+                    .filter(|shelf| shelf_holds(shelf, paths::MRLIR))
+            })
+        })
+    })?;
+
+    let tracks = nav_array(shelf, &path!["contents"])
+        .map(|rows| parse_playlist_tracks(rows))
+        .unwrap_or_default();
+    let reload = nav_str(
+        shelf,
+        &path!["continuations", 0, "reloadContinuationData", "continuation"],
+    )
+    .map(str::to_string);
+    Some((tracks, reload))
+}
+
+/// Parse the "Related playlists" carousel from a playlist page or a section
+/// list continuation. Returns `None` if the response has no such carousel.
+pub fn parse_related_playlists(response: &Value) -> Option<Vec<PlaylistSummary>> {
+    sections_below_tracks(response)?
+        .iter()
+        .filter_map(|section| section.get("musicCarouselShelfRenderer"))
+        .find(|shelf| shelf_holds(shelf, paths::MTRIR))
+        .and_then(|shelf| nav_array(shelf, &path!["contents"]))
+        .map(|items| parse_library_playlist_items(items))
+}
+
+/// Parse playlist header metadata (title, privacy, author, ...).
+fn parse_playlist_header(response: &Value, playlist: &mut Playlist, words: &Vocabulary) {
     // Determine if owned playlist
     let two_col = nav(response, paths::TWO_COLUMN_RENDERER);
     let two_col = match two_col {
         Some(v) => v,
-        None => return playlist,
+        None => return,
     };
 
     let tab_content = nav(two_col, paths::TAB_CONTENT);
     let tab_content = match tab_content {
         Some(v) => v,
-        None => return playlist,
+        None => return,
     };
 
     let section_list_item = nav(tab_content, &path!["sectionListRenderer", "contents", 0]);
     let section_list_item = match section_list_item {
         Some(v) => v,
-        None => return playlist,
+        None => return,
     };
 
     // Check if editable (owned) playlist
@@ -158,20 +603,27 @@ pub fn parse_playlist_response(response: &Value, playlist_id: &str) -> Playlist
 
         // Thumbnails
         playlist.thumbnails = parse_thumbnails(header);
+        playlist.cover_source = parse_cover_source(&playlist.thumbnails);
 
         // Description
-        playlist.description = nav_str(
+        if let Some(runs) = nav_array(
             header,
             &path![
                 "description",
                 "musicDescriptionShelfRenderer",
                 "description",
-                "runs",
-                0,
-                "text"
+                "runs"
             ],
-        )
-        .map(|s| s.to_string());
+        ) {
+            playlist.description_runs = parse_description_runs(runs);
+            playlist.description = Some(
+                playlist
+                    .description_runs
+                    .iter()
+                    .map(|run| run.text.as_str())
+                    .collect(),
+            );
+        }
 
         // Author from facepile or subtitle
         if let Some(author_name) = nav_str(
@@ -197,66 +649,113 @@ pub fn parse_playlist_response(response: &Value, playlist_id: &str) -> Playlist
             });
         }
 
+        if let Some(id) = header_play_playlist_id(header) {
+            playlist.id = id.trim_start_matches("VL").to_string();
+        }
+
+        // Year from the subtitle, e.g. "Playlist • 2024"
+        playlist.year =
+            nav_array(header, &path!["subtitle", "runs"]).and_then(|runs| parse_year_run(runs));
+
         // Parse second subtitle for metadata
         if let Some(second_subtitle) = nav(header, &path!["secondSubtitle", "runs"])
             && let Some(runs) = second_subtitle.as_array()
         {
-            parse_playlist_meta_from_runs(runs, &mut playlist);
-        }
-    }
-
-    // Parse tracks from secondary contents
-    let secondary = nav(
-        two_col,
-        &path!["secondaryContents", "sectionListRenderer", "contents", 0],
-    );
-    if let Some(secondary) = secondary {
-        let shelf = nav(secondary, &path!["musicPlaylistShelfRenderer", "contents"]);
-        if let Some(Value::Array(contents)) = shelf {
-            playlist.tracks = parse_playlist_tracks(contents);
+            parse_playlist_meta_from_runs(runs, playlist, words);
         }
     }
+}
 
-    // Calculate total duration
-    playlist.duration_seconds = Some(
-        playlist
-            .tracks
-            .iter()
-            .filter_map(|t| t.duration_seconds)
-            .sum(),
-    );
-
-    playlist
+/// Playlist started by a responsive header's play button.
+pub fn header_play_playlist_id(header: &Value) -> Option<&str> {
+    nav_array(header, &path!["buttons"])
+        .into_iter()
+        .flatten()
+        .find_map(|button| {
+            nav_str(
+                button,
+                &path![
+                    "musicPlayButtonRenderer",
+                    "playNavigationEndpoint",
+                    "watchEndpoint",
+                    "playlistId"
+                ],
+            )
+        })
 }
 
 /// Parse metadata from second subtitle runs.
-fn parse_playlist_meta_from_runs(runs: &[Value], playlist: &mut Playlist) {
+fn parse_playlist_meta_from_runs(runs: &[Value], playlist: &mut Playlist, words: &Vocabulary) {
     // Format varies: could be "123 songs", "X songs • Y hours", "X views • Y songs • Z hours"
     for run in runs {
         if let Some(text) = run.get("text").and_then(|v| v.as_str()) {
-            let text_lower = text.to_lowercase();
-
-            if text_lower.contains("song") || text_lower.contains("track") {
-                // Extract track count
-                if let Some(count_str) = text.split_whitespace().next()
-                    && let Ok(count) = count_str.replace(',', "").parse::<u32>()
-                {
+            if Vocabulary::matches(text, words.songs) {
+                if let Some(count) = parse_track_count(text) {
                     playlist.track_count = Some(count);
                 }
-            } else if text_lower.contains("hour") || text_lower.contains("minute") {
+            } else if Vocabulary::matches(text, words.duration) {
                 playlist.duration = Some(text.to_string());
+            } else if Vocabulary::matches(text, words.views) {
+                playlist.views = parse_abbreviated_number(text);
+            } else if Vocabulary::matches(text, words.likes) {
+                playlist.likes = parse_abbreviated_number(text);
             }
         }
     }
 }
 
-/// Parse playlist tracks from contents array.
+/// Parse a count label such as `"1,024 songs"` or `"1.024 Titel"`.
+pub fn parse_track_count(text: &str) -> Option<u32> {
+    parse_abbreviated_number(text.split_whitespace().next()?)?
+        .try_into()
+        .ok()
+}
+
+/// Parse playlist tracks from contents array, reading English labels.
 pub fn parse_playlist_tracks(contents: &[Value]) -> Vec<PlaylistTrack> {
-    contents.iter().filter_map(parse_playlist_track).collect()
+    parse_playlist_track_rows(contents, &ENGLISH, &mut Vec::new())
+}
+
+/// Like [`parse_playlist_tracks`], reading "Added" labels in the language of
+/// `words` and recording rows that had to be dropped in `warnings`. Deleted
+/// songs and continuation markers are dropped silently.
+pub fn parse_playlist_track_rows(
+    contents: &[Value],
+    words: &Vocabulary,
+    warnings: &mut Vec<ParseWarning>,
+) -> Vec<PlaylistTrack> {
+    let mut tracks = Vec::with_capacity(contents.len());
+    for (index, row) in contents.iter().enumerate() {
+        if let Some(track) = parse_playlist_track_in(row, words) {
+            tracks.push(track);
+            continue;
+        }
+        match row.get(paths::MRLIR) {
+            None => warnings.extend(unknown_row(index, row)),
+            Some(data) if get_item_text(data, 0) == Some("Song deleted") => {}
+            Some(_) => warnings.push(ParseWarning::new(
+                index,
+                paths::MRLIR,
+                "row has no video ID, playlist item ID or title",
+            )),
+        }
+    }
+    tracks
 }
 
 /// Parse a single playlist track.
+///
+/// The flex columns only enrich the track: a row without them still yields a
+/// track if it carries a video ID, taking its title from the accessibility
+/// label. Rows with no video ID, playlist item ID or title are dropped.
+/// "Added" labels are read in English.
 pub fn parse_playlist_track(item: &Value) -> Option<PlaylistTrack> {
+    parse_playlist_track_in(item, &ENGLISH)
+}
+
+/// Like [`parse_playlist_track`], reading "Added" labels in the language of
+/// `words`.
+pub fn parse_playlist_track_in(item: &Value, words: &Vocabulary) -> Option<PlaylistTrack> {
     let data = item.get(paths::MRLIR)?;
 
     let mut track = PlaylistTrack {
@@ -301,37 +800,67 @@ pub fn parse_playlist_track(item: &Value) -> Option<PlaylistTrack> {
             }
         }
     }
+    if track.video_id.is_none() {
+        track.video_id = nav_str(data, &path!["playlistItemData", "videoId"]).map(str::to_string);
+    }
 
-    // Determine flex column indexes by analyzing content
-    let flex_columns = data.get("flexColumns")?.as_array()?;
+    let flex_columns = data
+        .get("flexColumns")
+        .and_then(Value::as_array)
+        .map_or(0, Vec::len);
 
-    // Title is usually first column
-    track.title = get_item_text(data, 0).map(|s| s.to_string());
+    // Title is usually first column; some experimental layouts only label
+    // the row for screen readers
+    track.title = get_item_text(data, 0)
+        .or_else(|| nav_str(data, &path!["accessibility", "accessibilityData", "label"]))
+        .map(|s| s.to_string());
 
-    // Skip deleted songs
-    if track.title.as_deref() == Some("Song deleted") {
+    // Skip deleted songs and rows without anything to identify them by
+    if track.title.as_deref() == Some("Song deleted")
+        || (track.title.is_none() && track.video_id.is_none() && track.set_video_id.is_none())
+    {
         return None;
     }
 
     // Artists usually second column
     track.artists = parse_song_artists(data, 1);
 
-    // Try to find album (usually third column, but could vary)
-    for i in 2..flex_columns.len() {
-        if let Some(album) = parse_song_album(data, i) {
+    // Try to find album (usually third column, but could vary); an "Added ..."
+    // column is parsed as the added date instead.
+    for i in 2..flex_columns {
+        if let Some(added_at) = get_item_text(data, i).and_then(|text| parse_added_at(text, words))
+        {
+            track.added_at = Some(added_at);
+            continue;
+        }
+        if track.album.is_none()
+            && let Some(album) = parse_song_album(data, i)
+        {
             track.album = Some(album);
-            break;
         }
     }
 
-    // Duration from fixed columns if available
-    if let Some(fixed) = get_fixed_column_item(data, 0) {
-        let duration = nav_str(fixed, &path!["text", "simpleText"])
+    // Duration from fixed columns if available; later fixed columns may carry
+    // the added date
+    let fixed_columns = data
+        .get("fixedColumns")
+        .and_then(|v| v.as_array())
+        .map_or(0, Vec::len);
+    for i in 0..fixed_columns {
+        let Some(fixed) = get_fixed_column_item(data, i) else {
+            continue;
+        };
+        let text = nav_str(fixed, &path!["text", "simpleText"])
             .or_else(|| nav_str(fixed, &path!["text", "runs", 0, "text"]));
+        let Some(text) = text else {
+            continue;
+        };
 
-        if let Some(dur) = duration {
-            track.duration = Some(dur.to_string());
-            track.duration_seconds = parse_duration(dur);
+        if let Some(added_at) = parse_added_at(text, words) {
+            track.added_at = Some(added_at);
+        } else if track.duration.is_none() {
+            track.duration = Some(text.to_string());
+            track.duration_seconds = parse_duration(text);
         }
     }
 
@@ -365,21 +894,15 @@ pub fn parse_playlist_track(item: &Value) -> Option<PlaylistTrack> {
             "musicVideoType"
         ],
     )
-    .map(|s| s.to_string());
+    .map(MusicVideoType::from);
 
     Some(track)
 }
 
-/// Get continuation token from results.
-pub fn get_continuation_token(results: &Value) -> Option<String> {
-    let contents = results.get("contents")?.as_array()?;
-    let last = contents.last()?;
-    nav_str(last, paths::CONTINUATION_TOKEN).map(|s| s.to_string())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::AddedAt;
     use serde_json::json;
 
     fn library_response(items: serde_json::Value) -> serde_json::Value {
@@ -436,6 +959,408 @@ mod tests {
         assert_eq!(thumbs[0].width, Some(100));
     }
 
+    fn owned_playlist_response(header_thumbnails: serde_json::Value) -> serde_json::Value {
+        json!({
+            "contents": {
+                "twoColumnBrowseResultsRenderer": {
+                    "tabs": [{
+                        "tabRenderer": {
+                            "content": {
+                                "sectionListRenderer": {
+                                    "contents": [{
+                                        "musicEditablePlaylistDetailHeaderRenderer": {
+                                            "editHeader": {
+                                                "musicPlaylistEditHeaderRenderer": {
+                                                    "privacy": "PRIVATE"
+                                                }
+                                            },
+                                            "header": {
+                                                "musicResponsiveHeaderRenderer": {
+                                                    "title": { "runs": [{ "text": "Mine" }] },
+                                                    "subtitle": {
+                                                        "runs": [
+                                                            { "text": "Playlist" },
+                                                            { "text": " • " },
+                                                            { "text": "2024" }
+                                                        ]
+                                                    },
+                                                    "thumbnail": {
+                                                        "musicThumbnailRenderer": {
+                                                            "thumbnail": {
+                                                                "thumbnails": header_thumbnails
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }]
+                                }
+                            }
+                        }
+                    }]
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_parse_playlist_description_runs() {
+        let mut response = owned_playlist_response(json!([]));
+        response["contents"]["twoColumnBrowseResultsRenderer"]["tabs"][0]["tabRenderer"]["content"]
+            ["sectionListRenderer"]["contents"][0]["musicEditablePlaylistDetailHeaderRenderer"]["header"]
+            ["musicResponsiveHeaderRenderer"]["description"] = json!({
+            "musicDescriptionShelfRenderer": {
+                "description": {
+                    "runs": [
+                        { "text": "Mixed by " },
+                        {
+                            "text": "Some Artist",
+                            "navigationEndpoint": { "browseEndpoint": { "browseId": "UCartist" } }
+                        },
+                        { "text": ". More at " },
+                        {
+                            "text": "example.com/mix",
+                            "navigationEndpoint": {
+                                "urlEndpoint": { "url": "https://example.com/mix" }
+                            }
+                        }
+                    ]
+                }
+            }
+        });
+
+        let (playlist, _) = parse_playlist_response(&response, "PL1", "en");
+        assert_eq!(
+            playlist.description.as_deref(),
+            Some("Mixed by Some Artist. More at example.com/mix")
+        );
+        assert_eq!(playlist.description_runs.len(), 4);
+        assert_eq!(playlist.description_runs[0].url, None);
+        assert_eq!(
+            playlist.description_runs[1].url.as_deref(),
+            Some("https://music.youtube.com/browse/UCartist")
+        );
+        assert_eq!(
+            playlist.description_runs[3],
+            DescriptionRun {
+                text: "example.com/mix".to_string(),
+                url: Some("https://example.com/mix".to_string()),
+            }
+        );
+    }
+
+    fn thumbnail(url: &str, width: u32) -> Thumbnail {
+        Thumbnail {
+            url: url.to_string(),
+            width: Some(width),
+            height: Some(width),
+        }
+    }
+
+    #[test]
+    fn test_parse_cover_source_custom() {
+        let thumbs = vec![
+            thumbnail("https://yt3.ggpht.com/abc=s192", 192),
+            thumbnail("https://yt3.ggpht.com/abc=s576", 576),
+        ];
+        assert_eq!(parse_cover_source(&thumbs), Some(CoverSource::Custom));
+    }
+
+    #[test]
+    fn test_parse_cover_source_auto_collage() {
+        let thumbs = vec![
+            thumbnail("https://i.ytimg.com/vi/vid1/sddefault.jpg", 640),
+            thumbnail("https://i.ytimg.com/vi/vid1/hqdefault.jpg", 480),
+            thumbnail("https://i.ytimg.com/vi/vid2/hqdefault.jpg", 480),
+            thumbnail("https://i.ytimg.com/vi/vid3/hqdefault.jpg", 480),
+            thumbnail("https://i.ytimg.com/vi/vid4/hqdefault.jpg", 480),
+        ];
+        assert_eq!(
+            parse_cover_source(&thumbs),
+            Some(CoverSource::AutoCollage {
+                components: vec![
+                    "https://i.ytimg.com/vi/vid1/sddefault.jpg".to_string(),
+                    "https://i.ytimg.com/vi/vid2/hqdefault.jpg".to_string(),
+                    "https://i.ytimg.com/vi/vid3/hqdefault.jpg".to_string(),
+                    "https://i.ytimg.com/vi/vid4/hqdefault.jpg".to_string(),
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_playlist_response_cover_source() {
+        let custom = owned_playlist_response(json!([
+            {"url": "https://yt3.ggpht.com/cover=s576", "width": 576, "height": 576}
+        ]));
+        let (playlist, _) = parse_playlist_response(&custom, "PL1", "en");
+        assert!(playlist.owned);
+        assert_eq!(playlist.year, Some(2024));
+        assert_eq!(playlist.cover_source, Some(CoverSource::Custom));
+
+        let collage = owned_playlist_response(json!([
+            {"url": "https://i.ytimg.com/vi/a/hqdefault.jpg", "width": 480, "height": 360},
+            {"url": "https://i.ytimg.com/vi/b/hqdefault.jpg", "width": 480, "height": 360}
+        ]));
+        let (playlist, _) = parse_playlist_response(&collage, "PL1", "en");
+        assert!(matches!(
+            playlist.cover_source,
+            Some(CoverSource::AutoCollage { ref components }) if components.len() == 2
+        ));
+    }
+
+    fn track_row(video_id: &str) -> serde_json::Value {
+        json!({
+            "musicResponsiveListItemRenderer": {
+                "overlay": {
+                    "musicItemThumbnailOverlayRenderer": {
+                        "content": {
+                            "musicPlayButtonRenderer": {
+                                "playNavigationEndpoint": {
+                                    "watchEndpoint": { "videoId": video_id }
+                                }
+                            }
+                        }
+                    }
+                },
+                "flexColumns": [{
+                    "musicResponsiveListItemFlexColumnRenderer": {
+                        "text": { "runs": [{ "text": video_id }] }
+                    }
+                }]
+            }
+        })
+    }
+
+    fn continuation_row(token: &str) -> serde_json::Value {
+        json!({
+            "continuationItemRenderer": {
+                "continuationEndpoint": {
+                    "continuationCommand": { "token": token }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_parse_playlist_track_video_type() {
+        let mut row = track_row("a");
+        row["musicResponsiveListItemRenderer"]["menu"] = json!({
+            "menuRenderer": {
+                "items": [{
+                    "menuNavigationItemRenderer": {
+                        "navigationEndpoint": {
+                            "watchEndpoint": {
+                                "watchEndpointMusicSupportedConfigs": {
+                                    "watchEndpointMusicConfig": {
+                                        "musicVideoType": "MUSIC_VIDEO_TYPE_ATV"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }]
+            }
+        });
+
+        let track = parse_playlist_track(&row).unwrap();
+        assert_eq!(track.video_type, Some(MusicVideoType::Atv));
+    }
+
+    #[test]
+    fn test_playlist_continuation_rows_shapes() {
+        let shelf = json!({
+            "continuationContents": {
+                "musicPlaylistShelfContinuation": {
+                    "contents": [track_row("a"), continuation_row("next")]
+                }
+            }
+        });
+        let (rows, token) = continuation_rows(&shelf);
+        assert_eq!(parse_playlist_tracks(rows).len(), 1);
+        assert_eq!(token.as_deref(), Some("next"));
+
+        let action = json!({
+            "onResponseReceivedActions": [{
+                "appendContinuationItemsAction": {
+                    "continuationItems": [track_row("b"), track_row("c")]
+                }
+            }]
+        });
+        let (rows, token) = continuation_rows(&action);
+        assert_eq!(parse_playlist_tracks(rows).len(), 2);
+        assert_eq!(token, None);
+
+        assert!(continuation_rows(&json!({})).0.is_empty());
+    }
+
+    fn text_column(text: &str) -> serde_json::Value {
+        json!({
+            "musicResponsiveListItemFlexColumnRenderer": {
+                "text": { "runs": [{ "text": text }] }
+            }
+        })
+    }
+
+    #[test]
+    fn test_parse_playlist_track_added_at() {
+        let mut row = track_row("a");
+        row["musicResponsiveListItemRenderer"]["flexColumns"] = json!([
+            text_column("Title"),
+            text_column("Artist"),
+            text_column("Added 2 weeks ago"),
+            text_column("Album")
+        ]);
+        row["musicResponsiveListItemRenderer"]["fixedColumns"] = json!([{
+            "musicResponsiveListItemFixedColumnRenderer": {
+                "text": { "runs": [{ "text": "3:42" }] }
+            }
+        }]);
+
+        let track = parse_playlist_track(&row).unwrap();
+        assert_eq!(
+            track.added_at,
+            Some(AddedAt::Relative("2 weeks ago".to_string()))
+        );
+        assert_eq!(track.album.unwrap().name, "Album");
+        assert_eq!(track.duration_seconds, Some(222));
+
+        let mut row = track_row("b");
+        row["musicResponsiveListItemRenderer"]["fixedColumns"] = json!([
+            { "musicResponsiveListItemFixedColumnRenderer": { "text": { "simpleText": "1:00" } } },
+            { "musicResponsiveListItemFixedColumnRenderer": { "text": { "simpleText": "Added Jan 2, 2023" } } }
+        ]);
+        let track = parse_playlist_track(&row).unwrap();
+        assert_eq!(track.duration.as_deref(), Some("1:00"));
+        #[cfg(feature = "chrono")]
+        assert!(matches!(track.added_at, Some(AddedAt::Date(_))));
+    }
+
+    #[test]
+    fn test_parse_playlist_track_rows_reads_added_at_in_the_requested_language() {
+        let mut row = track_row("a");
+        row["musicResponsiveListItemRenderer"]["flexColumns"] = json!([
+            text_column("Titel"),
+            text_column("Künstler"),
+            text_column("Hinzugefügt: vor 2 Wochen"),
+        ]);
+        let rows = [row];
+
+        let german = parse_playlist_track_rows(&rows, vocabulary("de"), &mut Vec::new());
+        assert_eq!(
+            german[0].added_at,
+            Some(AddedAt::Relative("vor 2 Wochen".to_string()))
+        );
+        assert!(german[0].album.is_none());
+        let english = parse_playlist_track_rows(&rows, vocabulary("en"), &mut Vec::new());
+        assert_eq!(english[0].added_at, None);
+    }
+
+    #[test]
+    fn test_parse_playlist_response_secondary_contents_continuation() {
+        let response = json!({
+            "contents": {
+                "twoColumnBrowseResultsRenderer": {
+                    "secondaryContents": {
+                        "sectionListRenderer": {
+                            "contents": [{
+                                "musicPlaylistShelfRenderer": {
+                                    "contents": [track_row("a"), continuation_row("next")]
+                                }
+                            }]
+                        }
+                    }
+                }
+            }
+        });
+
+        let (playlist, continuation) = parse_playlist_response(&response, "VLPL1", "en");
+        assert_eq!(playlist.id, "PL1");
+        assert_eq!(playlist.tracks.len(), 1);
+        assert_eq!(continuation.as_deref(), Some("next"));
+    }
+
+    #[test]
+    fn test_parse_playlist_response_shelf_in_tab_content() {
+        let response = json!({
+            "contents": {
+                "twoColumnBrowseResultsRenderer": {
+                    "tabs": [{
+                        "tabRenderer": {
+                            "content": {
+                                "sectionListRenderer": {
+                                    "contents": [{
+                                        "musicShelfRenderer": {
+                                            "contents": [track_row("a"), track_row("b")],
+                                            "continuations": [{
+                                                "nextContinuationData": { "continuation": "legacy" }
+                                            }]
+                                        }
+                                    }]
+                                }
+                            }
+                        }
+                    }]
+                }
+            }
+        });
+
+        let (playlist, continuation) = parse_playlist_response(&response, "PL1", "en");
+        assert_eq!(playlist.tracks.len(), 2);
+        assert_eq!(continuation.as_deref(), Some("legacy"));
+    }
+
+    #[test]
+    fn test_parse_playlist_meta_views_and_likes() {
+        let runs = json!([
+            { "text": "1.2M views" },
+            { "text": " • " },
+            { "text": "34K likes" },
+            { "text": " • " },
+            { "text": "1,024 songs" },
+            { "text": " • " },
+            { "text": "6+ hours" }
+        ]);
+
+        let mut playlist = Playlist::default();
+        parse_playlist_meta_from_runs(runs.as_array().unwrap(), &mut playlist, vocabulary("en"));
+        assert_eq!(playlist.views, Some(1_200_000));
+        assert_eq!(playlist.likes, Some(34_000));
+        assert_eq!(playlist.track_count, Some(1024));
+        assert_eq!(playlist.duration.as_deref(), Some("6+ hours"));
+    }
+
+    #[test]
+    fn test_parse_playlist_meta_reads_the_configured_language() {
+        let runs = json!([
+            { "text": "1.234 Aufrufe" },
+            { "text": " • " },
+            { "text": "1.024 Titel" },
+            { "text": " • " },
+            { "text": "3 Std. 5 Min." }
+        ]);
+        let runs = runs.as_array().unwrap();
+
+        let mut german = Playlist::default();
+        parse_playlist_meta_from_runs(runs, &mut german, vocabulary("de"));
+        assert_eq!(german.views, Some(1234));
+        assert_eq!(german.track_count, Some(1024));
+        assert_eq!(german.duration.as_deref(), Some("3 Std. 5 Min."));
+
+        // The same labels are not English, so nothing is read from them.
+        let mut english = Playlist::default();
+        parse_playlist_meta_from_runs(runs, &mut english, vocabulary("en"));
+        assert_eq!(english.views, None);
+        assert_eq!(english.track_count, None);
+        assert_eq!(english.duration, None);
+    }
+
+    #[test]
+    fn test_parse_cover_source_without_thumbnails() {
+        assert_eq!(parse_cover_source(&[]), None);
+    }
+
     #[test]
     fn test_parse_library_playlists_keeps_first_playlist() {
         let response = library_response(json!([
@@ -443,12 +1368,164 @@ mod tests {
             playlist_item("Second", "VLPLSECOND")
         ]));
 
-        let playlists = parse_library_playlists(&response);
+        let playlists = parse_library_playlists_page(&response).playlists;
         assert_eq!(playlists.len(), 2);
         assert_eq!(playlists[0].playlist_id, "PLFIRST");
         assert_eq!(playlists[0].title, "First");
     }
 
+    fn new_playlist_tile() -> serde_json::Value {
+        json!({
+            "musicTwoRowItemRenderer": {
+                "title": { "runs": [{ "text": "New playlist" }] },
+                "navigationEndpoint": {
+                    "createPlaylistEndpoint": {}
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_parse_library_playlists_skips_two_pseudo_items() {
+        let response = library_response(json!([
+            new_playlist_tile(),
+            {
+                "musicTwoRowItemRenderer": {
+                    "title": { "runs": [{ "text": "Shortcut" }] },
+                    "navigationEndpoint": { "browseEndpoint": { "browseId": "" } }
+                }
+            },
+            playlist_item("Your Likes", "VLLM"),
+            playlist_item("First", "VLPLFIRST")
+        ]));
+
+        let playlists = parse_library_playlists_page(&response).playlists;
+        let ids: Vec<_> = playlists.iter().map(|p| p.playlist_id.as_str()).collect();
+        assert_eq!(ids, ["LM", "PLFIRST"]);
+    }
+
+    #[test]
+    fn test_parse_library_playlists_skips_single_pseudo_item() {
+        let response = library_response(json!([
+            new_playlist_tile(),
+            playlist_item("First", "VLPLFIRST")
+        ]));
+
+        let playlists = parse_library_playlists_page(&response).playlists;
+        assert_eq!(playlists.len(), 1);
+        assert_eq!(playlists[0].playlist_id, "PLFIRST");
+    }
+
+    #[test]
+    fn test_parse_library_playlist_items_continuation_skips_nothing() {
+        let items = json!([
+            playlist_item("Third", "VLPLTHIRD"),
+            playlist_item("Fourth", "VLPLFOURTH")
+        ]);
+
+        let playlists = parse_library_playlist_items(items.as_array().unwrap());
+        assert_eq!(playlists.len(), 2);
+        assert_eq!(playlists[0].playlist_id, "PLTHIRD");
+    }
+
+    #[test]
+    fn test_parse_library_playlists_page_token_and_total() {
+        let mut response = library_response(json!([
+            playlist_item("First", "VLPLFIRST"),
+            continuation_row("page2")
+        ]));
+        response["contents"]["singleColumnBrowseResultsRenderer"]["tabs"][0]["tabRenderer"]["content"]
+            ["sectionListRenderer"]["contents"][0]["gridRenderer"]["header"] = json!({
+            "gridHeaderRenderer": { "title": { "runs": [{ "text": "312 playlists" }] } }
+        });
+
+        let page = parse_library_playlists_page(&response);
+        assert_eq!(page.playlists.len(), 1);
+        assert_eq!(page.total_hint, Some(312));
+        assert_eq!(page.next_token.as_deref(), Some("page2"));
+    }
+
+    #[test]
+    fn test_parse_library_playlists_continuation_shapes() {
+        let grid_continuation = json!({
+            "continuationContents": {
+                "gridContinuation": {
+                    "items": [playlist_item("Second", "VLPLSECOND")],
+                    "continuations": [{
+                        "nextContinuationData": { "continuation": "page3" }
+                    }]
+                }
+            }
+        });
+        let page = parse_library_playlists_continuation(&grid_continuation);
+        assert_eq!(page.playlists[0].playlist_id, "PLSECOND");
+        assert_eq!(page.next_token.as_deref(), Some("page3"));
+
+        let append_action = json!({
+            "onResponseReceivedActions": [{
+                "appendContinuationItemsAction": {
+                    "continuationItems": [playlist_item("Third", "VLPLTHIRD")]
+                }
+            }]
+        });
+        let page = parse_library_playlists_continuation(&append_action);
+        assert_eq!(page.playlists[0].playlist_id, "PLTHIRD");
+        assert_eq!(page.next_token, None);
+    }
+
+    #[test]
+    fn test_parse_library_playlists_keeps_degenerate_items() {
+        let response = library_response(json!([
+            // Empty playlist: no thumbnails and no subtitle count
+            {
+                "musicTwoRowItemRenderer": {
+                    "title": { "runs": [{ "text": "Empty" }] },
+                    "navigationEndpoint": { "browseEndpoint": { "browseId": "VLPLEMPTY" } }
+                }
+            },
+            // Accessibility-only title
+            {
+                "musicTwoRowItemRenderer": {
+                    "title": {
+                        "runs": [{
+                            "accessibility": {
+                                "accessibilityData": { "label": "Episodes for later" }
+                            }
+                        }]
+                    },
+                    "navigationEndpoint": { "browseEndpoint": { "browseId": "VLSE" } }
+                }
+            },
+            // ID only on the play button overlay
+            {
+                "musicTwoRowItemRenderer": {
+                    "title": { "runs": [{ "text": "Auto" }] },
+                    "thumbnailOverlay": {
+                        "musicItemThumbnailOverlayRenderer": {
+                            "content": {
+                                "musicPlayButtonRenderer": {
+                                    "playNavigationEndpoint": {
+                                        "watchPlaylistEndpoint": { "playlistId": "RDAUTO" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        ]));
+
+        let playlists = parse_library_playlists_page(&response).playlists;
+        assert_eq!(playlists.len(), 3);
+        assert_eq!(playlists[0].playlist_id, "PLEMPTY");
+        assert!(playlists[0].thumbnails.is_empty());
+        assert_eq!(playlists[0].count, None);
+        assert_eq!(playlists[1].playlist_id, "SE");
+        assert_eq!(playlists[1].title, "Episodes for later");
+        assert_eq!(playlists[2].playlist_id, "RDAUTO");
+        assert_eq!(playlists[2].title, "Auto");
+    }
+
     #[test]
     fn test_parse_library_playlists_ignores_non_playlist_tile() {
         let response = library_response(json!([
@@ -456,7 +1533,7 @@ mod tests {
             playlist_item("First", "VLPLFIRST")
         ]));
 
-        let playlists = parse_library_playlists(&response);
+        let playlists = parse_library_playlists_page(&response).playlists;
         assert_eq!(playlists.len(), 1);
         assert_eq!(playlists[0].playlist_id, "PLFIRST");
     }
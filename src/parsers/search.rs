@@ -0,0 +1,717 @@
+//! Search response parsing.
+
+use serde_json::Value;
+
+use crate::continuation::get_continuation_token;
+use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::date::parse_year_run;
+use crate::parsers::navigation::paths;
+use crate::parsers::playlist::parse_thumbnails;
+use crate::parsers::track::{
+    get_fixed_column_item, get_flex_column_item, get_item_text, parse_artist_runs, parse_duration,
+    parse_song_artists,
+};
+use crate::types::{Album, MusicVideoType, SearchResult, SearchResultType, SearchSuggestion};
+
+/// Separator between subtitle fields, e.g. `"Artist • Album • 3:45"`.
+pub const SUBTITLE_SEPARATOR: &str = " • ";
+
+/// Parse the `musicShelfRenderer` sections of a search response into results
+/// and the continuation token of the last shelf that has one.
+///
+/// Other sections (the top-result card, "Did you mean" and "Showing results
+/// for" corrections) are skipped.
+pub fn parse_search_results(response: &Value) -> (Vec<SearchResult>, Option<String>) {
+    let sections = nav(response, &path!["contents", "tabbedSearchResultsRenderer"])
+        .and_then(|v| nav(v, paths::TAB_CONTENT))
+        .and_then(|v| nav_array(v, paths::SECTION_LIST))
+        .or_else(|| {
+            nav_array(
+                response,
+                &path!["contents", "sectionListRenderer", "contents"],
+            )
+        });
+
+    let Some(sections) = sections else {
+        return (Vec::new(), None);
+    };
+
+    let shelves = || {
+        sections
+            .iter()
+            .filter_map(|section| section.get("musicShelfRenderer"))
+    };
+    let results = shelves()
+        .filter_map(|shelf| nav_array(shelf, &path!["contents"]))
+        .flat_map(|rows| parse_search_rows(rows))
+        .collect();
+    (results, shelves().rev().find_map(get_continuation_token))
+}
+
+/// Parse a `music/get_search_suggestions` response.
+///
+/// Only the text suggestions of `searchSuggestionsSectionRenderer` sections
+/// are read; sections suggesting songs, albums or artists directly are
+/// skipped.
+pub fn parse_search_suggestions(response: &Value) -> Vec<SearchSuggestion> {
+    nav_array(response, &path!["contents"])
+        .into_iter()
+        .flatten()
+        .filter_map(|section| {
+            nav_array(
+                section,
+                &path!["searchSuggestionsSectionRenderer", "contents"],
+            )
+        })
+        .flatten()
+        .filter_map(parse_search_suggestion)
+        .collect()
+}
+
+fn parse_search_suggestion(item: &Value) -> Option<SearchSuggestion> {
+    let (renderer, from_history) = match item.get("historySuggestionRenderer") {
+        Some(renderer) => (renderer, true),
+        None => (item.get("searchSuggestionRenderer")?, false),
+    };
+
+    let text: String = nav_array(renderer, &path!["suggestion", "runs"])
+        .into_iter()
+        .flatten()
+        .filter_map(|run| nav_str(run, &path!["text"]))
+        .collect();
+    let text = if text.is_empty() {
+        nav_str(
+            renderer,
+            &path!["navigationEndpoint", "searchEndpoint", "query"],
+        )?
+        .to_string()
+    } else {
+        text
+    };
+
+    Some(SearchSuggestion {
+        text,
+        from_history,
+        feedback_token: nav_str(
+            renderer,
+            &path!["serviceEndpoint", "feedbackEndpoint", "feedbackToken"],
+        )
+        .map(str::to_string),
+    })
+}
+
+/// Parse search rows, skipping anything that is not a result row.
+pub fn parse_search_rows(rows: &[Value]) -> Vec<SearchResult> {
+    rows.iter().filter_map(parse_search_result).collect()
+}
+
+/// Parse a single `musicResponsiveListItemRenderer` search row.
+pub fn parse_search_result(item: &Value) -> Option<SearchResult> {
+    let data = item.get(paths::MRLIR)?;
+    let title = get_item_text(data, 0)?.to_string();
+
+    let watch = nav(data, paths::PLAY_BUTTON)
+        .and_then(|button| nav(button, &path!["playNavigationEndpoint", "watchEndpoint"]));
+    let video_id = watch
+        .and_then(|w| nav_str(w, &path!["videoId"]))
+        .or_else(|| nav_str(data, &path!["playlistItemData", "videoId"]))
+        .map(str::to_string);
+    let video_type = watch.and_then(watch_video_type);
+    let browse_id = nav_str(data, paths::NAVIGATION_BROWSE_ID).map(str::to_string);
+
+    let mut result = SearchResult {
+        result_type: result_type(data, video_id.is_some(), video_type.as_ref()),
+        title,
+        video_id,
+        browse_id,
+        artists: Vec::new(),
+        album: None,
+        duration: None,
+        duration_seconds: None,
+        year: None,
+        thumbnails: parse_thumbnails(data),
+        video_type,
+        is_explicit: nav(data, paths::BADGE_LABEL).is_some(),
+    };
+
+    // Global results join everything into the second column; library results
+    // spread artist and album over separate columns, some of which may be
+    // missing.
+    let columns = nav_array(data, &path!["flexColumns"]).map_or(0, Vec::len);
+    for index in 1..columns {
+        if let Some(runs) =
+            get_flex_column_item(data, index).and_then(|c| nav_array(c, &path!["text", "runs"]))
+        {
+            parse_subtitle_runs(runs, &mut result);
+        }
+    }
+    if result.artists.is_empty()
+        && columns > 2
+        && matches!(
+            result.result_type,
+            SearchResultType::Song | SearchResultType::Video
+        )
+    {
+        // Library layout without artist links (e.g. uploads): the artist
+        // column holds only names.
+        result.artists = parse_song_artists(data, 1);
+    }
+
+    if result.duration.is_none()
+        && let Some(text) = get_fixed_column_item(data, 0)
+            .and_then(|c| nav_str(c, &path!["text", "runs", 0, "text"]))
+    {
+        set_duration(&mut result, text);
+    }
+
+    Some(result)
+}
+
+/// Parse carousel items, which mix `musicResponsiveListItemRenderer` rows
+/// (songs) with `musicTwoRowItemRenderer` cards (artists, albums, playlists,
+/// videos).
+pub fn parse_carousel_items(items: &[Value]) -> Vec<SearchResult> {
+    items
+        .iter()
+        .filter_map(|item| parse_search_result(item).or_else(|| parse_two_row_result(item)))
+        .collect()
+}
+
+/// Parse a single `musicTwoRowItemRenderer` card.
+pub fn parse_two_row_result(item: &Value) -> Option<SearchResult> {
+    let data = item.get(paths::MTRIR)?;
+    let title = nav_str(data, paths::TITLE_TEXT)?.to_string();
+
+    let watch = nav(data, &path!["navigationEndpoint", "watchEndpoint"]);
+    let video_id = watch
+        .and_then(|w| nav_str(w, &path!["videoId"]))
+        .map(str::to_string);
+    let video_type = watch.and_then(watch_video_type);
+
+    let mut result = SearchResult {
+        result_type: result_type(data, video_id.is_some(), video_type.as_ref()),
+        title,
+        video_id,
+        browse_id: nav_str(data, paths::NAVIGATION_BROWSE_ID).map(str::to_string),
+        artists: Vec::new(),
+        album: None,
+        duration: None,
+        duration_seconds: None,
+        year: None,
+        thumbnails: parse_thumbnails(data),
+        video_type,
+        is_explicit: nav(
+            data,
+            &path![
+                "subtitleBadges",
+                0,
+                "musicInlineBadgeRenderer",
+                "accessibilityData"
+            ],
+        )
+        .is_some(),
+    };
+    if let Some(runs) = nav_array(data, paths::SUBTITLE_RUNS) {
+        parse_subtitle_runs(runs, &mut result);
+    }
+    Some(result)
+}
+
+fn watch_video_type(watch: &Value) -> Option<MusicVideoType> {
+    nav_str(
+        watch,
+        &path![
+            "watchEndpointMusicSupportedConfigs",
+            "watchEndpointMusicConfig",
+            "musicVideoType"
+        ],
+    )
+    .map(MusicVideoType::from)
+}
+
+/// Classify a result by its video type if playable, else by its link.
+fn result_type(
+    data: &Value,
+    playable: bool,
+    video_type: Option<&MusicVideoType>,
+) -> SearchResultType {
+    match (playable, video_type) {
+        (true, Some(MusicVideoType::Atv)) => SearchResultType::Song,
+        (true, Some(MusicVideoType::Podcast)) => SearchResultType::Episode,
+        (true, _) => SearchResultType::Video,
+        (false, _) => page_type_result(data),
+    }
+}
+
+/// Classify a non-playable result by the page type it links to.
+fn page_type_result(data: &Value) -> SearchResultType {
+    match page_type(data) {
+        Some("MUSIC_PAGE_TYPE_ALBUM" | "MUSIC_PAGE_TYPE_LIBRARY_ALBUM") => SearchResultType::Album,
+        Some("MUSIC_PAGE_TYPE_ARTIST" | "MUSIC_PAGE_TYPE_LIBRARY_ARTIST") => {
+            SearchResultType::Artist
+        }
+        Some("MUSIC_PAGE_TYPE_PLAYLIST") => SearchResultType::Playlist,
+        Some("MUSIC_PAGE_TYPE_PODCAST_SHOW_DETAIL_PAGE") => SearchResultType::Podcast,
+        Some("MUSIC_PAGE_TYPE_USER_CHANNEL") => SearchResultType::Profile,
+        _ => SearchResultType::Other,
+    }
+}
+
+/// Page type of an item's or run's browse endpoint.
+fn page_type(value: &Value) -> Option<&str> {
+    nav_str(
+        value,
+        &path![
+            "navigationEndpoint",
+            "browseEndpoint",
+            "browseEndpointContextSupportedConfigs",
+            "browseEndpointContextMusicConfig",
+            "pageType"
+        ],
+    )
+}
+
+/// Whether a subtitle run links to an artist (including library-only
+/// artists, whose IDs do not start with `UC`).
+pub fn is_artist_run(run: &Value) -> bool {
+    nav_str(run, paths::NAVIGATION_BROWSE_ID).is_some_and(|id| id.starts_with("UC"))
+        || page_type(run).is_some_and(|t| t.ends_with("ARTIST"))
+}
+
+/// Whether a subtitle run links to an album.
+fn is_album_run(run: &Value) -> bool {
+    nav_str(run, paths::NAVIGATION_BROWSE_ID).is_some_and(|id| id.starts_with("MPRE"))
+        || page_type(run).is_some_and(|t| t.ends_with("ALBUM"))
+}
+
+/// Fill artists, album, duration and year from a subtitle flex column.
+///
+/// Fields are recognised by their links (artist channels start with `UC`,
+/// albums with `MPRE`, or the linked page type) and by duration format, so a
+/// leading localized type label such as `"Song"` is ignored.
+fn parse_subtitle_runs(runs: &[Value], result: &mut SearchResult) {
+    for group in runs.split(|run| nav_str(run, &path!["text"]) == Some(SUBTITLE_SEPARATOR)) {
+        if result.artists.is_empty() && group.iter().any(is_artist_run) {
+            result.artists = parse_artist_runs(group);
+        } else if result.album.is_none()
+            && let Some(run) = group.iter().find(|run| is_album_run(run))
+        {
+            result.album = Some(Album {
+                name: nav_str(run, &path!["text"]).unwrap_or_default().to_string(),
+                id: nav_str(run, paths::NAVIGATION_BROWSE_ID).map(str::to_string),
+            });
+        } else if let [run] = group
+            && let Some(text) = nav_str(run, &path!["text"])
+            && text.contains(':')
+        {
+            set_duration(result, text);
+        } else if result.year.is_none() {
+            result.year = parse_year_run(group);
+        }
+    }
+}
+
+fn set_duration(result: &mut SearchResult, text: &str) {
+    if let Some(seconds) = parse_duration(text) {
+        result.duration = Some(text.to_string());
+        result.duration_seconds = Some(seconds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::continuation::continuation_rows;
+    use serde_json::json;
+
+    fn run(text: &str, browse_id: Option<&str>) -> Value {
+        match browse_id {
+            Some(id) => json!({
+                "text": text,
+                "navigationEndpoint": { "browseEndpoint": { "browseId": id } }
+            }),
+            None => json!({ "text": text }),
+        }
+    }
+
+    fn song_row(video_id: &str, video_type: &str, subtitle: Vec<Value>) -> Value {
+        watch_row("Around the World", video_id, video_type, subtitle)
+    }
+
+    fn watch_row(title: &str, video_id: &str, video_type: &str, subtitle: Vec<Value>) -> Value {
+        json!({
+            "musicResponsiveListItemRenderer": {
+                "overlay": {
+                    "musicItemThumbnailOverlayRenderer": {
+                        "content": {
+                            "musicPlayButtonRenderer": {
+                                "playNavigationEndpoint": {
+                                    "watchEndpoint": {
+                                        "videoId": video_id,
+                                        "watchEndpointMusicSupportedConfigs": {
+                                            "watchEndpointMusicConfig": { "musicVideoType": video_type }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                "flexColumns": [
+                    { "musicResponsiveListItemFlexColumnRenderer": { "text": { "runs": [{ "text": title }] } } },
+                    { "musicResponsiveListItemFlexColumnRenderer": { "text": { "runs": subtitle } } }
+                ]
+            }
+        })
+    }
+
+    fn browse_row(title: &str, browse_id: &str, page_type: &str, subtitle: Vec<Value>) -> Value {
+        json!({
+            "musicResponsiveListItemRenderer": {
+                "navigationEndpoint": {
+                    "browseEndpoint": {
+                        "browseId": browse_id,
+                        "browseEndpointContextSupportedConfigs": {
+                            "browseEndpointContextMusicConfig": { "pageType": page_type }
+                        }
+                    }
+                },
+                "flexColumns": [
+                    { "musicResponsiveListItemFlexColumnRenderer": { "text": { "runs": [{ "text": title }] } } },
+                    { "musicResponsiveListItemFlexColumnRenderer": { "text": { "runs": subtitle } } }
+                ]
+            }
+        })
+    }
+
+    fn search_response(sections: Vec<Value>) -> Value {
+        json!({
+            "contents": {
+                "tabbedSearchResultsRenderer": {
+                    "tabs": [{ "tabRenderer": { "content": { "sectionListRenderer": { "contents": sections } } } }]
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_parse_search_results() {
+        let sep = || run(" • ", None);
+        let song = song_row(
+            "s1",
+            "MUSIC_VIDEO_TYPE_ATV",
+            vec![
+                run("Song", None),
+                sep(),
+                run("Daft Punk", Some("UCdp")),
+                sep(),
+                run("Homework", Some("MPREb_hw")),
+                sep(),
+                run("7:09", None),
+            ],
+        );
+        let video = song_row(
+            "v1",
+            "MUSIC_VIDEO_TYPE_OMV",
+            vec![
+                run("Daft Punk", Some("UCdp")),
+                sep(),
+                run("120M views", None),
+                sep(),
+                run("4:01", None),
+            ],
+        );
+        let response = search_response(vec![
+            json!({ "itemSectionRenderer": { "contents": [{ "didYouMeanRenderer": {} }] } }),
+            json!({ "musicShelfRenderer": { "contents": [song, video] } }),
+            json!({ "musicShelfRenderer": { "contents": [browse_row("Homework", "MPREb_hw", "MUSIC_PAGE_TYPE_ALBUM", vec![])] } }),
+        ]);
+
+        let (results, token) = parse_search_results(&response);
+        assert_eq!(results.len(), 3);
+        assert_eq!(token, None);
+
+        let song = &results[0];
+        assert_eq!(song.result_type, SearchResultType::Song);
+        assert_eq!(song.video_id.as_deref(), Some("s1"));
+        assert_eq!(song.artists.len(), 1);
+        assert_eq!(song.artists[0].id.as_deref(), Some("UCdp"));
+        assert_eq!(
+            song.album.as_ref().map(|a| a.name.as_str()),
+            Some("Homework")
+        );
+        assert_eq!(song.duration_seconds, Some(429));
+
+        let video = &results[1];
+        assert_eq!(video.result_type, SearchResultType::Video);
+        assert!(video.album.is_none());
+        assert_eq!(video.duration.as_deref(), Some("4:01"));
+
+        let album = &results[2];
+        assert_eq!(album.result_type, SearchResultType::Album);
+        assert_eq!(album.browse_id.as_deref(), Some("MPREb_hw"));
+        assert!(album.video_id.is_none());
+    }
+
+    /// Rows shaped like each filter's results, with the expected type, title,
+    /// first artist, album and year.
+    #[test]
+    fn test_parse_filtered_search_rows() {
+        let sep = || run(" • ", None);
+        let artist = || run("Daft Punk", Some("UCdp"));
+        type Expected = (
+            SearchResultType,
+            &'static str,
+            Option<&'static str>,
+            Option<&'static str>,
+            Option<u16>,
+        );
+        let cases: Vec<(&str, Value, Expected)> = vec![
+            (
+                "songs",
+                watch_row(
+                    "One More Time",
+                    "s1",
+                    "MUSIC_VIDEO_TYPE_ATV",
+                    vec![
+                        artist(),
+                        sep(),
+                        run("Discovery", Some("MPREb_disc")),
+                        sep(),
+                        run("5:21", None),
+                    ],
+                ),
+                (
+                    SearchResultType::Song,
+                    "One More Time",
+                    Some("Daft Punk"),
+                    Some("Discovery"),
+                    None,
+                ),
+            ),
+            (
+                "videos",
+                watch_row(
+                    "Around the World (Official Video)",
+                    "v1",
+                    "MUSIC_VIDEO_TYPE_OMV",
+                    vec![
+                        artist(),
+                        sep(),
+                        run("120M views", None),
+                        sep(),
+                        run("4:01", None),
+                    ],
+                ),
+                (
+                    SearchResultType::Video,
+                    "Around the World (Official Video)",
+                    Some("Daft Punk"),
+                    None,
+                    None,
+                ),
+            ),
+            (
+                "albums",
+                browse_row(
+                    "Homework",
+                    "MPREb_hw",
+                    "MUSIC_PAGE_TYPE_ALBUM",
+                    vec![
+                        run("Album", None),
+                        sep(),
+                        artist(),
+                        sep(),
+                        run("1997", None),
+                    ],
+                ),
+                (
+                    SearchResultType::Album,
+                    "Homework",
+                    Some("Daft Punk"),
+                    None,
+                    Some(1997),
+                ),
+            ),
+            (
+                "artists",
+                browse_row(
+                    "Daft Punk",
+                    "UCdp",
+                    "MUSIC_PAGE_TYPE_ARTIST",
+                    vec![run("Artist", None), sep(), run("2.1M subscribers", None)],
+                ),
+                (SearchResultType::Artist, "Daft Punk", None, None, None),
+            ),
+            (
+                "community_playlists",
+                browse_row(
+                    "French Touch",
+                    "VLPLft",
+                    "MUSIC_PAGE_TYPE_PLAYLIST",
+                    vec![
+                        run("Some User", Some("UCuser")),
+                        sep(),
+                        run("12K views", None),
+                    ],
+                ),
+                (
+                    SearchResultType::Playlist,
+                    "French Touch",
+                    Some("Some User"),
+                    None,
+                    None,
+                ),
+            ),
+            (
+                "featured_playlists",
+                browse_row(
+                    "Daft Punk Essentials",
+                    "VLRDCLAK",
+                    "MUSIC_PAGE_TYPE_PLAYLIST",
+                    vec![run("YouTube Music", None), sep(), run("50 songs", None)],
+                ),
+                (
+                    SearchResultType::Playlist,
+                    "Daft Punk Essentials",
+                    None,
+                    None,
+                    None,
+                ),
+            ),
+            (
+                "podcasts",
+                browse_row(
+                    "Song Exploder",
+                    "MPSPPL",
+                    "MUSIC_PAGE_TYPE_PODCAST_SHOW_DETAIL_PAGE",
+                    vec![run("Podcast", None), sep(), run("Hrishikesh Hirway", None)],
+                ),
+                (SearchResultType::Podcast, "Song Exploder", None, None, None),
+            ),
+            (
+                "episodes",
+                watch_row(
+                    "Daft Punk - Instant Crush",
+                    "e1",
+                    "MUSIC_VIDEO_TYPE_PODCAST_EPISODE",
+                    vec![
+                        run("Jan 5, 2024", None),
+                        sep(),
+                        run("Song Exploder", Some("MPSPPL")),
+                    ],
+                ),
+                (
+                    SearchResultType::Episode,
+                    "Daft Punk - Instant Crush",
+                    None,
+                    None,
+                    None,
+                ),
+            ),
+        ];
+
+        for (filter, row, (result_type, title, artist, album, year)) in cases {
+            let result = parse_search_result(&row).unwrap();
+            assert_eq!(result.result_type, result_type, "{filter}");
+            assert_eq!(result.title, title, "{filter}");
+            assert_eq!(
+                result.artists.first().map(|a| a.name.as_str()),
+                artist,
+                "{filter}"
+            );
+            assert_eq!(
+                result.album.as_ref().map(|a| a.name.as_str()),
+                album,
+                "{filter}"
+            );
+            assert_eq!(result.year, year, "{filter}");
+        }
+    }
+
+    /// Library rows: one field per column, fixed duration column, and no
+    /// artist links for uploads.
+    #[test]
+    fn test_parse_library_search_rows() {
+        let column = |runs: Value| json!({ "musicResponsiveListItemFlexColumnRenderer": { "text": { "runs": runs } } });
+        let mut row = watch_row("Bohemian Rhapsody", "q1", "MUSIC_VIDEO_TYPE_ATV", vec![]);
+        row["musicResponsiveListItemRenderer"]["flexColumns"] = json!([
+            column(json!([{ "text": "Bohemian Rhapsody" }])),
+            column(json!([{ "text": "Queen" }])),
+            column(json!([{
+                "text": "A Night at the Opera",
+                "navigationEndpoint": { "browseEndpoint": {
+                    "browseId": "FEmusic_library_privately_owned_release_detailabc",
+                    "browseEndpointContextSupportedConfigs": {
+                        "browseEndpointContextMusicConfig": {
+                            "pageType": "MUSIC_PAGE_TYPE_LIBRARY_ALBUM"
+                        }
+                    }
+                } }
+            }])),
+        ]);
+        row["musicResponsiveListItemRenderer"]["fixedColumns"] = json!([{
+            "musicResponsiveListItemFixedColumnRenderer": {
+                "text": { "runs": [{ "text": "5:55" }] }
+            }
+        }]);
+
+        let result = parse_search_result(&row).unwrap();
+        assert_eq!(result.result_type, SearchResultType::Song);
+        assert_eq!(result.artists.len(), 1);
+        assert_eq!(result.artists[0].name, "Queen");
+        assert_eq!(
+            result.album.as_ref().map(|a| a.name.as_str()),
+            Some("A Night at the Opera")
+        );
+        assert_eq!(result.duration_seconds, Some(355));
+
+        // Missing album column
+        let flex = &mut row["musicResponsiveListItemRenderer"]["flexColumns"];
+        flex[2] = json!({});
+        let result = parse_search_result(&row).unwrap();
+        assert_eq!(result.artists[0].name, "Queen");
+        assert!(result.album.is_none());
+    }
+
+    #[test]
+    fn test_search_continuation_rows_shapes() {
+        let next = json!({
+            "continuationItemRenderer": {
+                "continuationEndpoint": { "continuationCommand": { "token": "t2" } }
+            }
+        });
+        let shelf = json!({
+            "continuationContents": {
+                "musicShelfContinuation": { "contents": [album_like(), next.clone()] }
+            }
+        });
+        let (rows, token) = continuation_rows(&shelf);
+        assert_eq!(parse_search_rows(rows).len(), 1);
+        assert_eq!(token.as_deref(), Some("t2"));
+
+        let command = json!({
+            "onResponseReceivedCommands": [{
+                "appendContinuationItemsAction": { "continuationItems": [album_like(), next] }
+            }]
+        });
+        let (rows, token) = continuation_rows(&command);
+        assert_eq!(parse_search_rows(rows).len(), 1);
+        assert_eq!(token.as_deref(), Some("t2"));
+
+        assert!(continuation_rows(&json!({})).0.is_empty());
+    }
+
+    fn album_like() -> Value {
+        browse_row("Homework", "MPREb_hw", "MUSIC_PAGE_TYPE_ALBUM", vec![])
+    }
+
+    #[test]
+    fn test_parse_search_results_without_shelves() {
+        let response = search_response(vec![json!({
+            "itemSectionRenderer": { "contents": [{ "messageRenderer": {} }] }
+        })]);
+        assert!(parse_search_results(&response).0.is_empty());
+        assert!(parse_search_results(&json!({})).0.is_empty());
+    }
+}
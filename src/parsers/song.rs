@@ -0,0 +1,167 @@
+//! Player response parsing.
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::locale::{Vocabulary, vocabulary};
+use crate::parsers::track::parse_external_ids;
+use crate::types::{LikeStatus, PlayabilityStatus, RateSongResponse, Song, Unplayable};
+
+/// Parse a `player` response into a [`Song`].
+///
+/// The typed fields deserialize directly; external identifiers are read from
+/// the microformat when present, and a refused track is classified from its
+/// `playabilityStatus` using the reason texts of `language`.
+pub fn parse_song(response: &Value, language: &str) -> serde_json::Result<Song> {
+    let mut song = Song::deserialize(response)?;
+    song.external_ids = nav(response, &path!["microformat", "microformatDataRenderer"])
+        .and_then(parse_external_ids);
+    song.unplayable = song
+        .playability_status
+        .as_ref()
+        .zip(response.get("playabilityStatus"))
+        .and_then(|(status, raw)| parse_unplayable(status, raw, vocabulary(language)));
+    Ok(song)
+}
+
+/// Classify why the player refused a track, or `None` if it is playable.
+///
+/// Paid-content offers are recognized by their renderer; the other causes by
+/// the status constant or the reason texts shown on the error screen.
+fn parse_unplayable(
+    status: &PlayabilityStatus,
+    raw: &Value,
+    words: &Vocabulary,
+) -> Option<Unplayable> {
+    if status.is_ok() {
+        return None;
+    }
+
+    let error_screen = raw.get("errorScreen").and_then(Value::as_object);
+    let offers_purchase = error_screen
+        .is_some_and(|screen| screen.keys().any(|key| key.to_lowercase().contains("ypc")));
+    let message = nav(raw, &path!["errorScreen", "playerErrorMessageRenderer"]);
+    let texts: Vec<String> = status
+        .reason
+        .iter()
+        .cloned()
+        .chain(
+            ["reason", "subreason"]
+                .into_iter()
+                .filter_map(|key| message.and_then(|message| message.get(key)).and_then(text)),
+        )
+        .collect();
+    let mentions = |stems: &[&str]| texts.iter().any(|text| Vocabulary::matches(text, stems));
+
+    Some(
+        if matches!(
+            status.status.as_str(),
+            "AGE_CHECK_REQUIRED" | "AGE_VERIFICATION_REQUIRED"
+        ) || mentions(words.age_restricted)
+        {
+            Unplayable::AgeRestricted
+        } else if offers_purchase || mentions(words.premium) {
+            Unplayable::PremiumRequired
+        } else if mentions(words.region_blocked) {
+            Unplayable::RegionBlocked
+        } else {
+            Unplayable::Other {
+                status: status.status.clone(),
+                reason: status.reason.clone(),
+            }
+        },
+    )
+}
+
+/// Parse the response to rating a song `like_status`.
+///
+/// The confirmation text comes from the toast the web client would show.
+pub fn parse_rate_response(response: Value, like_status: LikeStatus) -> RateSongResponse {
+    let feedback = nav_array(&response, &path!["actions"])
+        .into_iter()
+        .flatten()
+        .filter_map(|action| nav(action, &path!["addToToastAction", "item"]))
+        .filter_map(Value::as_object)
+        .flat_map(|item| item.values())
+        .find_map(|toast| {
+            ["successResponseText", "responseText", "text"]
+                .into_iter()
+                .find_map(|key| toast.get(key).and_then(text))
+        });
+    RateSongResponse {
+        like_status,
+        feedback,
+        raw: response,
+    }
+}
+
+/// The text of a `simpleText` or `runs` field.
+fn text(value: &Value) -> Option<String> {
+    if let Some(text) = nav_str(value, &path!["simpleText"]) {
+        return Some(text.to_string());
+    }
+    let runs = nav_array(value, &path!["runs"])?;
+    Some(
+        runs.iter()
+            .filter_map(|run| nav_str(run, &path!["text"]))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn refused(language: &str, subreason: &str) -> Option<Unplayable> {
+        let response = json!({
+            "playabilityStatus": {
+                "status": "UNPLAYABLE",
+                "reason": "Video nicht verfügbar",
+                "errorScreen": {
+                    "playerErrorMessageRenderer": {
+                        "subreason": { "runs": [{ "text": subreason }] }
+                    }
+                }
+            },
+            "videoDetails": {
+                "videoId": "v", "title": "t", "author": "a",
+                "lengthSeconds": "1", "viewCount": "0"
+            }
+        });
+        parse_song(&response, language).unwrap().unplayable
+    }
+
+    #[test]
+    fn test_rate_response_reads_toast_text() {
+        let response = json!({
+            "actions": [{
+                "addToToastAction": {
+                    "item": {
+                        "notificationActionRenderer": {
+                            "responseText": { "runs": [{ "text": "Saved to " }, { "text": "Liked music" }] }
+                        }
+                    }
+                }
+            }]
+        });
+        let rated = parse_rate_response(response, LikeStatus::Like);
+        assert_eq!(rated.like_status, LikeStatus::Like);
+        assert_eq!(rated.feedback.as_deref(), Some("Saved to Liked music"));
+
+        let bare = parse_rate_response(json!({}), LikeStatus::Indifferent);
+        assert_eq!(bare.feedback, None);
+    }
+
+    #[test]
+    fn test_unplayable_reads_reasons_in_response_language() {
+        let subreason = "Dieses Video ist in deinem Land nicht verfügbar.";
+        assert_eq!(refused("de", subreason), Some(Unplayable::RegionBlocked));
+        assert!(matches!(
+            refused("en", subreason),
+            Some(Unplayable::Other { status, .. }) if status == "UNPLAYABLE"
+        ));
+    }
+}
@@ -3,7 +3,11 @@
 use serde_json::Value;
 
 use crate::nav::{nav, nav_str};
-use crate::types::{Album, Artist};
+#[cfg(feature = "chrono")]
+use crate::parsers::date::parse_localized_date;
+use crate::parsers::locale::Vocabulary;
+
+use crate::types::{AddedAt, Album, Artist, ExternalIds, LikeStatus};
 
 /// Parse duration string to seconds.
 ///
@@ -25,12 +29,64 @@ pub fn parse_duration(duration: &str) -> Option<u32> {
             2 => 3600, // hours
             _ => return None,
         };
-        seconds += value * multiplier;
+        seconds = seconds.checked_add(value.checked_mul(multiplier)?)?;
     }
 
     Some(seconds)
 }
 
+/// Parse an "Added ..." column label in the language of `words` into an
+/// [`AddedAt`].
+///
+/// Returns `None` if the text is not an added-date label.
+pub fn parse_added_at(text: &str, words: &Vocabulary) -> Option<AddedAt> {
+    let text = text.trim();
+    let rest = words.added.iter().find_map(|stem| {
+        text.get(..stem.len())
+            .filter(|prefix| prefix.to_lowercase() == *stem)
+            .map(|_| text[stem.len()..].trim_start_matches(':').trim())
+    })?;
+    if rest.is_empty() {
+        return None;
+    }
+
+    #[cfg(feature = "chrono")]
+    if let Some(date) = parse_localized_date(rest) {
+        return Some(AddedAt::Date(date));
+    }
+    Some(AddedAt::Relative(rest.to_string()))
+}
+
+/// Read the `isrc` and `upc` fields of a renderer, if it carries either.
+///
+/// Values that are not well-formed codes are ignored.
+pub fn parse_external_ids(data: &Value) -> Option<ExternalIds> {
+    let field = |key: &'static str| nav_str(data, &path![key]);
+    let ids = ExternalIds {
+        isrc: field("isrc").and_then(normalize_isrc),
+        upc: field("upc")
+            .map(str::trim)
+            .filter(|upc| matches!(upc.len(), 12 | 13) && upc.bytes().all(|b| b.is_ascii_digit()))
+            .map(str::to_string),
+    };
+    (ids != ExternalIds::default()).then_some(ids)
+}
+
+/// Normalize an ISRC such as `"US-RC1-76-07839"` to `"USRC17607839"`.
+fn normalize_isrc(text: &str) -> Option<String> {
+    let isrc: String = text
+        .chars()
+        .filter(|c| *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    let bytes = isrc.as_bytes();
+    let valid = bytes.len() == 12
+        && bytes[..2].iter().all(u8::is_ascii_alphabetic)
+        && bytes[2..5].iter().all(u8::is_ascii_alphanumeric)
+        && bytes[5..].iter().all(u8::is_ascii_digit);
+    valid.then_some(isrc)
+}
+
 /// Parse artists from flex column runs.
 pub fn parse_song_artists(data: &Value, index: usize) -> Vec<Artist> {
     let flex_item = get_flex_column_item(data, index);
@@ -117,9 +173,20 @@ pub fn get_item_text(item: &Value, index: usize) -> Option<&str> {
     nav_str(column, &path!["text", "runs", 0, "text"])
 }
 
+/// Parse a `likeStatus` value.
+pub fn parse_like_status(status: &str) -> Option<LikeStatus> {
+    match status {
+        "LIKE" => Some(LikeStatus::Like),
+        "DISLIKE" => Some(LikeStatus::Dislike),
+        "INDIFFERENT" => Some(LikeStatus::Indifferent),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parsers::locale::vocabulary;
 
     #[test]
     fn test_parse_duration() {
@@ -127,10 +194,52 @@ mod tests {
         assert_eq!(parse_duration("0:30"), Some(30));
         assert_eq!(parse_duration("1:00:00"), Some(3600));
         assert_eq!(parse_duration("1:23:45"), Some(5025));
+        assert_eq!(parse_duration("9999999:00:00"), None);
         assert_eq!(parse_duration(""), None);
         assert_eq!(parse_duration("  "), None);
     }
 
+    #[test]
+    fn test_parse_added_at() {
+        let english = vocabulary("en");
+        assert_eq!(
+            parse_added_at("Added 2 weeks ago", english),
+            Some(AddedAt::Relative("2 weeks ago".to_string()))
+        );
+        assert_eq!(parse_added_at("Added", english), None);
+        assert_eq!(parse_added_at("Discovery", english), None);
+
+        let german = vocabulary("de");
+        assert_eq!(
+            parse_added_at("Hinzugefügt: vor 2 Wochen", german),
+            Some(AddedAt::Relative("vor 2 Wochen".to_string()))
+        );
+        // Labels are only read in the requested language.
+        assert_eq!(parse_added_at("Added 2 weeks ago", german), None);
+        assert_eq!(parse_added_at("Hinzugefügt vor 2 Wochen", english), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_parse_added_at_dates() {
+        use chrono::NaiveDate;
+
+        assert_eq!(
+            parse_added_at("Added Mar 5, 2024", vocabulary("en")),
+            Some(AddedAt::Date(NaiveDate::from_ymd_opt(2024, 3, 5).unwrap()))
+        );
+        assert_eq!(
+            parse_added_at("added 2023-11-30", vocabulary("en")),
+            Some(AddedAt::Date(
+                NaiveDate::from_ymd_opt(2023, 11, 30).unwrap()
+            ))
+        );
+        assert_eq!(
+            parse_added_at("Hinzugefügt am 5. März 2024", vocabulary("de")),
+            Some(AddedAt::Date(NaiveDate::from_ymd_opt(2024, 3, 5).unwrap()))
+        );
+    }
+
     #[test]
     fn test_parse_artist_runs() {
         let runs = serde_json::json!([
@@ -146,4 +255,18 @@ mod tests {
         assert_eq!(artists[1].name, "Artist 2");
         assert_eq!(artists[1].id, None);
     }
+
+    #[test]
+    fn test_parse_external_ids() {
+        let ids = parse_external_ids(&serde_json::json!({
+            "isrc": "us-rc1-76-07839",
+            "upc": "00602537518357"
+        }))
+        .unwrap();
+        assert_eq!(ids.isrc.as_deref(), Some("USRC17607839"));
+        assert_eq!(ids.upc, None);
+
+        assert!(parse_external_ids(&serde_json::json!({ "isrc": "not an isrc" })).is_none());
+        assert!(parse_external_ids(&serde_json::json!({})).is_none());
+    }
 }
@@ -0,0 +1,156 @@
+//! Uploaded music parsing.
+
+use serde_json::Value;
+
+use crate::continuation::get_continuation_token;
+use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::library::{
+    library_shelf_rows, parse_library_album_items, parse_library_track_artist_rows,
+};
+use crate::parsers::navigation::paths;
+use crate::parsers::playlist::{find_library_grid, parse_thumbnails};
+use crate::parsers::track::{
+    get_fixed_column_item, get_item_text, parse_duration, parse_like_status, parse_song_album,
+    parse_song_artists,
+};
+use crate::types::{UploadAlbum, UploadArtist, UploadTrack};
+
+/// Prefix of upload album browse IDs; the rest is the album's entity ID.
+pub const UPLOAD_ALBUM_PREFIX: &str = "FEmusic_library_privately_owned_release_detail";
+
+/// Parse the first page of uploaded songs.
+///
+/// Returns the tracks and the continuation token. No uploads yield no
+/// items.
+pub fn parse_upload_songs(response: &Value) -> (Vec<UploadTrack>, Option<String>) {
+    let (rows, token) = library_shelf_rows(response);
+    (parse_upload_song_rows(rows), token)
+}
+
+/// Parse uploaded song rows. Rows without a delete action, such as the
+/// "Shuffle all" row heading the first page, are skipped.
+pub fn parse_upload_song_rows(rows: &[Value]) -> Vec<UploadTrack> {
+    rows.iter()
+        .filter_map(|row| parse_upload_track(row.get(paths::MRLIR)?))
+        .collect()
+}
+
+/// Parse an upload row: title, artists and album in flex columns 0 to 2
+/// and the duration in fixed column 0. Unlike playlist rows there is no
+/// video type or badge, and the video ID may only be in the menu.
+fn parse_upload_track(data: &Value) -> Option<UploadTrack> {
+    let menu_items = nav_array(data, paths::MENU_ITEMS)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let entity_id = menu_items.iter().find_map(|item| {
+        nav_str(
+            item,
+            &path![
+                "menuNavigationItemRenderer",
+                "navigationEndpoint",
+                "confirmDialogEndpoint",
+                "content",
+                "confirmDialogRenderer",
+                "confirmButton",
+                "buttonRenderer",
+                "command",
+                "musicDeletePrivatelyOwnedEntityCommand",
+                "entityId"
+            ],
+        )
+    })?;
+
+    let video_id = nav(data, paths::PLAY_BUTTON)
+        .and_then(|button| {
+            nav_str(
+                button,
+                &path!["playNavigationEndpoint", "watchEndpoint", "videoId"],
+            )
+        })
+        .or_else(|| {
+            menu_items.iter().find_map(|item| {
+                nav_str(
+                    item,
+                    &path![
+                        "menuServiceItemRenderer",
+                        "serviceEndpoint",
+                        "queueAddEndpoint",
+                        "queueTarget",
+                        "videoId"
+                    ],
+                )
+            })
+        });
+    let duration = get_fixed_column_item(data, 0).and_then(|column| {
+        nav_str(column, &path!["text", "runs", 0, "text"])
+            .or_else(|| nav_str(column, &path!["text", "simpleText"]))
+    });
+
+    Some(UploadTrack {
+        entity_id: entity_id.to_string(),
+        video_id: video_id.map(str::to_string),
+        title: get_item_text(data, 0)?.to_string(),
+        artists: parse_song_artists(data, 1),
+        album: parse_song_album(data, 2),
+        duration: duration.map(str::to_string),
+        duration_seconds: duration.and_then(parse_duration),
+        thumbnails: parse_thumbnails(data),
+        like_status: nav_str(data, paths::MENU_LIKE_STATUS).and_then(parse_like_status),
+    })
+}
+
+/// Parse the first page of upload albums.
+///
+/// Returns the albums and the continuation token.
+pub fn parse_upload_albums(response: &Value) -> (Vec<UploadAlbum>, Option<String>) {
+    let Some(grid) = find_library_grid(response) else {
+        return (Vec::new(), None);
+    };
+    let items = nav_array(grid, &path!["items"])
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    (
+        parse_upload_album_items(items),
+        get_continuation_token(grid),
+    )
+}
+
+/// Parse upload album tiles, which have the layout of library album tiles.
+pub fn parse_upload_album_items(items: &[Value]) -> Vec<UploadAlbum> {
+    parse_library_album_items(items)
+        .into_iter()
+        .filter_map(|album| {
+            let entity_id = album.browse_id.strip_prefix(UPLOAD_ALBUM_PREFIX)?;
+            Some(UploadAlbum {
+                entity_id: entity_id.to_string(),
+                browse_id: album.browse_id.clone(),
+                title: album.title,
+                artists: album.artists,
+                year: album.year,
+                thumbnails: album.thumbnails,
+            })
+        })
+        .collect()
+}
+
+/// Parse the first page of upload artists.
+///
+/// Returns the artists and the continuation token.
+pub fn parse_upload_artists(response: &Value) -> (Vec<UploadArtist>, Option<String>) {
+    let (rows, token) = library_shelf_rows(response);
+    (parse_upload_artist_rows(rows), token)
+}
+
+/// Parse upload artist rows, which have the layout of the library's
+/// Artists tab: the name and a song count.
+pub fn parse_upload_artist_rows(rows: &[Value]) -> Vec<UploadArtist> {
+    parse_library_track_artist_rows(rows)
+        .into_iter()
+        .map(|artist| UploadArtist {
+            name: artist.name,
+            browse_id: artist.browse_id,
+            songs: artist.songs,
+            thumbnails: artist.thumbnails,
+        })
+        .collect()
+}
@@ -0,0 +1,96 @@
+//! User channel parsing.
+
+use serde_json::Value;
+
+use crate::continuation::get_continuation_token;
+use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::artist::{page_type, parse_video, section_from};
+use crate::parsers::navigation::paths;
+use crate::parsers::playlist::{parse_library_playlist_items, parse_thumbnails};
+use crate::types::{PlaylistSummary, UserPage};
+
+/// Parse a user channel `browse` response.
+///
+/// Like artist pages, carousels are classified by what their items link to.
+pub fn parse_user_page(response: &Value, channel_id: &str) -> UserPage {
+    let mut user = UserPage {
+        channel_id: channel_id.to_string(),
+        ..Default::default()
+    };
+
+    if let Some(header) = nav(response, paths::HEADER).and_then(|h| {
+        h.get("musicVisualHeaderRenderer")
+            .or_else(|| h.get("musicImmersiveHeaderRenderer"))
+    }) {
+        user.name = nav_str(header, paths::TITLE_TEXT)
+            .unwrap_or_default()
+            .to_string();
+        user.thumbnails = parse_thumbnails(header);
+    }
+
+    for carousel in sections(response).filter_map(|s| s.get("musicCarouselShelfRenderer")) {
+        let title_run = nav(
+            carousel,
+            &path![
+                "header",
+                "musicCarouselShelfBasicHeaderRenderer",
+                "title",
+                "runs",
+                0
+            ],
+        );
+        let contents = nav_array(carousel, &path!["contents"])
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let Some(first) = contents.first().and_then(|item| item.get(paths::MTRIR)) else {
+            continue;
+        };
+
+        if nav(first, &path!["navigationEndpoint", "watchEndpoint"]).is_some() {
+            if user.videos.is_none() {
+                let videos = contents
+                    .iter()
+                    .filter_map(|item| parse_video(item.get(paths::MTRIR)?))
+                    .collect();
+                user.videos = Some(section_from(title_run, videos));
+            }
+        } else if page_type(first) == Some("MUSIC_PAGE_TYPE_PLAYLIST") && user.playlists.is_none() {
+            user.playlists = Some(section_from(
+                title_run,
+                parse_library_playlist_items(contents),
+            ));
+        }
+    }
+
+    user
+}
+
+/// Parse the first page of a user's full playlist listing.
+///
+/// A channel without public playlists yields no items rather than an error.
+/// Returns the playlists and the continuation token.
+pub fn parse_user_playlists(response: &Value) -> (Vec<PlaylistSummary>, Option<String>) {
+    let Some(grid) = sections(response).find_map(|s| {
+        s.get("gridRenderer")
+            .or_else(|| s.get("musicCarouselShelfRenderer"))
+    }) else {
+        return (Vec::new(), None);
+    };
+
+    let items = nav_array(grid, &path!["items"])
+        .or_else(|| nav_array(grid, &path!["contents"]))
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    (
+        parse_library_playlist_items(items),
+        get_continuation_token(grid),
+    )
+}
+
+fn sections(response: &Value) -> impl Iterator<Item = &Value> {
+    nav(response, paths::SINGLE_COLUMN)
+        .and_then(|v| nav(v, paths::TAB_CONTENT))
+        .and_then(|v| nav_array(v, paths::SECTION_LIST))
+        .into_iter()
+        .flatten()
+}
@@ -0,0 +1,281 @@
+//! Watch queue (`next` endpoint) parsing.
+
+use serde_json::Value;
+
+use crate::nav::{nav, nav_array, nav_str};
+use crate::parsers::navigation::paths;
+use crate::parsers::playlist::parse_thumbnails;
+use crate::parsers::search::{SUBTITLE_SEPARATOR, is_artist_run, parse_carousel_items};
+use crate::parsers::track::{parse_artist_runs, parse_duration, parse_like_status};
+use crate::types::{
+    Album, LikeStatus, MusicVideoType, PlaylistTrack, RelatedSection, WatchPlaylist,
+};
+
+/// Parse a `next` response into the queue and the browse IDs of its tabs,
+/// with the token for more of the queue.
+pub fn parse_watch_playlist(response: &Value) -> (WatchPlaylist, Option<String>) {
+    let tabs = nav_array(
+        response,
+        &path![
+            "contents",
+            "singleColumnMusicWatchNextResultsRenderer",
+            "tabbedRenderer",
+            "watchNextTabbedResultsRenderer",
+            "tabs"
+        ],
+    )
+    .map(Vec::as_slice)
+    .unwrap_or_default();
+    let panel = tabs.first().and_then(|tab| {
+        nav(
+            tab,
+            &path![
+                "tabRenderer",
+                "content",
+                "musicQueueRenderer",
+                "content",
+                "playlistPanelRenderer"
+            ],
+        )
+    });
+    let rows = panel
+        .and_then(|panel| nav_array(panel, &path!["contents"]))
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let mut tracks = parse_watch_rows(rows);
+    let lyrics_browse_id = tab_browse_id(tabs, 1);
+    // The lyrics tab belongs to the selected track, which the queue starts
+    // with unless a row says otherwise.
+    let current = rows
+        .iter()
+        .filter_map(panel_video)
+        .find(|video| video.get("selected").and_then(Value::as_bool) == Some(true))
+        .and_then(|video| nav_str(video, &path!["videoId"]));
+    let current = match current {
+        Some(video_id) => tracks
+            .iter_mut()
+            .find(|track| track.video_id.as_deref() == Some(video_id)),
+        None => tracks.first_mut(),
+    };
+    if let Some(track) = current {
+        track.has_lyrics = lyrics_browse_id.is_some();
+    }
+    let playlist = WatchPlaylist {
+        playlist_id: panel
+            .and_then(|panel| nav_str(panel, &path!["playlistId"]))
+            .or_else(|| rows.iter().find_map(row_playlist_id))
+            .map(str::to_string),
+        lyrics_browse_id,
+        related_browse_id: tab_browse_id(tabs, 2),
+        automix_playlist_id: rows
+            .iter()
+            .find_map(automix_playlist_id)
+            .map(str::to_string),
+        tracks,
+    };
+    (playlist, panel.and_then(panel_continuation))
+}
+
+/// Locate the rows and next token of a `playlistPanelContinuation` response.
+pub fn watch_continuation_rows(response: &Value) -> (&[Value], Option<String>) {
+    match nav(
+        response,
+        &path!["continuationContents", "playlistPanelContinuation"],
+    ) {
+        Some(panel) => (
+            nav_array(panel, &path!["contents"])
+                .map(Vec::as_slice)
+                .unwrap_or_default(),
+            panel_continuation(panel),
+        ),
+        None => (&[], None),
+    }
+}
+
+/// Parse the browse response behind a related tab (`MPTR...`).
+///
+/// Sections are kept in page order with their headings. Carousels without
+/// any item this crate can read are skipped.
+pub fn parse_song_related(response: &Value) -> Vec<RelatedSection> {
+    nav_array(
+        response,
+        &path!["contents", "sectionListRenderer", "contents"],
+    )
+    .into_iter()
+    .flatten()
+    .filter_map(parse_related_section)
+    .collect()
+}
+
+fn parse_related_section(section: &Value) -> Option<RelatedSection> {
+    if let Some(carousel) = section.get("musicCarouselShelfRenderer") {
+        let items = nav_array(carousel, &path!["contents"])
+            .map(|items| parse_carousel_items(items))
+            .unwrap_or_default();
+        if items.is_empty() {
+            return None;
+        }
+        return Some(RelatedSection {
+            title: nav_str(
+                carousel,
+                &path![
+                    "header",
+                    "musicCarouselShelfBasicHeaderRenderer",
+                    "title",
+                    "runs",
+                    0,
+                    "text"
+                ],
+            )
+            .unwrap_or_default()
+            .to_string(),
+            items,
+            description: None,
+        });
+    }
+
+    let shelf = nav(section, paths::DESCRIPTION_SHELF)?;
+    let description: String = nav_array(shelf, &path!["description", "runs"])?
+        .iter()
+        .filter_map(|run| nav_str(run, &path!["text"]))
+        .collect();
+    Some(RelatedSection {
+        title: nav_str(shelf, &path!["header", "runs", 0, "text"])
+            .unwrap_or_default()
+            .to_string(),
+        items: Vec::new(),
+        description: Some(description),
+    })
+}
+
+/// Read the like status of each queue item of a `music/get_queue` response,
+/// by video ID. Items without a video ID or status are skipped.
+pub fn parse_queue_like_statuses(response: &Value) -> Vec<(String, LikeStatus)> {
+    nav_array(response, &path!["queueDatas"])
+        .into_iter()
+        .flatten()
+        .filter_map(|data| panel_video(data.get("content")?))
+        .filter_map(|video| {
+            Some((
+                nav_str(video, &path!["videoId"])?.to_string(),
+                parse_like_status(nav_str(video, paths::MENU_LIKE_STATUS)?)?,
+            ))
+        })
+        .collect()
+}
+
+/// Parse queue rows. Rows other than queued videos, such as the automix
+/// preview, are skipped.
+pub fn parse_watch_rows(rows: &[Value]) -> Vec<PlaylistTrack> {
+    rows.iter().filter_map(parse_watch_track).collect()
+}
+
+/// Parse a `playlistPanelVideoRenderer` row, unwrapping the wrapper used for
+/// tracks that have a music video counterpart.
+fn parse_watch_track(row: &Value) -> Option<PlaylistTrack> {
+    let data = panel_video(row)?;
+    let mut track = PlaylistTrack {
+        video_id: nav_str(data, &path!["videoId"]).map(str::to_string),
+        title: nav_str(data, paths::TITLE_TEXT).map(str::to_string),
+        set_video_id: nav_str(data, &path!["playlistSetVideoId"]).map(str::to_string),
+        thumbnails: parse_thumbnails(data),
+        is_available: data.get("unplayableText").is_none(),
+        is_explicit: nav(data, paths::BADGE_LABEL).is_some(),
+        video_type: nav_str(
+            data,
+            &path![
+                "navigationEndpoint",
+                "watchEndpoint",
+                "watchEndpointMusicSupportedConfigs",
+                "watchEndpointMusicConfig",
+                "musicVideoType"
+            ],
+        )
+        .map(MusicVideoType::from),
+        ..Default::default()
+    };
+    if track.video_id.is_none() && track.title.is_none() {
+        return None;
+    }
+
+    if let Some(length) = nav_str(data, &path!["lengthText", "runs", 0, "text"]) {
+        track.duration = Some(length.to_string());
+        track.duration_seconds = parse_duration(length);
+    }
+
+    // The byline reads "Artist • Album • Year" for songs and
+    // "Artist • Views • Likes" for videos.
+    let byline = nav_array(data, &path!["longBylineText", "runs"])
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    for (index, group) in byline
+        .split(|run| nav_str(run, &path!["text"]) == Some(SUBTITLE_SEPARATOR))
+        .enumerate()
+    {
+        if track.album.is_none()
+            && let Some(run) = group.iter().find(|run| {
+                nav_str(run, paths::NAVIGATION_BROWSE_ID).is_some_and(|id| id.starts_with("MPRE"))
+            })
+        {
+            track.album = Some(Album {
+                name: nav_str(run, &path!["text"]).unwrap_or_default().to_string(),
+                id: nav_str(run, paths::NAVIGATION_BROWSE_ID).map(str::to_string),
+            });
+        } else if track.artists.is_empty() && (index == 0 || group.iter().any(is_artist_run)) {
+            track.artists = parse_artist_runs(group);
+        }
+    }
+
+    Some(track)
+}
+
+fn panel_video(row: &Value) -> Option<&Value> {
+    row.get("playlistPanelVideoRenderer").or_else(|| {
+        nav(
+            row,
+            &path![
+                "playlistPanelVideoWrapperRenderer",
+                "primaryRenderer",
+                "playlistPanelVideoRenderer"
+            ],
+        )
+    })
+}
+
+fn row_playlist_id(row: &Value) -> Option<&str> {
+    nav_str(panel_video(row)?, paths::NAVIGATION_PLAYLIST_ID)
+}
+
+fn automix_playlist_id(row: &Value) -> Option<&str> {
+    nav_str(
+        row,
+        &path![
+            "automixPreviewVideoRenderer",
+            "content",
+            "automixPlaylistVideoRenderer",
+            "navigationEndpoint",
+            "watchPlaylistEndpoint",
+            "playlistId"
+        ],
+    )
+}
+
+/// Browse ID behind the tab at `index`, unless the tab is disabled (as the
+/// lyrics tab is for tracks without lyrics).
+fn tab_browse_id(tabs: &[Value], index: usize) -> Option<String> {
+    let tab = tabs.get(index)?.get("tabRenderer")?;
+    if tab.get("unselectable").is_some() {
+        return None;
+    }
+    nav_str(tab, &path!["endpoint", "browseEndpoint", "browseId"]).map(str::to_string)
+}
+
+/// Token for more of the queue. Radio queues are endless and use
+/// `nextRadioContinuationData`.
+fn panel_continuation(panel: &Value) -> Option<String> {
+    ["nextRadioContinuationData", "nextContinuationData"]
+        .into_iter()
+        .find_map(|key| nav_str(panel, &path!["continuations", 0, key, "continuation"]))
+        .map(str::to_string)
+}
@@ -0,0 +1,144 @@
+//! Named clients for several accounts (requires the `pool` feature).
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use crate::auth::BrowserAuth;
+use crate::client::{YTMusicClient, YTMusicClientBuilder, http_client};
+use crate::error::{Error, Result};
+use crate::rate_limit::RateLimiter;
+
+/// A set of named clients sharing one connection pool and, optionally, one
+/// rate limit.
+///
+/// Accounts are registered up front; each client is built on first use.
+/// With [`with_min_interval`](Self::with_min_interval), requests from all
+/// accounts are paced together, so running several accounts from one machine
+/// does not multiply the request rate.
+///
+/// ```no_run
+/// # async fn demo(alice: ytmusicapi::BrowserAuth, bob: ytmusicapi::BrowserAuth) -> ytmusicapi::Result<()> {
+/// use std::time::Duration;
+/// use ytmusicapi::pool::ClientPool;
+///
+/// let mut pool = ClientPool::new()?.with_min_interval(Duration::from_millis(250));
+/// pool.register("alice", alice)?;
+/// pool.register("bob", bob)?;
+///
+/// let playlists = pool.get("alice")?.get_library_playlists(None).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ClientPool {
+    http: reqwest::Client,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    accounts: HashMap<String, Account>,
+}
+
+struct Account {
+    builder: YTMusicClientBuilder,
+    client: OnceLock<YTMusicClient>,
+}
+
+impl ClientPool {
+    /// Create an empty pool without a rate limit.
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            http: http_client()?,
+            rate_limiter: None,
+            accounts: HashMap::new(),
+        })
+    }
+
+    /// Space all requests of the pool's clients at least `interval` apart.
+    pub fn with_min_interval(mut self, interval: Duration) -> Self {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(interval)));
+        self
+    }
+
+    /// Register an account with default client options.
+    ///
+    /// Replaces any account of the same name.
+    pub fn register(&mut self, name: impl Into<String>, auth: BrowserAuth) -> Result<()> {
+        self.register_builder(name, YTMusicClient::builder().with_browser_auth(auth))
+    }
+
+    /// Register an account with client options of its own.
+    ///
+    /// The pool's connection pool and rate limiter replace the builder's.
+    /// Returns [`Error::Config`] if the options are invalid. Replaces any
+    /// account of the same name.
+    pub fn register_builder(
+        &mut self,
+        name: impl Into<String>,
+        builder: YTMusicClientBuilder,
+    ) -> Result<()> {
+        let errors = builder.validate();
+        if !errors.is_empty() {
+            return Err(Error::Config(errors));
+        }
+        let mut builder = builder.with_http_client(self.http.clone());
+        if let Some(limiter) = &self.rate_limiter {
+            builder = builder.with_rate_limiter(limiter.clone());
+        }
+        self.accounts.insert(
+            name.into(),
+            Account {
+                builder,
+                client: OnceLock::new(),
+            },
+        );
+        Ok(())
+    }
+
+    /// The client of account `name`, built on first use.
+    ///
+    /// Returns [`Error::InvalidInput`] if no such account is registered.
+    pub fn get(&self, name: &str) -> Result<&YTMusicClient> {
+        let account = self
+            .accounts
+            .get(name)
+            .ok_or_else(|| Error::InvalidInput(format!("No account named {name:?} in the pool")))?;
+        if let Some(client) = account.client.get() {
+            return Ok(client);
+        }
+        let client = account.builder.clone().build()?;
+        Ok(account.client.get_or_init(|| client))
+    }
+
+    /// Names of the registered accounts, in no particular order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.accounts.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_builds_each_client_once() {
+        let mut pool = ClientPool::new()
+            .unwrap()
+            .with_min_interval(Duration::from_millis(10));
+        pool.register_builder("alice", YTMusicClient::builder())
+            .unwrap();
+
+        let first = pool.get("alice").unwrap();
+        let second = pool.get("alice").unwrap();
+        assert!(std::ptr::eq(first, second));
+        assert!(matches!(pool.get("bob"), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_register_rejects_invalid_options() {
+        let mut pool = ClientPool::new().unwrap();
+        let builder = YTMusicClient::builder().with_location("USA");
+        assert!(matches!(
+            pool.register_builder("alice", builder),
+            Err(Error::Config(_))
+        ));
+        assert_eq!(pool.names().count(), 0);
+    }
+}
@@ -0,0 +1,79 @@
+//! Request pacing shared between clients.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Spaces out requests by a minimum interval.
+///
+/// One limiter can be shared between several clients (see
+/// [`YTMusicClientBuilder::with_rate_limiter`](crate::YTMusicClientBuilder::with_rate_limiter)),
+/// which then pace their requests together, e.g. to keep several accounts on
+/// one machine under an aggregate rate. Requests are released in the order
+/// they arrive.
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// use ytmusicapi::{RateLimiter, YTMusicClient};
+///
+/// let limiter = Arc::new(RateLimiter::new(Duration::from_millis(500)));
+/// let client = YTMusicClient::builder()
+///     .with_rate_limiter(limiter.clone())
+///     .build()?;
+/// # Ok::<(), ytmusicapi::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    next: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    /// Allow one request per `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next: Mutex::new(None),
+        }
+    }
+
+    /// The minimum interval between requests.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Wait for this request's turn.
+    pub(crate) async fn acquire(&self) {
+        let slot = self.reserve(Instant::now());
+        tokio::time::sleep_until(slot.into()).await;
+    }
+
+    /// Claim the earliest free slot at or after `now`.
+    fn reserve(&self, now: Instant) -> Instant {
+        let mut next = self.next.lock().unwrap_or_else(|e| e.into_inner());
+        let slot = next.map_or(now, |next| next.max(now));
+        *next = Some(slot + self.interval);
+        slot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve_spaces_requests() {
+        let limiter = RateLimiter::new(Duration::from_secs(1));
+        let start = Instant::now();
+
+        assert_eq!(limiter.reserve(start), start);
+        assert_eq!(limiter.reserve(start), start + Duration::from_secs(1));
+        assert_eq!(limiter.reserve(start), start + Duration::from_secs(2));
+
+        // After an idle period the next request goes out at once.
+        let later = start + Duration::from_secs(10);
+        assert_eq!(limiter.reserve(later), later);
+        assert_eq!(limiter.reserve(later), later + Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,200 @@
+//! Answering requests from recorded responses instead of the network.
+//!
+//! A fixture directory holds one JSON response body per request, at
+//! `<endpoint>/<key>.json`. The key is the ID the request is about (its
+//! continuation token, browse ID, video ID, playlist ID, upload entity ID or
+//! search query, whichever comes first), with characters other than ASCII letters,
+//! digits, `-` and `_` replaced by `_`. Requests without a recorded response
+//! for their key fall back to `<endpoint>/default.json`.
+
+use std::io;
+use std::path::{Path, PathBuf};
+#[cfg(test)]
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+use crate::error::Result;
+#[cfg(test)]
+use crate::{BrowserAuth, YTMusicClient, YTMusicClientBuilder};
+
+/// Request fields identifying what a request is about, most specific first.
+const KEY_FIELDS: [&str; 6] = [
+    "continuation",
+    "browseId",
+    "videoId",
+    "playlistId",
+    "entityId",
+    "query",
+];
+
+/// Serves recorded responses from a fixture directory.
+#[derive(Debug, Clone)]
+pub(crate) struct FixtureTransport {
+    dir: PathBuf,
+    /// Endpoint and body of every request so far, for tests to inspect.
+    #[cfg(test)]
+    sent: Arc<Mutex<Vec<(String, Value)>>>,
+}
+
+impl FixtureTransport {
+    pub(crate) fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            #[cfg(test)]
+            sent: Arc::default(),
+        }
+    }
+
+    /// Endpoint and body of every request answered or refused so far.
+    #[cfg(test)]
+    pub(crate) fn sent(&self) -> Vec<(String, Value)> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    /// The recorded response body for `body` posted to `endpoint`.
+    ///
+    /// Returns [`Error::Io`](crate::Error::Io) naming the files looked for if
+    /// no response is recorded.
+    pub(crate) fn respond(&self, endpoint: &str, body: &Value) -> Result<String> {
+        #[cfg(test)]
+        self.sent
+            .lock()
+            .unwrap()
+            .push((endpoint.to_string(), body.clone()));
+        let candidates = response_paths(&self.dir, endpoint, body);
+        let Some(path) = candidates.iter().find(|path| path.is_file()) else {
+            let tried: Vec<_> = candidates.iter().map(|p| p.display().to_string()).collect();
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no recorded response at {}", tried.join(" or ")),
+            )
+            .into());
+        };
+        Ok(std::fs::read_to_string(path)?)
+    }
+}
+
+/// Files that may hold the response for `body` posted to `endpoint`, in the
+/// order they are tried.
+fn response_paths(dir: &Path, endpoint: &str, body: &Value) -> Vec<PathBuf> {
+    let endpoint_dir = dir.join(endpoint);
+    request_key(body)
+        .map(|key| endpoint_dir.join(format!("{key}.json")))
+        .into_iter()
+        .chain([endpoint_dir.join("default.json")])
+        .collect()
+}
+
+fn request_key(body: &Value) -> Option<String> {
+    let key = KEY_FIELDS
+        .iter()
+        .find_map(|field| body.get(field).and_then(Value::as_str))?;
+    Some(
+        key.chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect(),
+    )
+}
+
+/// A fresh directory for one test, removed when dropped.
+#[cfg(test)]
+pub(crate) struct ScratchDir(PathBuf);
+
+#[cfg(test)]
+impl ScratchDir {
+    /// Create an empty directory named after `test` and this process, so
+    /// concurrent test runs never share it.
+    pub(crate) fn new(test: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("ytmusicapi-{test}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Write `contents` to `relative`, creating its parent directories.
+    pub(crate) fn write(&self, relative: &str, contents: &str) {
+        let path = self.0.join(relative);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+}
+
+#[cfg(test)]
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Credentials that satisfy authenticated methods, for clients that never
+/// reach the network.
+#[cfg(test)]
+pub(crate) fn test_auth() -> BrowserAuth {
+    BrowserAuth::from_json(r#"{"cookie": "__Secure-3PAPISID=abc"}"#).unwrap()
+}
+
+/// A builder for an authenticated client answering from `dir`, for tests
+/// that need further options; see [`fixture_client`].
+#[cfg(test)]
+pub(crate) fn fixture_builder(dir: &ScratchDir) -> YTMusicClientBuilder {
+    YTMusicClient::builder()
+        .with_browser_auth(test_auth())
+        .with_fixture_dir(dir.path())
+}
+
+/// An authenticated client answering from the responses recorded in `dir`.
+#[cfg(test)]
+pub(crate) fn fixture_client(dir: &ScratchDir) -> YTMusicClient {
+    fixture_builder(dir).build().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn request_key_prefers_the_most_specific_field() {
+        let body = json!({ "browseId": "VLPL1", "continuation": "4qmF%3D" });
+        assert_eq!(request_key(&body).as_deref(), Some("4qmF_3D"));
+        assert_eq!(
+            request_key(&json!({ "query": "daft punk" })).as_deref(),
+            Some("daft_punk")
+        );
+        assert_eq!(request_key(&json!({ "context": {} })), None);
+    }
+
+    #[test]
+    fn respond_falls_back_to_the_default() {
+        let dir = ScratchDir::new("transport");
+        dir.write("browse/VLPL1.json", "{\"keyed\":true}");
+        dir.write("browse/default.json", "{}");
+        let transport = FixtureTransport::new(dir.path());
+
+        let keyed = transport.respond("browse", &json!({ "browseId": "VLPL1" }));
+        assert_eq!(keyed.unwrap(), "{\"keyed\":true}");
+        let other = transport.respond("browse", &json!({ "browseId": "VLPL2" }));
+        assert_eq!(other.unwrap(), "{}");
+
+        let error = transport
+            .respond("next", &json!({ "videoId": "v1" }))
+            .unwrap_err();
+        let message = error.to_string();
+        assert!(
+            message.contains("v1.json") && message.contains("default.json"),
+            "{message}"
+        );
+    }
+}
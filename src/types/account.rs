@@ -0,0 +1,44 @@
+//! Account types.
+
+use serde::{Deserialize, Serialize};
+
+use super::Thumbnail;
+
+/// What the authenticated account can do, as far as the account menu reveals.
+///
+/// Returned by [`YTMusicClient::get_capabilities`](crate::YTMusicClient::get_capabilities).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// Whether the account has a YouTube channel. Playlist creation fails
+    /// without one.
+    pub has_channel: bool,
+    /// Whether the account has YouTube Music Premium, if the menu shows a
+    /// membership link or a Premium upsell.
+    pub is_premium: Option<bool>,
+}
+
+/// Whether the configured credentials are still accepted.
+///
+/// Returned by [`YTMusicClient::probe_auth`](crate::YTMusicClient::probe_auth).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthState {
+    /// The account menu shows the signed-in account.
+    Valid,
+    /// The cookies were rejected, signed out, or are waiting on an account
+    /// challenge. Export fresh headers from the browser.
+    Expired,
+}
+
+/// The signed-in account, as shown in the account menu.
+///
+/// Returned by [`YTMusicClient::get_account_info`](crate::YTMusicClient::get_account_info).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AccountInfo {
+    /// Display name of the account.
+    pub name: String,
+    /// Channel handle, such as `@name`, if the account has a channel.
+    pub channel_handle: Option<String>,
+    /// Avatar in several sizes.
+    pub thumbnails: Vec<Thumbnail>,
+}
@@ -0,0 +1,112 @@
+//! Album page types.
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Artist, DescriptionRun, ExternalIds, LikeStatus, MusicVideoType, Thumbnail, TrackLike,
+};
+
+/// An album, single or EP with its track listing.
+///
+/// Returned by [`YTMusicClient::get_album`](crate::YTMusicClient::get_album).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AlbumPage {
+    /// Album browse ID (`MPREb_...`).
+    pub browse_id: String,
+    /// Album title.
+    pub title: String,
+    /// Release type label as shown (e.g., `"Album"`, `"EP"`, `"Single"`), in
+    /// the client language.
+    pub album_type: Option<String>,
+    /// Release year, if shown.
+    pub year: Option<u16>,
+    /// Album artists.
+    pub artists: Vec<Artist>,
+    /// Description, with all runs joined.
+    pub description: Option<String>,
+    /// Description runs, preserving links.
+    pub description_runs: Vec<DescriptionRun>,
+    /// Human-readable duration (e.g., `"42 minutes"`), if present.
+    pub duration: Option<String>,
+    /// Total duration in seconds, computed from parsed tracks.
+    pub duration_seconds: Option<u32>,
+    /// Number of tracks shown in the header, if present.
+    pub track_count: Option<u32>,
+    /// Cover images.
+    pub thumbnails: Vec<Thumbnail>,
+    /// Whether the album is marked explicit.
+    pub is_explicit: bool,
+    /// Playlist ID (`OLAK5uy_...`) that plays the album, if present.
+    pub audio_playlist_id: Option<String>,
+    /// Playlist ID behind the header's "Shuffle play" menu item, if present.
+    #[serde(default)]
+    pub shuffle_playlist_id: Option<String>,
+    /// Playlist ID (`RDAMPL...`) of the album radio, if the header menu
+    /// offers one.
+    #[serde(default)]
+    pub radio_playlist_id: Option<String>,
+    /// Tracks in album order.
+    pub tracks: Vec<AlbumTrack>,
+}
+
+/// A track on an album page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlbumTrack {
+    /// Video ID (used for playback), if available.
+    pub video_id: Option<String>,
+    /// Track title.
+    pub title: String,
+    /// Position on the album, or on its disc for albums with several
+    /// discs, if shown.
+    pub track_number: Option<u32>,
+    /// Disc the track is on, counted from 1; `None` for single-disc albums.
+    #[serde(default)]
+    pub disc: Option<u32>,
+    /// Track artists. Rows only name artists that differ from the album's, so
+    /// otherwise these are the album artists.
+    pub artists: Vec<Artist>,
+    /// Human-readable duration (e.g., `"3:42"`), if available.
+    pub duration: Option<String>,
+    /// Duration in seconds, if parsed successfully.
+    pub duration_seconds: Option<u32>,
+    /// Whether the track is available for playback.
+    pub is_available: bool,
+    /// Whether the track has explicit content.
+    pub is_explicit: bool,
+    /// The signed-in user's rating, if the row shows a like button.
+    pub like_status: Option<LikeStatus>,
+    /// Type of video, if available.
+    pub video_type: Option<MusicVideoType>,
+    /// ISRC and similar identifiers, when the row carries them.
+    pub external_ids: Option<ExternalIds>,
+}
+
+impl TrackLike for AlbumTrack {
+    fn video_id(&self) -> Option<&str> {
+        self.video_id.as_deref()
+    }
+
+    fn title(&self) -> Option<&str> {
+        Some(&self.title)
+    }
+
+    fn artists(&self) -> &[Artist] {
+        &self.artists
+    }
+
+    fn duration_seconds(&self) -> Option<u32> {
+        self.duration_seconds
+    }
+
+    fn is_explicit(&self) -> Option<bool> {
+        Some(self.is_explicit)
+    }
+
+    fn is_available(&self) -> Option<bool> {
+        Some(self.is_available)
+    }
+
+    fn video_type(&self) -> Option<&MusicVideoType> {
+        self.video_type.as_ref()
+    }
+}
@@ -0,0 +1,118 @@
+//! Artist page types.
+
+use serde::{Deserialize, Serialize};
+
+use super::{MusicVideoType, PlaylistTrack, Thumbnail};
+
+/// An artist's channel page.
+///
+/// Returned by [`YTMusicClient::get_artist`](crate::YTMusicClient::get_artist).
+/// Sections the page does not show are `None`; small artists often have no
+/// videos or singles.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArtistPage {
+    /// Channel ID (`UC...`).
+    pub channel_id: String,
+    /// Artist name.
+    pub name: String,
+    /// Biography, if shown.
+    pub description: Option<String>,
+    /// Subscriber count, parsed from the abbreviated label (e.g., `"1.2M"`).
+    pub subscribers: Option<u64>,
+    /// Whether the signed-in user is subscribed. Always `false` without
+    /// authentication.
+    pub subscribed: bool,
+    /// Header images.
+    pub thumbnails: Vec<Thumbnail>,
+    /// Top songs.
+    pub songs: Option<ArtistSection<PlaylistTrack>>,
+    /// Albums.
+    pub albums: Option<ArtistSection<ArtistRelease>>,
+    /// Singles and EPs.
+    pub singles: Option<ArtistSection<ArtistRelease>>,
+    /// Music videos.
+    pub videos: Option<ArtistSection<ArtistVideo>>,
+    /// Similar artists ("Fans might also like").
+    pub related: Option<ArtistSection<RelatedArtist>>,
+}
+
+/// One section of an artist or user page.
+///
+/// The page shows only the first items; `browse_id` and `params` identify
+/// the full listing behind the section's "See all" link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtistSection<T> {
+    /// Section heading as shown, in the client language.
+    pub title: String,
+    /// Browse ID of the full listing, if the section links to one.
+    pub browse_id: Option<String>,
+    /// Browse `params` of the full listing, if any.
+    pub params: Option<String>,
+    /// Items shown on the page.
+    pub items: Vec<T>,
+}
+
+/// An album, single or EP on an artist page or in an artist's discography.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtistRelease {
+    /// Release title.
+    pub title: String,
+    /// Album browse ID (`MPREb_...`).
+    pub browse_id: String,
+    /// Release year, if shown.
+    pub year: Option<u16>,
+    /// Release type label as shown (e.g., `"Album"`, `"EP"`, `"Single"`), in
+    /// the client language. Artist page carousels often omit it.
+    pub release_type: Option<String>,
+    /// Cover images.
+    pub thumbnails: Vec<Thumbnail>,
+    /// Whether the release is marked explicit.
+    pub is_explicit: bool,
+}
+
+/// A video on an artist page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtistVideo {
+    /// Video title.
+    pub title: String,
+    /// Video ID.
+    pub video_id: String,
+    /// View count, if shown.
+    pub views: Option<u64>,
+    /// Thumbnail images.
+    pub thumbnails: Vec<Thumbnail>,
+    /// Kind of video, if known.
+    pub video_type: Option<MusicVideoType>,
+}
+
+/// A related artist on an artist page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelatedArtist {
+    /// Artist name.
+    pub name: String,
+    /// Channel ID (`UC...`).
+    pub browse_id: String,
+    /// Subscriber count, if shown.
+    pub subscribers: Option<u64>,
+    /// Profile images.
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+/// Outcome of subscribing to or unsubscribing from artists.
+///
+/// Returned by [`YTMusicClient::subscribe_artists`](crate::YTMusicClient::subscribe_artists)
+/// and [`YTMusicClient::unsubscribe_artists`](crate::YTMusicClient::unsubscribe_artists).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubscriptionResult {
+    /// Channel IDs the response confirmed in the requested state.
+    pub succeeded: Vec<String>,
+    /// Channel IDs the response did not confirm.
+    pub failed: Vec<String>,
+}
+
+impl SubscriptionResult {
+    /// Whether every channel was confirmed.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
@@ -0,0 +1,64 @@
+//! Mutation audit records.
+
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+/// A change made (or, in a dry run, planned) on the account.
+///
+/// Passed to the hook installed with
+/// [`YTMusicClientBuilder::with_audit_hook`](crate::YTMusicClientBuilder::with_audit_hook)
+/// after each successful mutation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MutationRecord {
+    /// When the mutation completed.
+    pub timestamp: SystemTime,
+    /// What was changed.
+    pub operation: MutationKind,
+    /// IDs the mutation applied to. For playlist edits the playlist ID comes
+    /// first, followed by the affected video IDs (or, for item notes, the
+    /// playlist item ID).
+    pub target_ids: Vec<String>,
+    /// How the mutation went.
+    pub outcome: MutationOutcome,
+}
+
+/// Kind of mutation in a [`MutationRecord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MutationKind {
+    /// A playlist was created.
+    CreatePlaylist,
+    /// A playlist was deleted.
+    DeletePlaylist,
+    /// Playlist items were added, removed or annotated.
+    EditPlaylist,
+    /// A song or playlist was rated.
+    Rate,
+    /// Feedback tokens were sent (e.g. removing search history).
+    Feedback,
+    /// A play was added to the listening history.
+    AddHistory,
+    /// Artists were subscribed to.
+    Subscribe,
+    /// Artists were unsubscribed from.
+    Unsubscribe,
+    /// A song file was uploaded.
+    Upload,
+    /// An uploaded song or album was deleted.
+    DeleteUpload,
+}
+
+/// Outcome of a [`MutationRecord`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MutationOutcome {
+    /// The server confirmed the whole change.
+    Succeeded,
+    /// The server confirmed only part of the change; `failed` lists the IDs
+    /// it did not confirm.
+    Partial {
+        /// Unconfirmed IDs.
+        failed: Vec<String>,
+    },
+    /// Dry run: the change was planned but not sent.
+    Simulated,
+}
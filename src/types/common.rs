@@ -1,7 +1,11 @@
 //! Common types shared across the API.
 
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 
+use super::de::option_number;
+
 /// Rating status for a song.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -24,17 +28,164 @@ impl LikeStatus {
     }
 }
 
+/// Kind of video backing a track.
+///
+/// Known values are represented without allocating; anything else is kept
+/// verbatim in [`MusicVideoType::Other`]. Serializes to and from the raw API
+/// constant (e.g. `"MUSIC_VIDEO_TYPE_ATV"`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MusicVideoType {
+    /// Audio track with album artwork (`MUSIC_VIDEO_TYPE_ATV`).
+    Atv,
+    /// Official music video (`MUSIC_VIDEO_TYPE_OMV`).
+    Omv,
+    /// User-generated content (`MUSIC_VIDEO_TYPE_UGC`).
+    Ugc,
+    /// Official source music (`MUSIC_VIDEO_TYPE_OFFICIAL_SOURCE_MUSIC`).
+    OfficialSourceMusic,
+    /// Podcast episode (`MUSIC_VIDEO_TYPE_PODCAST_EPISODE`).
+    Podcast,
+    /// Any other value reported by the API.
+    Other(String),
+}
+
+impl MusicVideoType {
+    /// The raw API constant for this video type.
+    pub fn as_str(&self) -> &str {
+        match self {
+            MusicVideoType::Atv => "MUSIC_VIDEO_TYPE_ATV",
+            MusicVideoType::Omv => "MUSIC_VIDEO_TYPE_OMV",
+            MusicVideoType::Ugc => "MUSIC_VIDEO_TYPE_UGC",
+            MusicVideoType::OfficialSourceMusic => "MUSIC_VIDEO_TYPE_OFFICIAL_SOURCE_MUSIC",
+            MusicVideoType::Podcast => "MUSIC_VIDEO_TYPE_PODCAST_EPISODE",
+            MusicVideoType::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for MusicVideoType {
+    fn from(s: &str) -> Self {
+        match s {
+            "MUSIC_VIDEO_TYPE_ATV" => MusicVideoType::Atv,
+            "MUSIC_VIDEO_TYPE_OMV" => MusicVideoType::Omv,
+            "MUSIC_VIDEO_TYPE_UGC" => MusicVideoType::Ugc,
+            "MUSIC_VIDEO_TYPE_OFFICIAL_SOURCE_MUSIC" => MusicVideoType::OfficialSourceMusic,
+            "MUSIC_VIDEO_TYPE_PODCAST_EPISODE" => MusicVideoType::Podcast,
+            other => MusicVideoType::Other(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for MusicVideoType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for MusicVideoType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for MusicVideoType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(MusicVideoType::from(s.as_str()))
+    }
+}
+
+/// Industry identifiers for a recording or release.
+///
+/// Only present for content whose distributor supplied them, and only where
+/// the response carries them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalIds {
+    /// International Standard Recording Code, normalized to 12 characters
+    /// without hyphens (e.g., `"USRC17607839"`).
+    pub isrc: Option<String>,
+    /// Universal Product Code of the release.
+    pub upc: Option<String>,
+}
+
 /// A thumbnail image.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Thumbnail {
     /// URL of the thumbnail.
     pub url: String,
     /// Width in pixels, if provided by the API.
+    #[serde(default, deserialize_with = "option_number")]
     pub width: Option<u32>,
     /// Height in pixels, if provided by the API.
+    #[serde(default, deserialize_with = "option_number")]
     pub height: Option<u32>,
 }
 
+impl Thumbnail {
+    fn area(&self) -> u64 {
+        u64::from(self.width.unwrap_or(0)) * u64::from(self.height.unwrap_or(0))
+    }
+
+    /// Pixel count, if both dimensions are known.
+    fn known_area(&self) -> Option<u64> {
+        Some(u64::from(self.width?) * u64::from(self.height?))
+    }
+}
+
+/// Bring a thumbnail list from the API into the order the crate guarantees:
+/// each URL once, smallest first by pixel count, thumbnails of unknown size
+/// last. Otherwise equal thumbnails keep their order.
+pub(crate) fn sort_thumbnails(mut thumbnails: Vec<Thumbnail>) -> Vec<Thumbnail> {
+    thumbnails.sort_by_key(|t| (t.known_area().is_none(), t.known_area()));
+    let mut seen = HashSet::new();
+    thumbnails.retain(|t| seen.insert(t.url.clone()));
+    thumbnails
+}
+
+/// Size selection for the thumbnail lists returned throughout the API.
+///
+/// Implemented for `[Thumbnail]`, so it works on any `Vec<Thumbnail>` field.
+/// Lists returned by the crate hold each URL once and are sorted smallest
+/// first by pixel count, with thumbnails of unknown size at the end, so
+/// `last()` is the largest only when every size is known; [`best`](Self::best)
+/// always is.
+///
+/// ```
+/// use ytmusicapi::{Thumbnail, Thumbnails};
+///
+/// let ladder: Vec<Thumbnail> = [60, 120, 544]
+///     .map(|size| Thumbnail {
+///         url: format!("https://example.com/{size}.jpg"),
+///         width: Some(size),
+///         height: Some(size),
+///     })
+///     .into();
+/// assert_eq!(ladder.best().unwrap().width, Some(544));
+/// assert_eq!(ladder.at_least(100).unwrap().width, Some(120));
+/// ```
+pub trait Thumbnails {
+    /// The largest thumbnail by pixel count.
+    fn best(&self) -> Option<&Thumbnail>;
+
+    /// The smallest thumbnail at least `width` pixels wide, or the largest
+    /// one if none is that wide.
+    fn at_least(&self, width: u32) -> Option<&Thumbnail>;
+}
+
+impl Thumbnails for [Thumbnail] {
+    fn best(&self) -> Option<&Thumbnail> {
+        // Ladders are listed smallest first, so prefer the last of equals.
+        self.iter().rev().max_by_key(|t| t.area())
+    }
+
+    fn at_least(&self, width: u32) -> Option<&Thumbnail> {
+        self.iter()
+            .filter(|t| t.width.is_some_and(|w| w >= width))
+            .min_by_key(|t| t.width)
+            .or_else(|| self.best())
+    }
+}
+
 /// An artist reference.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Artist {
@@ -61,3 +212,211 @@ pub struct Author {
     /// Author channel browse ID, if available.
     pub id: Option<String>,
 }
+
+/// A listing row the parser dropped or only partly understood.
+///
+/// Parsers skip rows they cannot read rather than failing the whole listing.
+/// These warnings record each such row, so layout changes can be noticed
+/// while most of the listing still parses. Expected skips (deleted songs,
+/// the "New playlist" tile, continuation markers) are not reported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseWarning {
+    /// Response the row came from: `0` for the first page, then `1`, `2`, ...
+    /// for continuation pages.
+    pub page: usize,
+    /// Position of the row within its page.
+    pub index: usize,
+    /// Path, relative to the row, of the part that was missing or unknown.
+    pub path: String,
+    /// What was wrong.
+    pub reason: String,
+}
+
+impl ParseWarning {
+    pub(crate) fn new(index: usize, path: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            page: 0,
+            index,
+            path: path.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// One run of a rich-text description.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DescriptionRun {
+    /// Run text.
+    pub text: String,
+    /// Link target, if the run is a link. Links to other YouTube Music pages
+    /// are given as `https://music.youtube.com/browse/<id>` URLs.
+    pub url: Option<String>,
+}
+
+/// Common accessors for track-shaped types.
+///
+/// Implemented by every type that represents a playable track, so bulk helpers
+/// such as [`YTMusicClient::add_playlist_tracks`](crate::YTMusicClient::add_playlist_tracks)
+/// can accept any of them.
+pub trait TrackLike {
+    /// Video ID used for playback, if available.
+    fn video_id(&self) -> Option<&str>;
+    /// Track title, if available.
+    fn title(&self) -> Option<&str>;
+    /// Credited artists.
+    fn artists(&self) -> &[Artist];
+    /// Duration in seconds, if known.
+    fn duration_seconds(&self) -> Option<u32>;
+    /// Whether the track is marked explicit, if the type records it.
+    fn is_explicit(&self) -> Option<bool> {
+        None
+    }
+    /// Whether the track can be played, if the type records it.
+    fn is_available(&self) -> Option<bool> {
+        None
+    }
+    /// Kind of video backing the track, if known.
+    fn video_type(&self) -> Option<&MusicVideoType> {
+        None
+    }
+
+    /// Convert into a [`PlaylistTrack`](crate::PlaylistTrack).
+    ///
+    /// Only the fields exposed by this trait are carried over; everything else
+    /// (album, thumbnails, `set_video_id`, ...) is left at its default. The result
+    /// is therefore not suitable for removing or reordering playlist items.
+    fn to_playlist_track(&self) -> crate::PlaylistTrack {
+        crate::PlaylistTrack {
+            video_id: self.video_id().map(str::to_string),
+            title: self.title().map(str::to_string),
+            artists: self.artists().to_vec(),
+            duration_seconds: self.duration_seconds(),
+            ..Default::default()
+        }
+    }
+}
+
+impl<T: TrackLike + ?Sized> TrackLike for &T {
+    fn video_id(&self) -> Option<&str> {
+        (**self).video_id()
+    }
+
+    fn title(&self) -> Option<&str> {
+        (**self).title()
+    }
+
+    fn artists(&self) -> &[Artist] {
+        (**self).artists()
+    }
+
+    fn duration_seconds(&self) -> Option<u32> {
+        (**self).duration_seconds()
+    }
+
+    fn is_explicit(&self) -> Option<bool> {
+        (**self).is_explicit()
+    }
+
+    fn is_available(&self) -> Option<bool> {
+        (**self).is_available()
+    }
+
+    fn video_type(&self) -> Option<&MusicVideoType> {
+        (**self).video_type()
+    }
+
+    fn to_playlist_track(&self) -> crate::PlaylistTrack {
+        (**self).to_playlist_track()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thumbnail(width: Option<u32>, height: Option<u32>) -> Thumbnail {
+        Thumbnail {
+            url: format!("{width:?}x{height:?}"),
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_thumbnail_selection() {
+        let thumbs = [
+            thumbnail(None, None),
+            thumbnail(Some(120), Some(90)),
+            thumbnail(Some(480), Some(360)),
+            thumbnail(Some(320), Some(180)),
+        ];
+        assert_eq!(thumbs.best().unwrap().width, Some(480));
+        assert_eq!(thumbs.at_least(200).unwrap().width, Some(320));
+        assert_eq!(thumbs.at_least(1000).unwrap().width, Some(480));
+        assert!(Vec::<Thumbnail>::new().best().is_none());
+        assert_eq!(
+            [thumbnail(None, None)].at_least(10).unwrap().url,
+            "NonexNone"
+        );
+    }
+
+    #[test]
+    fn test_sort_thumbnails_dedupes_and_orders_by_area() {
+        let duplicate = Thumbnail {
+            url: "Some(120)xSome(90)".to_string(),
+            width: Some(120),
+            height: Some(90),
+        };
+        let sorted = sort_thumbnails(vec![
+            thumbnail(None, None),
+            thumbnail(Some(480), Some(360)),
+            duplicate.clone(),
+            thumbnail(Some(320), Some(180)),
+            duplicate,
+            thumbnail(Some(60), None),
+        ]);
+        let urls: Vec<_> = sorted.iter().map(|t| t.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "Some(120)xSome(90)",
+                "Some(320)xSome(180)",
+                "Some(480)xSome(360)",
+                "NonexNone",
+                "Some(60)xNone",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_music_video_type_known_constants() {
+        let cases = [
+            ("MUSIC_VIDEO_TYPE_ATV", MusicVideoType::Atv),
+            ("MUSIC_VIDEO_TYPE_OMV", MusicVideoType::Omv),
+            ("MUSIC_VIDEO_TYPE_UGC", MusicVideoType::Ugc),
+            (
+                "MUSIC_VIDEO_TYPE_OFFICIAL_SOURCE_MUSIC",
+                MusicVideoType::OfficialSourceMusic,
+            ),
+            ("MUSIC_VIDEO_TYPE_PODCAST_EPISODE", MusicVideoType::Podcast),
+        ];
+
+        for (raw, expected) in cases {
+            assert_eq!(MusicVideoType::from(raw), expected);
+            assert_eq!(expected.as_str(), raw);
+            assert_eq!(serde_json::to_value(&expected).unwrap(), raw);
+        }
+    }
+
+    #[test]
+    fn test_music_video_type_unknown_round_trips() {
+        let raw = "MUSIC_VIDEO_TYPE_SOMETHING_NEW";
+        let parsed = MusicVideoType::from(raw);
+        assert_eq!(parsed, MusicVideoType::Other(raw.to_string()));
+
+        let json = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(json, format!("\"{raw}\""));
+        let back: MusicVideoType = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, parsed);
+    }
+}
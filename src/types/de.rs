@@ -0,0 +1,112 @@
+//! Lenient deserializers for numeric fields.
+//!
+//! The API is inconsistent about numbers: `viewCount` and `lengthSeconds` are
+//! strings in player responses but numbers in some renderer payloads, and a
+//! few fields change type between experiment variants. Numeric fields
+//! deserialized straight from API JSON should use these helpers so either
+//! encoding is accepted.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrNumber {
+    String(String),
+    Unsigned(u64),
+    Signed(i64),
+    Float(f64),
+}
+
+impl StringOrNumber {
+    fn into_string(self) -> String {
+        match self {
+            Self::String(s) => s,
+            Self::Unsigned(n) => n.to_string(),
+            Self::Signed(n) => n.to_string(),
+            Self::Float(n) => n.to_string(),
+        }
+    }
+}
+
+/// Deserialize a `String` field the API may send as a string or a number.
+pub(crate) fn string_or_number<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<String, D::Error> {
+    Ok(StringOrNumber::deserialize(deserializer)?.into_string())
+}
+
+/// Deserialize an optional number the API may send as a number or a numeric
+/// string. Use together with `#[serde(default)]`.
+pub(crate) fn option_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    match Option::<StringOrNumber>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(value) => value
+            .into_string()
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(D::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::types::{Song, Thumbnail, VideoDetails};
+
+    #[test]
+    fn test_video_details_accepts_both_encodings() {
+        let as_strings: VideoDetails = serde_json::from_value(json!({
+            "videoId": "v", "title": "t", "author": "a",
+            "lengthSeconds": "212", "viewCount": "1500"
+        }))
+        .unwrap();
+        let as_numbers: VideoDetails = serde_json::from_value(json!({
+            "videoId": "v", "title": "t", "author": "a",
+            "lengthSeconds": 212, "viewCount": 1500
+        }))
+        .unwrap();
+        assert_eq!(as_strings, as_numbers);
+        assert_eq!(as_numbers.length_seconds, "212");
+    }
+
+    #[test]
+    fn test_song_microformat_accepts_numeric_view_count() {
+        let song: Song = serde_json::from_value(json!({
+            "videoDetails": {
+                "videoId": "v", "title": "t", "author": "a",
+                "lengthSeconds": 212, "viewCount": "1500"
+            },
+            "microformat": {
+                "microformatDataRenderer": { "uploadDate": "2020-01-01", "viewCount": 1500 }
+            }
+        }))
+        .unwrap();
+        let microformat = song.microformat.unwrap().microformat_data_renderer;
+        assert_eq!(microformat.view_count, "1500");
+    }
+
+    #[test]
+    fn test_thumbnail_dimensions_accept_both_encodings() {
+        let thumbnail: Thumbnail =
+            serde_json::from_value(json!({ "url": "u", "width": "120", "height": 90 })).unwrap();
+        assert_eq!((thumbnail.width, thumbnail.height), (Some(120), Some(90)));
+
+        let thumbnail: Thumbnail = serde_json::from_value(json!({ "url": "u" })).unwrap();
+        assert_eq!((thumbnail.width, thumbnail.height), (None, None));
+
+        assert!(
+            serde_json::from_value::<Thumbnail>(json!({ "url": "u", "width": "wide" })).is_err()
+        );
+    }
+}
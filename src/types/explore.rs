@@ -0,0 +1,86 @@
+//! Explore page types.
+
+use serde::{Deserialize, Serialize};
+
+use super::SearchResult;
+
+/// A section of the "Moods & genres" page, such as "Moods & moments" or
+/// "Genres".
+///
+/// Returned by
+/// [`YTMusicClient::get_mood_categories`](crate::YTMusicClient::get_mood_categories).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoodSection {
+    /// Section heading as shown, in the client language.
+    pub title: String,
+    /// Categories in page order.
+    pub categories: Vec<MoodCategory>,
+}
+
+/// A mood or genre, such as "Focus" or "Jazz".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoodCategory {
+    /// Category name as shown, in the client language.
+    pub title: String,
+    /// Browse `params` of the category's playlists, for
+    /// [`YTMusicClient::get_mood_playlists`](crate::YTMusicClient::get_mood_playlists).
+    pub params: String,
+}
+
+/// The charts of a country, or the global charts.
+///
+/// Returned by [`YTMusicClient::get_charts`](crate::YTMusicClient::get_charts).
+/// Charts the page does not show are `None`; the global charts have no songs
+/// or trending chart.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Charts {
+    /// Name of the country the charts are for, as shown in the country
+    /// picker (e.g. `"Netherlands"`, `"Global"`).
+    pub country: Option<String>,
+    /// Country codes the country picker offers.
+    pub countries: Vec<String>,
+    /// Top songs.
+    pub songs: Option<ChartSection>,
+    /// Top music videos. Shown as the playlists holding each video chart,
+    /// without ranks.
+    pub videos: Option<ChartSection>,
+    /// Top artists.
+    pub artists: Option<ChartSection>,
+    /// Trending songs and videos.
+    pub trending: Option<ChartSection>,
+}
+
+/// One chart.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChartSection {
+    /// Chart heading as shown, in the client language.
+    pub title: String,
+    /// ID of the playlist holding the full chart, if the heading links to
+    /// one.
+    pub playlist_id: Option<String>,
+    /// Entries in chart order.
+    pub entries: Vec<ChartEntry>,
+}
+
+/// A song, video, artist or playlist on a chart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChartEntry {
+    /// Position on the chart, if shown.
+    pub rank: Option<u32>,
+    /// Movement since the previous chart, if shown.
+    pub trend: Option<ChartTrend>,
+    /// The charted item.
+    pub item: SearchResult,
+}
+
+/// Movement of a chart entry since the previous chart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartTrend {
+    /// Moved up.
+    Up,
+    /// Moved down.
+    Down,
+    /// Kept its position.
+    Neutral,
+}
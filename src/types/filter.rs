@@ -0,0 +1,264 @@
+//! Client-side track filtering.
+
+use serde::{Deserialize, Serialize};
+
+use super::{MusicVideoType, TrackLike};
+
+/// Predicates a track must pass, e.g. to keep explicit tracks out of a
+/// playlist built from search results.
+///
+/// Every predicate is off by default. A track is dropped only when it is
+/// known to fail a predicate; tracks missing the field a predicate needs
+/// (a search result has no availability, a row may have no duration) are
+/// kept unless [`exclude_unknown`](Self::exclude_unknown) is set.
+///
+/// ```
+/// use ytmusicapi::TrackFilter;
+///
+/// let filter = TrackFilter::new()
+///     .exclude_explicit(true)
+///     .max_duration_seconds(600);
+/// assert!(filter.exclude_explicit);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TrackFilter {
+    /// Drop tracks marked explicit.
+    pub exclude_explicit: bool,
+    /// Drop tracks that cannot be played.
+    pub available_only: bool,
+    /// Drop tracks shorter than this many seconds.
+    pub min_duration_seconds: Option<u32>,
+    /// Drop tracks longer than this many seconds.
+    pub max_duration_seconds: Option<u32>,
+    /// Keep only these video types; `None` keeps every type.
+    pub video_types: Option<Vec<MusicVideoType>>,
+    /// Also drop tracks whose explicitness, availability, duration or video
+    /// type is unknown, for each predicate that is set.
+    pub exclude_unknown: bool,
+}
+
+impl TrackFilter {
+    /// A filter that keeps every track.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop or keep explicit tracks.
+    pub fn exclude_explicit(mut self, exclude: bool) -> Self {
+        self.exclude_explicit = exclude;
+        self
+    }
+
+    /// Drop or keep tracks that cannot be played.
+    pub fn available_only(mut self, available_only: bool) -> Self {
+        self.available_only = available_only;
+        self
+    }
+
+    /// Drop tracks shorter than `seconds`.
+    pub fn min_duration_seconds(mut self, seconds: u32) -> Self {
+        self.min_duration_seconds = Some(seconds);
+        self
+    }
+
+    /// Drop tracks longer than `seconds`.
+    pub fn max_duration_seconds(mut self, seconds: u32) -> Self {
+        self.max_duration_seconds = Some(seconds);
+        self
+    }
+
+    /// Keep only the given video types.
+    pub fn video_types(mut self, types: impl IntoIterator<Item = MusicVideoType>) -> Self {
+        self.video_types = Some(types.into_iter().collect());
+        self
+    }
+
+    /// Drop or keep tracks missing a field a set predicate needs.
+    pub fn exclude_unknown(mut self, exclude: bool) -> Self {
+        self.exclude_unknown = exclude;
+        self
+    }
+
+    /// Whether `track` passes every predicate.
+    pub fn matches(&self, track: &impl TrackLike) -> bool {
+        self.rejection(track).is_none()
+    }
+
+    /// Split `tracks` into those passing the filter, in order, and counts of
+    /// those dropped.
+    pub fn apply<T: TrackLike>(
+        &self,
+        tracks: impl IntoIterator<Item = T>,
+    ) -> (Vec<T>, FilterCounts) {
+        let mut counts = FilterCounts::default();
+        let kept = tracks
+            .into_iter()
+            .filter(|track| match self.rejection(track) {
+                None => true,
+                Some(reason) => {
+                    counts.count(reason);
+                    false
+                }
+            })
+            .collect();
+        (kept, counts)
+    }
+
+    /// The first predicate `track` fails, checked in field order.
+    fn rejection(&self, track: &impl TrackLike) -> Option<Rejection> {
+        // Whether a known value fails, or an unknown one is not allowed.
+        let fails = |known: Option<bool>| known.unwrap_or(self.exclude_unknown);
+
+        if self.exclude_explicit && fails(track.is_explicit()) {
+            return Some(Rejection::Explicit);
+        }
+        if self.available_only && fails(track.is_available().map(|available| !available)) {
+            return Some(Rejection::Unavailable);
+        }
+        if self.min_duration_seconds.is_some() || self.max_duration_seconds.is_some() {
+            let out_of_bounds = track.duration_seconds().map(|seconds| {
+                self.min_duration_seconds.is_some_and(|min| seconds < min)
+                    || self.max_duration_seconds.is_some_and(|max| seconds > max)
+            });
+            if fails(out_of_bounds) {
+                return Some(Rejection::Duration);
+            }
+        }
+        if let Some(types) = &self.video_types
+            && fails(
+                track
+                    .video_type()
+                    .map(|video_type| !types.contains(video_type)),
+            )
+        {
+            return Some(Rejection::VideoType);
+        }
+        None
+    }
+}
+
+enum Rejection {
+    Explicit,
+    Unavailable,
+    Duration,
+    VideoType,
+}
+
+/// Number of tracks a [`TrackFilter`] dropped, by the first predicate each
+/// failed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilterCounts {
+    /// Dropped as explicit.
+    pub explicit: usize,
+    /// Dropped as unplayable.
+    pub unavailable: usize,
+    /// Dropped for their duration.
+    pub duration: usize,
+    /// Dropped for their video type.
+    pub video_type: usize,
+}
+
+impl FilterCounts {
+    /// Total number of tracks dropped.
+    pub fn total(&self) -> usize {
+        self.explicit + self.unavailable + self.duration + self.video_type
+    }
+
+    fn count(&mut self, reason: Rejection) {
+        let count = match reason {
+            Rejection::Explicit => &mut self.explicit,
+            Rejection::Unavailable => &mut self.unavailable,
+            Rejection::Duration => &mut self.duration,
+            Rejection::VideoType => &mut self.video_type,
+        };
+        *count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Artist, PlaylistTrack};
+
+    /// A track type that reports none of the filtered fields.
+    struct Bare;
+
+    impl TrackLike for Bare {
+        fn video_id(&self) -> Option<&str> {
+            Some("v")
+        }
+
+        fn title(&self) -> Option<&str> {
+            None
+        }
+
+        fn artists(&self) -> &[Artist] {
+            &[]
+        }
+
+        fn duration_seconds(&self) -> Option<u32> {
+            None
+        }
+    }
+
+    fn track(explicit: bool, available: bool, seconds: Option<u32>) -> PlaylistTrack {
+        PlaylistTrack {
+            video_id: Some("v".to_string()),
+            is_explicit: explicit,
+            is_available: available,
+            duration_seconds: seconds,
+            video_type: Some(MusicVideoType::Atv),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_apply_counts_first_failed_predicate() {
+        let filter = TrackFilter::new()
+            .exclude_explicit(true)
+            .available_only(true)
+            .min_duration_seconds(60)
+            .max_duration_seconds(600);
+        let tracks = vec![
+            track(false, true, Some(200)),
+            track(true, false, Some(200)),
+            track(false, false, Some(200)),
+            track(false, true, Some(30)),
+            track(false, true, Some(900)),
+            track(false, true, None),
+        ];
+
+        let (kept, counts) = filter.apply(tracks);
+        assert_eq!(kept.len(), 2);
+        assert_eq!(
+            counts,
+            FilterCounts {
+                explicit: 1,
+                unavailable: 1,
+                duration: 2,
+                video_type: 0,
+            }
+        );
+        assert_eq!(counts.total(), 4);
+    }
+
+    #[test]
+    fn test_unknown_fields_pass_unless_excluded() {
+        let result = Bare;
+        let filter = TrackFilter::new()
+            .exclude_explicit(true)
+            .available_only(true)
+            .max_duration_seconds(600)
+            .video_types([MusicVideoType::Atv]);
+        assert!(filter.matches(&result));
+        assert!(!filter.clone().exclude_unknown(true).matches(&result));
+
+        let omv = PlaylistTrack {
+            video_type: Some(MusicVideoType::Omv),
+            ..track(false, true, Some(200))
+        };
+        assert!(!filter.matches(&omv));
+        assert!(filter.matches(&track(false, true, Some(200))));
+    }
+}
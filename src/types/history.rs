@@ -0,0 +1,100 @@
+//! Listening history types.
+
+use std::ops::Deref;
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{Artist, MusicVideoType, PlaylistTrack, TrackLike};
+
+/// A played track from the listening history.
+///
+/// Dereferences to the underlying [`PlaylistTrack`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryTrack {
+    /// The track.
+    pub track: PlaylistTrack,
+    /// Header of the history section the track is listed under, as shown
+    /// (e.g. `"Today"`, `"Yesterday"`, `"March 2024"`), in the client language.
+    pub played: String,
+    /// Token that removes this entry from the history, if present.
+    pub feedback_token: Option<String>,
+}
+
+impl Deref for HistoryTrack {
+    type Target = PlaylistTrack;
+
+    fn deref(&self) -> &Self::Target {
+        &self.track
+    }
+}
+
+impl TrackLike for HistoryTrack {
+    fn video_id(&self) -> Option<&str> {
+        self.track.video_id.as_deref()
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.track.title.as_deref()
+    }
+
+    fn artists(&self) -> &[Artist] {
+        &self.track.artists
+    }
+
+    fn duration_seconds(&self) -> Option<u32> {
+        self.track.duration_seconds
+    }
+
+    fn is_explicit(&self) -> Option<bool> {
+        self.track.is_explicit()
+    }
+
+    fn is_available(&self) -> Option<bool> {
+        self.track.is_available()
+    }
+
+    fn video_type(&self) -> Option<&MusicVideoType> {
+        self.track.video_type.as_ref()
+    }
+
+    fn to_playlist_track(&self) -> PlaylistTrack {
+        self.track.clone()
+    }
+}
+
+/// One section of the listening history, such as a day or a month.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryBucket {
+    /// Header of the section, as shown (e.g. `"Today"`, `"March 2024"`).
+    pub period: String,
+    /// Most recent date an entry in this section can have been played on,
+    /// when the header is recognised.
+    #[cfg(feature = "chrono")]
+    pub latest: Option<NaiveDate>,
+    /// Tracks of the section, most recent first.
+    pub tracks: Vec<HistoryTrack>,
+}
+
+/// The recent listening history, from
+/// [`YTMusicClient::get_history_since`](crate::YTMusicClient::get_history_since).
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistorySince {
+    /// Sections that may hold plays on or after the cutoff, most recent
+    /// first. A section spanning the cutoff is kept whole, so its earliest
+    /// tracks may predate it.
+    pub buckets: Vec<HistoryBucket>,
+    /// Whether a section entirely before the cutoff was reached. `false`
+    /// means the history ended first, so it holds nothing older.
+    pub reached_cutoff: bool,
+}
+
+#[cfg(feature = "chrono")]
+impl HistorySince {
+    /// All fetched tracks, most recent first.
+    pub fn tracks(&self) -> impl Iterator<Item = &HistoryTrack> {
+        self.buckets.iter().flat_map(|bucket| &bucket.tracks)
+    }
+}
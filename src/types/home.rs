@@ -0,0 +1,32 @@
+//! Home feed types.
+
+use serde::{Deserialize, Serialize};
+
+use super::{PlaylistSummary, SearchResult};
+
+/// A carousel of the home feed, such as "Listen again", "Quick picks" or
+/// "Mixed for you".
+///
+/// Returned by [`YTMusicClient::get_home`](crate::YTMusicClient::get_home).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HomeSection {
+    /// Carousel heading as shown, in the client language.
+    pub title: String,
+    /// Items in page order.
+    pub contents: Vec<HomeItem>,
+}
+
+/// An item of a [`HomeSection`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HomeItem {
+    /// A song or music video.
+    Song(SearchResult),
+    /// An album, single or EP.
+    Album(SearchResult),
+    /// A playlist, including personal mixes such as "My Supermix", whose
+    /// `playlist_id` starts playback.
+    Playlist(PlaylistSummary),
+    /// An artist channel.
+    Artist(SearchResult),
+}
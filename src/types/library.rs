@@ -0,0 +1,193 @@
+//! Library listing and maintenance types.
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{Artist, PlaylistTrack, Thumbnail};
+
+/// Sort order of a library listing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LibraryOrder {
+    /// Most recently added first.
+    RecentlyAdded,
+    /// Alphabetical by title.
+    AToZ,
+    /// Reverse alphabetical by title.
+    ZToA,
+}
+
+impl LibraryOrder {
+    pub(crate) fn params(self) -> &'static str {
+        match self {
+            LibraryOrder::AToZ => "ggMGKgQIARAA",
+            LibraryOrder::ZToA => "ggMGKgQIARAB",
+            LibraryOrder::RecentlyAdded => "ggMGKgQIABAB",
+        }
+    }
+}
+
+/// An album saved to the user's library.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryAlbum {
+    /// Album title.
+    pub title: String,
+    /// Album browse ID (`MPREb_...`).
+    pub browse_id: String,
+    /// Release type label as shown (e.g., `"Album"`, `"EP"`, `"Single"`), in
+    /// the client language.
+    pub album_type: Option<String>,
+    /// Album artists.
+    pub artists: Vec<Artist>,
+    /// Release year, if shown.
+    pub year: Option<u16>,
+    /// Cover images.
+    pub thumbnails: Vec<Thumbnail>,
+    /// Whether the album is marked explicit.
+    pub is_explicit: bool,
+}
+
+/// An artist in the user's library, e.g. a subscription.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryArtist {
+    /// Artist name.
+    pub name: String,
+    /// Channel ID (`UC...`), without the `MPLA` prefix of library links.
+    pub browse_id: String,
+    /// Subscriber count, if shown (subscriptions only).
+    pub subscribers: Option<u64>,
+    /// Number of library songs by the artist, if shown (library artists only).
+    pub songs: Option<u32>,
+    /// Profile images.
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+/// A library track found by
+/// [`YTMusicClient::search_library_tracks`](crate::YTMusicClient::search_library_tracks).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryTrackMatch {
+    /// The track as listed in the first playlist it was found in.
+    pub track: PlaylistTrack,
+    /// How well the track matches the query, from 0.0 to 1.0.
+    pub score: f32,
+    /// Every playlist holding the track, in library order.
+    pub locations: Vec<TrackLocation>,
+}
+
+/// Where a library track is saved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrackLocation {
+    /// Playlist ID (`LM` for Liked Music).
+    pub playlist_id: String,
+    /// Playlist title.
+    pub playlist_title: String,
+    /// Playlist item ID, for removing the track from this playlist.
+    pub set_video_id: Option<String>,
+}
+
+/// Options for [`YTMusicClient::plan_prune`](crate::YTMusicClient::plan_prune).
+///
+/// Each threshold selects one kind of library item. Only empty playlists are
+/// selected by default.
+///
+/// ```
+/// use ytmusicapi::PruneOptions;
+///
+/// let options = PruneOptions::new().artists_without_songs(true);
+/// assert!(options.empty_playlists);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PruneOptions {
+    /// Remove library playlists that report zero tracks.
+    pub empty_playlists: bool,
+    /// Unsubscribe from artists with no songs in the library.
+    #[serde(default)]
+    pub artists_without_songs: bool,
+    /// Remove library albums with no track in the listening history on or
+    /// after this date.
+    #[cfg(feature = "chrono")]
+    #[serde(default)]
+    pub albums_not_played_since: Option<NaiveDate>,
+}
+
+impl Default for PruneOptions {
+    fn default() -> Self {
+        Self {
+            empty_playlists: true,
+            artists_without_songs: false,
+            #[cfg(feature = "chrono")]
+            albums_not_played_since: None,
+        }
+    }
+}
+
+impl PruneOptions {
+    /// Options selecting empty playlists only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select library playlists that report zero tracks.
+    pub fn empty_playlists(mut self, enabled: bool) -> Self {
+        self.empty_playlists = enabled;
+        self
+    }
+
+    /// Select subscribed artists with no songs in the library.
+    pub fn artists_without_songs(mut self, enabled: bool) -> Self {
+        self.artists_without_songs = enabled;
+        self
+    }
+
+    /// Select library albums not played on or after `cutoff`.
+    #[cfg(feature = "chrono")]
+    pub fn albums_not_played_since(mut self, cutoff: NaiveDate) -> Self {
+        self.albums_not_played_since = Some(cutoff);
+        self
+    }
+}
+
+/// Why an item was selected for removal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PruneReason {
+    /// The playlist has no tracks.
+    EmptyPlaylist,
+    /// The subscribed artist has no songs in the library.
+    ArtistWithoutSongs,
+    /// No track of the library album was played since the cutoff.
+    AlbumNotPlayed,
+}
+
+/// A library item selected for removal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PruneCandidate {
+    /// Playlist ID, artist channel ID or album browse ID, depending on
+    /// `reason`.
+    pub id: String,
+    /// Title or artist name shown in the library.
+    pub title: String,
+    /// Why the item was selected.
+    pub reason: PruneReason,
+}
+
+/// Library items selected for removal by
+/// [`YTMusicClient::plan_prune`](crate::YTMusicClient::plan_prune).
+///
+/// Nothing is removed until the plan is passed to
+/// [`YTMusicClient::execute_prune`](crate::YTMusicClient::execute_prune), so
+/// it can be reviewed, filtered or saved first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrunePlan {
+    /// Items selected for removal, in the order they will be removed.
+    pub candidates: Vec<PruneCandidate>,
+}
+
+/// Outcome of [`YTMusicClient::execute_prune`](crate::YTMusicClient::execute_prune).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PruneReport {
+    /// IDs removed successfully.
+    pub removed: Vec<String>,
+    /// IDs whose removal failed, with the error message.
+    pub failed: Vec<(String, String)>,
+}
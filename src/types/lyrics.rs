@@ -0,0 +1,67 @@
+//! Lyrics types.
+
+use serde::{Deserialize, Serialize};
+
+/// Lyrics of a track.
+///
+/// Returned by [`YTMusicClient::get_lyrics`](crate::YTMusicClient::get_lyrics).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lyrics {
+    /// The lyrics, with one line per `\n`.
+    pub text: String,
+    /// Credit line naming the lyrics provider (e.g. `"Source: LyricFind"`),
+    /// if shown.
+    pub source: Option<String>,
+    /// Copyright notice of the lyrics (e.g. `"Lyrics © Sony/ATV Music
+    /// Publishing LLC"`), if shown. Show it with the lyrics, along with
+    /// `source`, when redistributing them.
+    #[serde(default)]
+    pub copyright: Option<String>,
+}
+
+/// Lyrics from
+/// [`YTMusicClient::get_timed_lyrics`](crate::YTMusicClient::get_timed_lyrics).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimedLyrics {
+    /// Lyrics with the time each line is sung.
+    Timed {
+        /// Lines in order of appearance.
+        lines: Vec<TimedLyricLine>,
+        /// Credit line naming the lyrics provider, if shown.
+        source: Option<String>,
+    },
+    /// Lyrics without timing, for tracks the provider has not synchronized.
+    Plain(Lyrics),
+}
+
+impl TimedLyrics {
+    /// Whether the lyrics carry line timing.
+    pub fn has_timestamps(&self) -> bool {
+        matches!(self, Self::Timed { .. })
+    }
+
+    /// The lyrics as plain text, one line per `\n`.
+    pub fn text(&self) -> String {
+        match self {
+            Self::Timed { lines, .. } => lines
+                .iter()
+                .map(|line| line.text.as_str())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Self::Plain(lyrics) => lyrics.text.clone(),
+        }
+    }
+}
+
+/// One line of [`TimedLyrics`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimedLyricLine {
+    /// Text of the line; empty for instrumental breaks.
+    pub text: String,
+    /// When the line starts, in milliseconds from the start of the track.
+    pub start_ms: u64,
+    /// When the line ends, in milliseconds from the start of the track.
+    pub end_ms: u64,
+    /// Identifier of the line within the lyrics.
+    pub id: u64,
+}
@@ -1,9 +1,40 @@
 //! Types for YouTube Music API responses.
 
+mod account;
+mod album;
+mod artist;
+mod audit;
 mod common;
+mod de;
+mod explore;
+mod filter;
+mod history;
+mod home;
+mod library;
+mod lyrics;
 mod playlist;
+mod search;
 mod song;
+mod undo;
+mod upload;
+mod user;
+mod watch;
 
+pub use account::*;
+pub use album::*;
+pub use artist::*;
+pub use audit::*;
 pub use common::*;
+pub use explore::*;
+pub use filter::*;
+pub use history::*;
+pub use home::*;
+pub use library::*;
+pub use lyrics::*;
 pub use playlist::*;
+pub use search::*;
 pub use song::*;
+pub use undo::*;
+pub use upload::*;
+pub use user::*;
+pub use watch::*;
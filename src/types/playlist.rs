@@ -1,9 +1,14 @@
 //! Playlist types.
 
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::{Album, Artist, Author, Thumbnail};
+use super::{
+    Album, Artist, Author, DescriptionRun, FilterCounts, MusicVideoType, ParseWarning, Thumbnail,
+    TrackLike,
+};
 
 /// Privacy status of a playlist.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -18,6 +23,17 @@ pub enum Privacy {
     Unlisted,
 }
 
+impl Privacy {
+    /// The privacy status as the API spells it.
+    pub(crate) fn as_api_str(self) -> &'static str {
+        match self {
+            Privacy::Public => "PUBLIC",
+            Privacy::Private => "PRIVATE",
+            Privacy::Unlisted => "UNLISTED",
+        }
+    }
+}
+
 impl From<&str> for Privacy {
     fn from(s: &str) -> Self {
         match s.to_uppercase().as_str() {
@@ -29,6 +45,48 @@ impl From<&str> for Privacy {
     }
 }
 
+/// How `browse/edit_playlist` should treat videos already in the playlist.
+///
+/// The server either checks each added video against the playlist and
+/// leaves out the ones already in it (`DEDUPE_OPTION_CHECK`), or skips that
+/// check and adds them again (`DEDUPE_OPTION_SKIP`). [`Skip`](Self::Skip)
+/// and [`Fail`](Self::Fail) both ask for the check and only differ in how
+/// the left-out videos are reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DuplicateHandling {
+    /// Leave out videos that are already present and report them as
+    /// [`Error::Duplicates`](crate::Error::Duplicates) (`DEDUPE_OPTION_CHECK`).
+    Fail,
+    /// Leave out videos that are already present (`DEDUPE_OPTION_CHECK`);
+    /// they are listed in [`AddPlaylistItemsResult::skipped`].
+    #[default]
+    Skip,
+    /// Add videos even if they are already present (`DEDUPE_OPTION_SKIP`).
+    Allow,
+}
+
+impl DuplicateHandling {
+    /// The `dedupeOption` sent with each add action.
+    pub(crate) fn dedupe_option(self) -> &'static str {
+        match self {
+            DuplicateHandling::Fail | DuplicateHandling::Skip => "DEDUPE_OPTION_CHECK",
+            DuplicateHandling::Allow => "DEDUPE_OPTION_SKIP",
+        }
+    }
+}
+
+/// Where a playlist's cover image comes from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoverSource {
+    /// Cover generated automatically from the artwork of the first tracks.
+    AutoCollage {
+        /// Thumbnail URL of each track contributing to the collage, in order.
+        components: Vec<String>,
+    },
+    /// Cover image uploaded by the owner.
+    Custom,
+}
+
 /// Summary info for a playlist in a library listing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlaylistSummary {
@@ -40,35 +98,77 @@ pub struct PlaylistSummary {
     pub thumbnails: Vec<Thumbnail>,
     /// Number of tracks, if provided by the API.
     pub count: Option<u32>,
+    /// Author, when the subtitle links to their channel.
+    #[serde(default)]
+    pub author: Option<Author>,
+}
+
+/// One page of library playlists.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LibraryPlaylistsPage {
+    /// Playlists on this page, in server order.
+    pub playlists: Vec<PlaylistSummary>,
+    /// Total number of playlists in the library, if the response shows it.
+    /// Only reported on the first page.
+    pub total_hint: Option<u32>,
+    /// Token for the next page, if any.
+    pub next_token: Option<String>,
+    /// Rows on this page that could not be parsed; empty normally.
+    #[serde(default)]
+    pub warnings: Vec<ParseWarning>,
 }
 
 /// Full playlist with tracks.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Playlist {
-    /// Playlist ID without the `VL` prefix.
+    /// Playlist ID without the `VL` prefix, as resolved by the server.
+    ///
+    /// Taken from the response when it names the playlist, otherwise the
+    /// normalized ID that was requested. Stable across the input forms
+    /// [`YTMusicClient::get_playlist`](crate::YTMusicClient::get_playlist)
+    /// accepts, so it is suitable as a cache key.
     pub id: String,
+    /// The playlist ID or URL exactly as passed by the caller.
+    pub requested_id: String,
     /// Playlist title.
     pub title: String,
-    /// Description.
+    /// Description, with all runs joined.
     pub description: Option<String>,
+    /// Description runs, preserving links.
+    pub description_runs: Vec<DescriptionRun>,
     /// Privacy setting.
     pub privacy: Privacy,
     /// Thumbnail images.
     pub thumbnails: Vec<Thumbnail>,
+    /// Whether the cover is auto-generated or custom, if a header thumbnail was present.
+    pub cover_source: Option<CoverSource>,
     /// Author/creator of the playlist, if available.
     pub author: Option<Author>,
-    /// Year created/updated, if present in the response.
-    pub year: Option<String>,
+    /// Year shown in the playlist header, if present.
+    pub year: Option<u16>,
     /// Human-readable duration (e.g., `"2 hours"`), if present.
     pub duration: Option<String>,
     /// Total duration in seconds, computed from parsed tracks.
-    pub duration_seconds: Option<u32>,
+    pub duration_seconds: Option<u64>,
     /// Number of tracks, if provided by the API.
     pub track_count: Option<u32>,
+    /// View count shown in the header of public playlists.
+    pub views: Option<u64>,
+    /// Like count shown in the header, if present.
+    pub likes: Option<u64>,
     /// Whether the current user owns this playlist.
     pub owned: bool,
     /// Playlist tracks.
     pub tracks: Vec<PlaylistTrack>,
+    /// Playlists from the "Related playlists" carousel, if the page shows one.
+    /// Some layouts only load it on request; see
+    /// [`GetPlaylistOptions::related`].
+    #[serde(default)]
+    pub related: Vec<PlaylistSummary>,
+    /// Track rows that could not be parsed, across all pages read; empty
+    /// normally. A non-empty list hints at a layout change.
+    #[serde(default)]
+    pub warnings: Vec<ParseWarning>,
 }
 
 /// A track within a playlist.
@@ -94,17 +194,410 @@ pub struct PlaylistTrack {
     pub is_explicit: bool,
     /// Unique playlist item ID used for removing/reordering.
     pub set_video_id: Option<String>,
-    /// Type of video (e.g., `"MUSIC_VIDEO_TYPE_OMV"`), if available.
-    pub video_type: Option<String>,
+    /// Type of video (e.g., [`MusicVideoType::Omv`]), if available.
+    pub video_type: Option<MusicVideoType>,
+    /// When the track was added, if the row shows it.
+    pub added_at: Option<AddedAt>,
+    /// Whether the track has lyrics. Only known for the current track of a
+    /// [`WatchPlaylist`](crate::WatchPlaylist), from its
+    /// [`lyrics_browse_id`](crate::WatchPlaylist::lyrics_browse_id); `false`
+    /// for every other track.
+    #[serde(default)]
+    pub has_lyrics: bool,
+}
+
+impl TrackLike for PlaylistTrack {
+    fn video_id(&self) -> Option<&str> {
+        self.video_id.as_deref()
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    fn artists(&self) -> &[Artist] {
+        &self.artists
+    }
+
+    fn duration_seconds(&self) -> Option<u32> {
+        self.duration_seconds
+    }
+
+    fn is_explicit(&self) -> Option<bool> {
+        Some(self.is_explicit)
+    }
+
+    fn is_available(&self) -> Option<bool> {
+        Some(self.is_available)
+    }
+
+    fn video_type(&self) -> Option<&MusicVideoType> {
+        self.video_type.as_ref()
+    }
+
+    fn to_playlist_track(&self) -> PlaylistTrack {
+        self.clone()
+    }
+}
+
+/// When an item was added to a playlist or library.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AddedAt {
+    /// Relative label as shown by the web client (e.g. `"2 weeks ago"`).
+    ///
+    /// Without the `chrono` feature, calendar dates are kept here as shown.
+    Relative(String),
+    /// Calendar date, when the row shows one.
+    #[cfg(feature = "chrono")]
+    Date(NaiveDate),
+}
+
+/// Options for [`YTMusicClient::get_playlist_with`](crate::YTMusicClient::get_playlist_with).
+///
+/// ```
+/// use ytmusicapi::GetPlaylistOptions;
+///
+/// let options = GetPlaylistOptions::new().limit(200).include_unavailable(false);
+/// assert_eq!(options.limit, Some(200));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GetPlaylistOptions {
+    /// Maximum number of tracks to return. `None` for all.
+    pub limit: Option<u32>,
+    /// Keep tracks that cannot be played (default: `true`). When `false`,
+    /// they are dropped and do not count towards `limit`.
+    pub include_unavailable: bool,
+    /// Load the sections below the tracks to fill [`Playlist::related`] when
+    /// the first page does not include them (default: `false`). Costs up to
+    /// two extra requests.
+    pub related: bool,
+}
+
+impl Default for GetPlaylistOptions {
+    fn default() -> Self {
+        Self {
+            limit: None,
+            include_unavailable: true,
+            related: false,
+        }
+    }
+}
+
+impl GetPlaylistOptions {
+    /// Options returning every track.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return at most `limit` tracks.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Keep or drop tracks that cannot be played.
+    pub fn include_unavailable(mut self, include: bool) -> Self {
+        self.include_unavailable = include;
+        self
+    }
+
+    /// Load the related playlists if the first page does not include them.
+    pub fn related(mut self, related: bool) -> Self {
+        self.related = related;
+        self
+    }
+}
+
+/// Options for
+/// [`YTMusicClient::create_playlist_with`](crate::YTMusicClient::create_playlist_with).
+///
+/// ```
+/// use ytmusicapi::{CreatePlaylistOptions, Privacy};
+///
+/// let options = CreatePlaylistOptions::new("Road Trip")
+///     .description("Songs for the drive")
+///     .privacy(Privacy::Private);
+/// assert_eq!(options.privacy, Privacy::Private);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CreatePlaylistOptions {
+    /// Playlist title. Must not be blank.
+    pub title: String,
+    /// Description. Omitted from the request when `None` or blank.
+    pub description: Option<String>,
+    /// Privacy status (default: [`Privacy::Public`]).
+    pub privacy: Privacy,
+}
+
+impl CreatePlaylistOptions {
+    /// Options for a public playlist titled `title`.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the privacy status.
+    pub fn privacy(mut self, privacy: Privacy) -> Self {
+        self.privacy = privacy;
+        self
+    }
+}
+
+/// Changes for [`YTMusicClient::edit_playlist`](crate::YTMusicClient::edit_playlist).
+///
+/// Fields left as `None` are not changed.
+///
+/// ```
+/// use ytmusicapi::{EditPlaylistOptions, Privacy};
+///
+/// let options = EditPlaylistOptions::new().title("Renamed").privacy(Privacy::Unlisted);
+/// assert!(options.description.is_none());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct EditPlaylistOptions {
+    /// New title. Must not be blank.
+    pub title: Option<String>,
+    /// New description. An empty string clears it.
+    pub description: Option<String>,
+    /// New privacy status.
+    pub privacy: Option<Privacy>,
+}
+
+impl EditPlaylistOptions {
+    /// Options changing nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a new title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set a new description; an empty string clears it.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set a new privacy status.
+    pub fn privacy(mut self, privacy: Privacy) -> Self {
+        self.privacy = Some(privacy);
+        self
+    }
+
+    /// Whether no change is set.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.description.is_none() && self.privacy.is_none()
+    }
+}
+
+/// Status of a `browse/edit_playlist` request, as reported by the server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EditStatus {
+    /// `STATUS_SUCCEEDED`: the edit was applied.
+    Succeeded,
+    /// `STATUS_FAILED`: the edit was rejected.
+    Failed,
+    /// Any other status, as sent; empty if the response had none.
+    Other(String),
+}
+
+impl EditStatus {
+    /// Read the `status` field of an edit response.
+    pub fn from_response(response: &Value) -> Self {
+        match response.get("status").and_then(Value::as_str) {
+            Some("STATUS_SUCCEEDED") => Self::Succeeded,
+            Some("STATUS_FAILED") => Self::Failed,
+            other => Self::Other(other.unwrap_or_default().to_string()),
+        }
+    }
+
+    /// Whether the edit was applied.
+    pub fn is_succeeded(&self) -> bool {
+        matches!(self, Self::Succeeded)
+    }
+}
+
+/// One entry of the `playlistEditResults` of an edit response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionResult {
+    /// Result key as sent, e.g. `"playlistEditVideoAddedResultData"`.
+    pub kind: String,
+    /// Video the action applied to, if reported.
+    pub video_id: Option<String>,
+    /// Playlist entry the action applied to, if reported.
+    pub set_video_id: Option<String>,
+}
+
+/// Response of a `browse/edit_playlist` request that removes or changes items.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EditPlaylistResponse {
+    /// Status reported by the server.
+    pub status: EditStatus,
+    /// Per-action results, in the order the server reports them. Often empty
+    /// for removals.
+    pub actions: Vec<ActionResult>,
+    /// Removed items that edit verification could not check because the
+    /// playlist is longer than the part it re-reads. Always empty without
+    /// [`with_edit_verification`](crate::YTMusicClientBuilder::with_edit_verification).
+    #[serde(default)]
+    pub unverified: Vec<String>,
+    /// The full response, for fields not parsed here.
+    pub raw: Value,
+}
+
+impl EditPlaylistResponse {
+    /// Read an edit response.
+    pub fn from_response(response: Value) -> Self {
+        let actions = response
+            .get("playlistEditResults")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_object)
+            .filter_map(|result| {
+                let (kind, data) = result.iter().next()?;
+                let text = |key| data.get(key).and_then(Value::as_str).map(str::to_string);
+                Some(ActionResult {
+                    kind: kind.clone(),
+                    video_id: text("videoId"),
+                    set_video_id: text("setVideoId"),
+                })
+            })
+            .collect();
+        Self {
+            status: EditStatus::from_response(&response),
+            actions,
+            unverified: Vec::new(),
+            raw: response,
+        }
+    }
+
+    /// Whether the edit was applied.
+    pub fn is_succeeded(&self) -> bool {
+        self.status.is_succeeded()
+    }
+}
+
+/// A video added by [`YTMusicClient::add_playlist_items`](crate::YTMusicClient::add_playlist_items).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddedItem {
+    /// Video ID of the added item.
+    pub video_id: String,
+    /// ID of the new playlist entry, for removing or moving it later without
+    /// fetching the playlist.
+    pub set_video_id: Option<String>,
+    /// Video title, when the response includes one.
+    pub title: Option<String>,
+}
+
+/// Result of adding items to a playlist.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AddPlaylistItemsResult {
+    /// Status reported by the server.
+    pub status: EditStatus,
+    /// Items the server added, in the order it reports them.
+    pub added: Vec<AddedItem>,
+    /// Requested video IDs the server did not add, in request order. With
+    /// [`DuplicateHandling::Skip`] these are the videos already in the
+    /// playlist. Empty unless the edit succeeded and the response lists what
+    /// was added.
+    pub skipped: Vec<String>,
+    /// Tracks dropped by a [`TrackFilter`](crate::TrackFilter) before the request was sent; all
+    /// zero unless added with
+    /// [`add_playlist_tracks_filtered`](crate::YTMusicClient::add_playlist_tracks_filtered).
+    #[serde(default)]
+    pub filtered: FilterCounts,
+    /// Added items that edit verification could not check because the
+    /// playlist is longer than the part it re-reads. Always empty without
+    /// [`with_edit_verification`](crate::YTMusicClientBuilder::with_edit_verification).
+    #[serde(default)]
+    pub unverified: Vec<String>,
+    /// The full response, for fields not parsed here.
+    pub raw: Value,
+}
+
+impl AddPlaylistItemsResult {
+    /// Read an edit response to a request adding `requested` video IDs.
+    pub fn from_response(response: Value, requested: &[String]) -> Self {
+        let status = EditStatus::from_response(&response);
+        let results = response
+            .get("playlistEditResults")
+            .and_then(Value::as_array);
+        let added: Vec<AddedItem> = results
+            .into_iter()
+            .flatten()
+            .filter_map(|result| {
+                let data = result.get("playlistEditVideoAddedResultData")?;
+                let text = |key| data.get(key).and_then(Value::as_str).map(str::to_string);
+                Some(AddedItem {
+                    video_id: text("videoId")?,
+                    set_video_id: text("setVideoId"),
+                    title: text("videoTitle"),
+                })
+            })
+            .collect();
+
+        let mut skipped = Vec::new();
+        if status.is_succeeded() && results.is_some() {
+            // Match added items one by one, so a video requested twice but
+            // added once counts as skipped once.
+            let mut unmatched: Vec<&str> =
+                added.iter().map(|item| item.video_id.as_str()).collect();
+            for video_id in requested {
+                match unmatched.iter().position(|id| id == video_id) {
+                    Some(index) => {
+                        unmatched.swap_remove(index);
+                    }
+                    None => skipped.push(video_id.clone()),
+                }
+            }
+        }
+
+        Self {
+            status,
+            added,
+            skipped,
+            filtered: FilterCounts::default(),
+            unverified: Vec::new(),
+            raw: response,
+        }
+    }
+
+    /// Whether the edit was applied.
+    pub fn is_succeeded(&self) -> bool {
+        self.status.is_succeeded()
+    }
+
+    /// Set video IDs of the added items, in order.
+    pub fn set_video_ids(&self) -> Vec<String> {
+        self.added
+            .iter()
+            .filter_map(|item| item.set_video_id.clone())
+            .collect()
+    }
 }
 
 /// Result of moving items between playlists.
 #[derive(Debug, Clone)]
 pub struct MovePlaylistItemsResult {
-    /// Response from adding items to the destination playlist.
-    pub add_response: Value,
-    /// Response from removing items from the source playlist.
-    pub remove_response: Value,
+    /// Result of adding the items to the destination playlist.
+    pub added: AddPlaylistItemsResult,
+    /// Result of removing the items from the source playlist.
+    pub removed: EditPlaylistResponse,
 }
 
 /// Response from creating a playlist.
@@ -119,17 +612,24 @@ impl Default for Playlist {
     fn default() -> Self {
         Self {
             id: String::new(),
+            requested_id: String::new(),
             title: String::new(),
             description: None,
+            description_runs: Vec::new(),
             privacy: Privacy::Public,
             thumbnails: Vec::new(),
+            cover_source: None,
             author: None,
             year: None,
             duration: None,
             duration_seconds: None,
             track_count: None,
+            views: None,
+            likes: None,
             owned: false,
             tracks: Vec::new(),
+            related: Vec::new(),
+            warnings: Vec::new(),
         }
     }
 }
@@ -148,6 +648,8 @@ impl Default for PlaylistTrack {
             is_explicit: false,
             set_video_id: None,
             video_type: None,
+            added_at: None,
+            has_lyrics: false,
         }
     }
 }
@@ -0,0 +1,223 @@
+//! Search result types.
+
+use std::ops::Deref;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Album, Artist, MusicVideoType, Thumbnail, TrackLike};
+
+/// Kind of item returned by a search.
+///
+/// Determined from the item's navigation endpoint rather than its localized
+/// label, so it does not depend on the client language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchResultType {
+    /// Audio track.
+    Song,
+    /// Music video or other non-audio-track video.
+    Video,
+    /// Album, single or EP.
+    Album,
+    /// Artist channel.
+    Artist,
+    /// Playlist.
+    Playlist,
+    /// Podcast show.
+    Podcast,
+    /// Podcast episode.
+    Episode,
+    /// User profile.
+    Profile,
+    /// Anything the parser does not recognise.
+    Other,
+}
+
+/// Restrict a search to one kind of result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchFilter {
+    /// Audio tracks.
+    Songs,
+    /// Music videos.
+    Videos,
+    /// Albums, singles and EPs.
+    Albums,
+    /// Artist channels.
+    Artists,
+    /// Playlists created by users.
+    CommunityPlaylists,
+    /// Playlists curated by YouTube Music.
+    FeaturedPlaylists,
+    /// Podcast shows.
+    Podcasts,
+    /// Podcast episodes.
+    Episodes,
+}
+
+impl SearchFilter {
+    /// The `params` value the web client sends for this filter.
+    ///
+    /// These are opaque protobuf strings, copied from the Python ytmusicapi.
+    pub(crate) fn params(self) -> &'static str {
+        match self {
+            SearchFilter::Songs => "EgWKAQIIAWoMEA4QChADEAQQCRAF",
+            SearchFilter::Videos => "EgWKAQIQAWoMEA4QChADEAQQCRAF",
+            SearchFilter::Albums => "EgWKAQIYAWoMEA4QChADEAQQCRAF",
+            SearchFilter::Artists => "EgWKAQIgAWoMEA4QChADEAQQCRAF",
+            SearchFilter::CommunityPlaylists => "EgeKAQQoAEABagwQDhAKEAMQBBAJEAU%3D",
+            SearchFilter::FeaturedPlaylists => "EgeKAQQoADgBagwQDhAKEAMQBBAJEAU%3D",
+            SearchFilter::Podcasts => "EgWKAQJQAWoMEA4QChADEAQQCRAF",
+            SearchFilter::Episodes => "EgWKAQJIAWoMEA4QChADEAQQCRAF",
+        }
+    }
+
+    /// Filter code used inside library-scoped `params`, if the filter is
+    /// available there.
+    pub(crate) fn library_code(self) -> Option<&'static str> {
+        match self {
+            SearchFilter::Songs => Some("II"),
+            SearchFilter::Videos => Some("IQ"),
+            SearchFilter::Albums => Some("IY"),
+            SearchFilter::Artists => Some("Ig"),
+            SearchFilter::Podcasts => Some("JQ"),
+            SearchFilter::Episodes => Some("JI"),
+            SearchFilter::CommunityPlaylists | SearchFilter::FeaturedPlaylists => None,
+        }
+    }
+
+    /// The result type this filter returns.
+    pub fn result_type(self) -> SearchResultType {
+        match self {
+            SearchFilter::Songs => SearchResultType::Song,
+            SearchFilter::Videos => SearchResultType::Video,
+            SearchFilter::Albums => SearchResultType::Album,
+            SearchFilter::Artists => SearchResultType::Artist,
+            SearchFilter::CommunityPlaylists | SearchFilter::FeaturedPlaylists => {
+                SearchResultType::Playlist
+            }
+            SearchFilter::Podcasts => SearchResultType::Podcast,
+            SearchFilter::Episodes => SearchResultType::Episode,
+        }
+    }
+}
+
+/// Search only within the signed-in user's own content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchScope {
+    /// Songs, albums, artists and playlists saved to the library.
+    Library,
+    /// Songs the user uploaded.
+    Uploads,
+}
+
+/// A single search result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    /// Kind of result.
+    pub result_type: SearchResultType,
+    /// Display title.
+    pub title: String,
+    /// Video ID for songs, videos and episodes.
+    pub video_id: Option<String>,
+    /// Browse ID for albums, artists, playlists, podcasts and profiles.
+    pub browse_id: Option<String>,
+    /// Credited artists, if listed.
+    pub artists: Vec<Artist>,
+    /// Album, for songs.
+    pub album: Option<Album>,
+    /// Human-readable duration (e.g., `"3:45"`), if listed.
+    pub duration: Option<String>,
+    /// Duration in seconds, parsed from `duration`.
+    pub duration_seconds: Option<u32>,
+    /// Release year, for albums.
+    pub year: Option<u16>,
+    /// Thumbnail images.
+    pub thumbnails: Vec<Thumbnail>,
+    /// Kind of video backing a playable result.
+    pub video_type: Option<MusicVideoType>,
+    /// Whether the result is marked explicit.
+    pub is_explicit: bool,
+}
+
+impl TrackLike for SearchResult {
+    fn video_id(&self) -> Option<&str> {
+        self.video_id.as_deref()
+    }
+
+    fn title(&self) -> Option<&str> {
+        Some(&self.title)
+    }
+
+    fn artists(&self) -> &[Artist] {
+        &self.artists
+    }
+
+    fn duration_seconds(&self) -> Option<u32> {
+        self.duration_seconds
+    }
+
+    fn is_explicit(&self) -> Option<bool> {
+        Some(self.is_explicit)
+    }
+
+    fn video_type(&self) -> Option<&MusicVideoType> {
+        self.video_type.as_ref()
+    }
+}
+
+/// A query suggestion for partially typed search input.
+///
+/// Returned by
+/// [`YTMusicClient::get_search_suggestions_detailed`](crate::YTMusicClient::get_search_suggestions_detailed).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchSuggestion {
+    /// Suggested query. The web client bolds the part not typed yet; the runs
+    /// are joined here.
+    pub text: String,
+    /// Whether the suggestion is a previous search of the signed-in user.
+    pub from_history: bool,
+    /// Token that removes a history suggestion, if this is one.
+    pub feedback_token: Option<String>,
+}
+
+/// Search results with the token for the next page.
+///
+/// Dereferences to a slice of [`SearchResult`], so it can be indexed and
+/// iterated like a `Vec`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchResults {
+    /// Results in server order.
+    pub results: Vec<SearchResult>,
+    /// Token for the next page, if more results are available. Pass it to
+    /// [`YTMusicClient::search_continuation`](crate::YTMusicClient::search_continuation)
+    /// to resume without re-issuing the query.
+    pub continuation: Option<String>,
+}
+
+impl Deref for SearchResults {
+    type Target = [SearchResult];
+
+    fn deref(&self) -> &Self::Target {
+        &self.results
+    }
+}
+
+impl IntoIterator for SearchResults {
+    type Item = SearchResult;
+    type IntoIter = std::vec::IntoIter<SearchResult>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a SearchResults {
+    type Item = &'a SearchResult;
+    type IntoIter = std::slice::Iter<'a, SearchResult>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.iter()
+    }
+}
@@ -1,4 +1,8 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::de::string_or_number;
+use super::{ExternalIds, LikeStatus, Thumbnail};
 
 /// Metadata returned by the `player` endpoint.
 ///
@@ -11,12 +15,82 @@ pub struct Song {
     pub video_details: VideoDetails,
     /// Optional microformat metadata.
     pub microformat: Option<Microformat>,
+    /// ISRC and similar identifiers, when the microformat carries them.
+    #[serde(default)]
+    pub external_ids: Option<ExternalIds>,
+    /// Playback reporting URLs, if present. Used by
+    /// [`YTMusicClient::add_history_item`](crate::YTMusicClient::add_history_item).
+    #[serde(default)]
+    pub playback_tracking: Option<PlaybackTracking>,
+    /// Whether the track can be played, as reported by the player.
+    #[serde(default)]
+    pub playability_status: Option<PlayabilityStatus>,
+    /// Why the track cannot be played, when the player refuses it.
+    #[serde(default)]
+    pub unplayable: Option<Unplayable>,
+}
+
+/// The `playabilityStatus` of a player response.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayabilityStatus {
+    /// Status constant, e.g. `"OK"`, `"LOGIN_REQUIRED"` or `"UNPLAYABLE"`.
+    pub status: String,
+    /// Human-readable reason, in the response language, if given.
+    pub reason: Option<String>,
+}
+
+impl PlayabilityStatus {
+    /// Whether the track is playable.
+    pub fn is_ok(&self) -> bool {
+        self.status == "OK"
+    }
+}
+
+/// Why the player refuses a track.
+///
+/// Use it to tell tracks that an account change could make playable again
+/// from those that are gone.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Unplayable {
+    /// The viewer must be signed in and confirm their age.
+    AgeRestricted,
+    /// The track needs a YouTube Music Premium membership.
+    PremiumRequired,
+    /// The track is not available in the viewer's country.
+    RegionBlocked,
+    /// Any other refusal, e.g. a deleted or private video.
+    Other {
+        /// Status constant of the player response.
+        status: String,
+        /// Reason shown by the player, if given.
+        reason: Option<String>,
+    },
+}
+
+/// Playback reporting URLs of a [`Song`].
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaybackTracking {
+    /// URL that records a play, if present.
+    pub videostats_playback_url: Option<TrackingUrl>,
+    /// URL that records watch time, if present.
+    pub videostats_watchtime_url: Option<TrackingUrl>,
+}
+
+/// A playback reporting URL.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackingUrl {
+    /// URL without the per-playback parameters.
+    pub base_url: String,
 }
 
 /// Core video metadata.
 ///
-/// Note that numeric values like `length_seconds` and `view_count` are returned
-/// as strings by the API.
+/// Note that numeric values like `length_seconds` and `view_count` are kept
+/// as strings. The API usually sends them as strings, but numbers are
+/// accepted too.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VideoDetails {
@@ -27,11 +101,44 @@ pub struct VideoDetails {
     /// Author/artist as presented by the API.
     pub author: String,
     /// Length in seconds, represented as a string.
+    #[serde(deserialize_with = "string_or_number")]
     pub length_seconds: String,
     /// View count, represented as a string.
+    #[serde(deserialize_with = "string_or_number")]
     pub view_count: String,
     /// Keyword tags, if present.
     pub keywords: Option<Vec<String>>,
+    /// Video thumbnails in every size the API lists. Use
+    /// [`Thumbnails`](crate::Thumbnails) to pick one.
+    #[serde(rename = "thumbnail", with = "thumbnail_list", default)]
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+/// (De)serialize thumbnails in the API's `{"thumbnails": [...]}` wrapper.
+mod thumbnail_list {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::types::{Thumbnail, sort_thumbnails};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper<T> {
+        thumbnails: T,
+    }
+
+    pub fn serialize<S: Serializer>(
+        thumbnails: &[Thumbnail],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Wrapper { thumbnails }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Thumbnail>, D::Error> {
+        Ok(sort_thumbnails(
+            Wrapper::deserialize(deserializer)?.thumbnails,
+        ))
+    }
 }
 
 /// Microformat wrapper.
@@ -51,7 +158,20 @@ pub struct MicroformatDataRenderer {
     /// Upload date as provided by the API.
     pub upload_date: String,
     /// View count, represented as a string.
+    #[serde(deserialize_with = "string_or_number")]
     pub view_count: String,
     /// Tags, if present.
     pub tags: Option<Vec<String>>,
 }
+
+/// Result of [`YTMusicClient::rate_song`](crate::YTMusicClient::rate_song).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RateSongResponse {
+    /// Rating the song now has. The server accepts a rating without echoing
+    /// it, so this is the rating that was sent.
+    pub like_status: LikeStatus,
+    /// Confirmation text the web client would show, if the response has one.
+    pub feedback: Option<String>,
+    /// The full response, for fields not parsed here.
+    pub raw: Value,
+}
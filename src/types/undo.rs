@@ -0,0 +1,210 @@
+//! Undo support for playlist edits.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AddPlaylistItemsResult, MovePlaylistItemsResult, PlaylistTrack};
+
+/// Operations that reverse one or more playlist edits.
+///
+/// Build one from the result of an edit, combine tokens with
+/// [`extend`](Self::extend), and apply it with
+/// [`YTMusicClient::undo`](crate::YTMusicClient::undo). Steps are undone
+/// newest first.
+///
+/// ```
+/// use ytmusicapi::{PlaylistTrack, UndoToken};
+///
+/// let removed = vec![PlaylistTrack {
+///     video_id: Some("dQw4w9WgXcQ".to_string()),
+///     ..Default::default()
+/// }];
+/// let token = UndoToken::for_removed("PL123", &removed);
+/// assert_eq!(token.steps.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UndoToken {
+    /// Steps in the order of the edits they reverse.
+    pub steps: Vec<UndoStep>,
+}
+
+/// One operation of an [`UndoToken`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum UndoStep {
+    /// Remove items an edit added. Each item carries its `video_id` and
+    /// `set_video_id`.
+    RemoveItems {
+        /// Playlist the items were added to.
+        playlist_id: String,
+        /// Items to remove.
+        items: Vec<PlaylistTrack>,
+    },
+    /// Add back items an edit removed, in their original order.
+    RestoreItems {
+        /// Playlist the items were removed from.
+        playlist_id: String,
+        /// Items to add back.
+        items: Vec<PlaylistTrack>,
+    },
+}
+
+impl UndoToken {
+    /// Undo [`YTMusicClient::add_playlist_items`](crate::YTMusicClient::add_playlist_items)
+    /// and friends, given their result.
+    ///
+    /// Only items the server confirmed as added, with the
+    /// [`set_video_id`](crate::AddedItem::set_video_id) that removes them,
+    /// are covered.
+    pub fn for_added(playlist_id: &str, result: &AddPlaylistItemsResult) -> Self {
+        let items: Vec<_> = result
+            .added
+            .iter()
+            .filter_map(|item| {
+                Some(PlaylistTrack {
+                    video_id: Some(item.video_id.clone()),
+                    set_video_id: Some(item.set_video_id.clone()?),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        Self::step(
+            !items.is_empty(),
+            UndoStep::RemoveItems {
+                playlist_id: playlist_id.to_string(),
+                items,
+            },
+        )
+    }
+
+    /// Undo a removal, given the removed items, e.g. from
+    /// [`YTMusicClient::remove_playlist_range`](crate::YTMusicClient::remove_playlist_range).
+    ///
+    /// Items without a video ID cannot be restored and are left out.
+    pub fn for_removed(playlist_id: &str, removed: &[PlaylistTrack]) -> Self {
+        let items: Vec<_> = removed
+            .iter()
+            .filter(|item| item.video_id.is_some())
+            .cloned()
+            .collect();
+        Self::step(
+            !items.is_empty(),
+            UndoStep::RestoreItems {
+                playlist_id: playlist_id.to_string(),
+                items,
+            },
+        )
+    }
+
+    /// Undo [`YTMusicClient::move_playlist_items`](crate::YTMusicClient::move_playlist_items),
+    /// given its result and the items that were moved.
+    ///
+    /// The items are restored to the source before the copies are removed
+    /// from the destination.
+    pub fn for_moved(
+        from_playlist_id: &str,
+        to_playlist_id: &str,
+        result: &MovePlaylistItemsResult,
+        items: &[PlaylistTrack],
+    ) -> Self {
+        let mut token = Self::for_added(to_playlist_id, &result.added);
+        token.extend(Self::for_removed(from_playlist_id, items));
+        token
+    }
+
+    /// Append the steps of a later edit.
+    pub fn extend(&mut self, later: UndoToken) {
+        self.steps.extend(later.steps);
+    }
+
+    /// Whether there is nothing to undo.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    fn step(keep: bool, step: UndoStep) -> Self {
+        Self {
+            steps: if keep { vec![step] } else { Vec::new() },
+        }
+    }
+}
+
+/// Outcome of [`YTMusicClient::undo`](crate::YTMusicClient::undo).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UndoReport {
+    /// Video IDs removed again.
+    pub removed: Vec<String>,
+    /// Video IDs added back. They are appended to the end of their playlist:
+    /// the API cannot insert at a position, so original positions are lost.
+    pub restored: Vec<String>,
+    /// Whether any restored item may be out of its original position.
+    pub positions_approximate: bool,
+    /// Steps that could not be applied, with the error message. They can be
+    /// retried as a new [`UndoToken`].
+    pub failed: Vec<(UndoStep, String)>,
+}
+
+impl UndoReport {
+    /// Whether every step was applied.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EditPlaylistResponse;
+    use serde_json::json;
+
+    #[test]
+    fn test_for_added_reads_confirmed_items() {
+        let response = json!({
+            "status": "STATUS_SUCCEEDED",
+            "playlistEditResults": [
+                { "playlistEditVideoAddedResultData": { "videoId": "a", "setVideoId": "sa" } },
+                { "playlistEditVideoAddedResultData": { "videoId": "b" } },
+                { "somethingElse": {} }
+            ]
+        });
+        let requested = ["a".to_string(), "b".to_string()];
+        let result = AddPlaylistItemsResult::from_response(response, &requested);
+        let token = UndoToken::for_added("PL1", &result);
+        let [UndoStep::RemoveItems { playlist_id, items }] = token.steps.as_slice() else {
+            panic!("expected one removal step");
+        };
+        assert_eq!(playlist_id, "PL1");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].set_video_id.as_deref(), Some("sa"));
+
+        let failed =
+            AddPlaylistItemsResult::from_response(json!({ "status": "STATUS_FAILED" }), &requested);
+        assert!(UndoToken::for_added("PL1", &failed).is_empty());
+    }
+
+    #[test]
+    fn test_for_moved_restores_before_removing() {
+        let moved = [PlaylistTrack {
+            video_id: Some("a".to_string()),
+            set_video_id: Some("old".to_string()),
+            ..Default::default()
+        }];
+        let added = json!({
+            "status": "STATUS_SUCCEEDED",
+            "playlistEditResults": [
+                { "playlistEditVideoAddedResultData": { "videoId": "a", "setVideoId": "new" } }
+            ]
+        });
+        let result = MovePlaylistItemsResult {
+            added: AddPlaylistItemsResult::from_response(added, &["a".to_string()]),
+            removed: EditPlaylistResponse::from_response(json!({})),
+        };
+        let token = UndoToken::for_moved("PLfrom", "PLto", &result, &moved);
+        // Applied newest first: restore to the source, then remove the copy.
+        assert!(matches!(
+            token.steps.as_slice(),
+            [
+                UndoStep::RemoveItems { playlist_id: to, .. },
+                UndoStep::RestoreItems { playlist_id: from, .. },
+            ] if to == "PLto" && from == "PLfrom"
+        ));
+    }
+}
@@ -0,0 +1,101 @@
+//! Uploaded music types.
+
+use serde::{Deserialize, Serialize};
+
+use super::{Album, Artist, LikeStatus, Thumbnail, TrackLike};
+
+/// A song the user uploaded.
+///
+/// Returned by
+/// [`YTMusicClient::get_library_upload_songs`](crate::YTMusicClient::get_library_upload_songs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadTrack {
+    /// ID that deletes the upload.
+    pub entity_id: String,
+    /// Video ID (used for playback), if the upload has been processed.
+    pub video_id: Option<String>,
+    /// Track title, from the file's tags or name.
+    pub title: String,
+    /// Artists from the file's tags.
+    pub artists: Vec<Artist>,
+    /// Album from the file's tags, linking to the upload album page.
+    pub album: Option<Album>,
+    /// Human-readable duration (e.g., `"3:42"`), if shown.
+    pub duration: Option<String>,
+    /// Duration in seconds, if parsed successfully.
+    pub duration_seconds: Option<u32>,
+    /// Cover images.
+    pub thumbnails: Vec<Thumbnail>,
+    /// The user's rating, if the row shows a like button.
+    pub like_status: Option<LikeStatus>,
+}
+
+impl TrackLike for UploadTrack {
+    fn video_id(&self) -> Option<&str> {
+        self.video_id.as_deref()
+    }
+
+    fn title(&self) -> Option<&str> {
+        Some(&self.title)
+    }
+
+    fn artists(&self) -> &[Artist] {
+        &self.artists
+    }
+
+    fn duration_seconds(&self) -> Option<u32> {
+        self.duration_seconds
+    }
+}
+
+/// An album of uploaded songs.
+///
+/// Returned by
+/// [`YTMusicClient::get_library_upload_albums`](crate::YTMusicClient::get_library_upload_albums).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadAlbum {
+    /// ID that deletes the album's uploads.
+    pub entity_id: String,
+    /// Browse ID of the album page
+    /// (`FEmusic_library_privately_owned_release_detail...`).
+    pub browse_id: String,
+    /// Album title.
+    pub title: String,
+    /// Album artists.
+    pub artists: Vec<Artist>,
+    /// Release year, if the tags carry one.
+    pub year: Option<u16>,
+    /// Cover images.
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+/// An artist of uploaded songs.
+///
+/// Returned by
+/// [`YTMusicClient::get_library_upload_artists`](crate::YTMusicClient::get_library_upload_artists).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadArtist {
+    /// Artist name.
+    pub name: String,
+    /// Browse ID of the artist's uploads
+    /// (`FEmusic_library_privately_owned_artist_detail...`).
+    pub browse_id: String,
+    /// Number of uploaded songs by the artist, if shown.
+    pub songs: Option<u32>,
+    /// Profile images.
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+/// Outcome of [`YTMusicClient::upload_song`](crate::YTMusicClient::upload_song).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UploadStatus {
+    /// The server accepted the file (`STATUS_SUCCEEDED`).
+    Succeeded,
+    /// The server refused the file.
+    Failed {
+        /// HTTP status of the refusal.
+        status: u16,
+        /// Response body, which usually explains the refusal.
+        message: String,
+    },
+}
@@ -0,0 +1,25 @@
+//! User channel types.
+
+use serde::{Deserialize, Serialize};
+
+use super::{ArtistSection, ArtistVideo, PlaylistSummary, Thumbnail};
+
+/// A user's channel page, such as a public playlist's author.
+///
+/// Returned by [`YTMusicClient::get_user`](crate::YTMusicClient::get_user).
+/// Sections the channel does not show are `None`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UserPage {
+    /// Channel ID (`UC...`).
+    pub channel_id: String,
+    /// Channel name.
+    pub name: String,
+    /// Profile images.
+    pub thumbnails: Vec<Thumbnail>,
+    /// Public playlists. Pass the section's `browse_id` and `params` to
+    /// [`YTMusicClient::get_user_playlists`](crate::YTMusicClient::get_user_playlists)
+    /// for all of them.
+    pub playlists: Option<ArtistSection<PlaylistSummary>>,
+    /// Uploaded videos.
+    pub videos: Option<ArtistSection<ArtistVideo>>,
+}
@@ -0,0 +1,43 @@
+//! Watch queue types.
+
+use serde::{Deserialize, Serialize};
+
+use super::{PlaylistTrack, SearchResult};
+
+/// The up-next queue of a song or playlist, as the player shows it.
+///
+/// Returned by
+/// [`YTMusicClient::get_watch_playlist`](crate::YTMusicClient::get_watch_playlist).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WatchPlaylist {
+    /// Queued tracks, starting with the current one. Tracks that cannot be
+    /// played are kept with `is_available` set to `false`.
+    pub tracks: Vec<PlaylistTrack>,
+    /// ID of the playlist the queue plays, such as an `RDAMVM...` radio.
+    pub playlist_id: Option<String>,
+    /// Browse ID of the current track's lyrics (`MPLY...`), or `None` if the
+    /// track has no lyrics.
+    pub lyrics_browse_id: Option<String>,
+    /// Browse ID of the tracks, artists and playlists related to the current
+    /// track (`MPTR...`), for
+    /// [`YTMusicClient::get_song_related`](crate::YTMusicClient::get_song_related).
+    pub related_browse_id: Option<String>,
+    /// ID of the mix that autoplay continues with once the queue ends.
+    pub automix_playlist_id: Option<String>,
+}
+
+/// One section of a track's related tab, such as "You might also like",
+/// "Recommended playlists" or "About the artist".
+///
+/// Returned by
+/// [`YTMusicClient::get_song_related`](crate::YTMusicClient::get_song_related).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RelatedSection {
+    /// Section heading as shown, in the client language.
+    pub title: String,
+    /// Songs, videos, artists, albums or playlists in the section. Empty for
+    /// text sections.
+    pub items: Vec<SearchResult>,
+    /// Text of a text section such as "About the artist".
+    pub description: Option<String>,
+}
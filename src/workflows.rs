@@ -0,0 +1,206 @@
+//! Multi-step workflows built on top of [`YTMusicClient`].
+//!
+//! These functions orchestrate several API calls, possibly across clients, and
+//! encode the safety checks that are easy to get wrong in application code.
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::YTMusicClient;
+use crate::error::{Error, Result};
+use crate::types::{DuplicateHandling, EditStatus, Playlist};
+
+/// Outcome of [`transfer_playlist`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferReport {
+    /// Playlist ID on the source account.
+    pub source_id: String,
+    /// ID of the copy created on the destination account.
+    pub dest_id: String,
+    /// Number of tracks with a video ID on the source playlist.
+    pub expected: usize,
+    /// Number of tracks with a video ID found on the copy after the transfer;
+    /// 0 if the copy could not be filled or re-fetched.
+    pub observed: usize,
+    /// Whether the copy was verified to contain every track.
+    pub verified: bool,
+    /// Whether the original playlist was deleted.
+    pub original_deleted: bool,
+    /// Why the transfer stopped after the copy was created, if it did.
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+fn video_ids(playlist: &Playlist) -> Vec<String> {
+    playlist
+        .tracks
+        .iter()
+        .filter_map(|t| t.video_id.clone())
+        .collect()
+}
+
+/// Add `ids` to the new copy and return the video IDs it then holds.
+async fn fill_copy(dest: &YTMusicClient, copy_id: &str, ids: &[String]) -> Result<Vec<String>> {
+    if !ids.is_empty() {
+        let added = dest
+            .add_playlist_items(copy_id, ids, DuplicateHandling::Allow)
+            .await?;
+        let reported = match &added.status {
+            EditStatus::Succeeded => None,
+            EditStatus::Failed => Some("STATUS_FAILED"),
+            EditStatus::Other(status) if status.is_empty() => Some("no status"),
+            EditStatus::Other(status) => Some(status.as_str()),
+        };
+        if let Some(reported) = reported {
+            return Err(Error::NotApplied(format!(
+                "adding tracks to the copy: the server reported {reported}"
+            )));
+        }
+    }
+    let copy = dest.get_playlist(copy_id, None).await?;
+    Ok(video_ids(&copy))
+}
+
+/// Copy a playlist from one account to another, optionally deleting the original.
+///
+/// The API has no real ownership transfer; this emulates one:
+///
+/// 1. Check that `dest` can create playlists, then fetch the playlist with
+///    `source`.
+/// 2. Create a playlist with the same title, description and privacy with `dest`
+///    and add every track (duplicates included, in source order).
+/// 3. Re-fetch the copy and verify it holds the same video IDs in the same order.
+/// 4. If `delete_original` is set **and** verification succeeded, delete the
+///    original with `source`.
+///
+/// Errors before the copy is created are returned as is. After that, nothing
+/// is returned early: if adding the tracks fails or is not applied, or the
+/// copy cannot be re-fetched, the report has `verified == false`, names the
+/// partial copy in `dest_id` so callers can inspect or remove it, and carries
+/// the reason in `error`. The original is only deleted after a successful
+/// verification; if that deletion fails, `original_deleted` is `false` and the
+/// reason is in `error`. Tracks without a video ID (e.g. unavailable tracks) cannot be
+/// copied and are not counted.
+pub async fn transfer_playlist(
+    source: &YTMusicClient,
+    dest: &YTMusicClient,
+    playlist_id: &str,
+    delete_original: bool,
+) -> Result<TransferReport> {
+    // Caches dest's capabilities so create_playlist fails before any work
+    // if the account has no channel.
+    dest.get_capabilities().await?;
+
+    let original = source.get_playlist(playlist_id, None).await?;
+    let source_ids = video_ids(&original);
+
+    let created = dest
+        .create_playlist(
+            &original.title,
+            original.description.as_deref(),
+            original.privacy,
+        )
+        .await?;
+
+    let mut error = None;
+    let copy_ids = match fill_copy(dest, &created.playlist_id, &source_ids).await {
+        Ok(copy_ids) => copy_ids,
+        Err(e) => {
+            error = Some(e.to_string());
+            Vec::new()
+        }
+    };
+    let verified = error.is_none() && source_ids == copy_ids;
+
+    let mut original_deleted = false;
+    if delete_original && verified {
+        match source.delete_playlist(&original.id).await {
+            Ok(()) => original_deleted = true,
+            Err(e) => error = Some(e.to_string()),
+        }
+    }
+
+    Ok(TransferReport {
+        source_id: original.id,
+        dest_id: created.playlist_id,
+        expected: source_ids.len(),
+        observed: copy_ids.len(),
+        verified,
+        original_deleted,
+        error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{ScratchDir, fixture_client};
+
+    const SOURCE_ID: &str = "RDCLAK5uy_fixture000000000000000000000000";
+
+    /// A client that serves the `public` playlist fixture as `SOURCE_ID`, can
+    /// create playlists, and answers `browse/edit_playlist` with `edit`.
+    fn transfer_client(dir: &ScratchDir, edit: &str) -> YTMusicClient {
+        let fixture =
+            |path: &str| std::fs::read_to_string(format!("tests/fixtures/{path}")).unwrap();
+        dir.write(
+            "account/account_menu/default.json",
+            &fixture("account_menu/free_with_channel.json"),
+        );
+        dir.write(
+            &format!("browse/VL{SOURCE_ID}.json"),
+            &fixture("playlist/public.json"),
+        );
+        dir.write(
+            "playlist/create/default.json",
+            r#"{"playlistId": "PLfixturecopy"}"#,
+        );
+        dir.write("browse/edit_playlist/default.json", edit);
+        dir.write("playlist/delete/default.json", "{}");
+        fixture_client(dir)
+    }
+
+    async fn transfer(client: &YTMusicClient) -> TransferReport {
+        transfer_playlist(client, client, SOURCE_ID, true)
+            .await
+            .unwrap()
+    }
+
+    fn sent_deletes(client: &YTMusicClient) -> usize {
+        client
+            .fixture_requests()
+            .iter()
+            .filter(|(endpoint, _)| endpoint == "playlist/delete")
+            .count()
+    }
+
+    #[tokio::test]
+    async fn failed_add_keeps_the_original_and_names_the_copy() {
+        let dir = ScratchDir::new("transfer-add");
+        let client = transfer_client(&dir, r#"{"status": "STATUS_FAILED"}"#);
+
+        let report = transfer(&client).await;
+        assert_eq!(report.dest_id, "PLfixturecopy");
+        assert_eq!(report.expected, 2);
+        assert!(!report.verified);
+        assert!(!report.original_deleted);
+        let error = report.error.unwrap();
+        assert!(error.starts_with("Not applied:"), "{error}");
+        assert!(error.contains("STATUS_FAILED"), "{error}");
+        assert_eq!(sent_deletes(&client), 0);
+    }
+
+    #[tokio::test]
+    async fn failed_refetch_keeps_the_original_and_names_the_copy() {
+        let dir = ScratchDir::new("transfer-refetch");
+        // No response is recorded for the copy, so re-fetching it fails.
+        let client = transfer_client(&dir, r#"{"status": "STATUS_SUCCEEDED"}"#);
+
+        let report = transfer(&client).await;
+        assert_eq!(report.dest_id, "PLfixturecopy");
+        assert!(!report.verified);
+        assert!(!report.original_deleted);
+        let error = report.error.unwrap();
+        assert!(error.contains("VLPLfixturecopy"), "{error}");
+        assert_eq!(sent_deletes(&client), 0);
+    }
+}
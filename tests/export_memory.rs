@@ -0,0 +1,179 @@
+//! Peak heap use of playlist exports.
+//!
+//! Measured with a counting global allocator, so this lives in its own test
+//! binary where the allocator cannot affect the library's other tests.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde_json::{Value, json};
+use ytmusicapi::YTMusicClient;
+use ytmusicapi::export::ExportFormat;
+
+/// Tracks live and peak heap bytes per thread, so concurrently running
+/// tests do not disturb each other's measurements.
+struct CountingAllocator;
+
+thread_local! {
+    static LIVE: Cell<usize> = const { Cell::new(0) };
+    static PEAK: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = LIVE.try_with(|live| {
+            live.set(live.get() + layout.size());
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+        });
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = LIVE.try_with(|live| live.set(live.get().saturating_sub(layout.size())));
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Tracks per recorded page, as the web client serves them.
+const PAGE: usize = 100;
+
+/// A fixture directory for one test, removed when dropped.
+struct Recording(PathBuf);
+
+impl Recording {
+    fn new(test: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("ytmusicapi-{test}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(path.join("browse")).unwrap();
+        Self(path)
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+
+    fn write(&self, relative: &str, contents: &Value) {
+        std::fs::write(self.0.join(relative), contents.to_string()).unwrap();
+    }
+}
+
+impl Drop for Recording {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// The rows of page `page` of `pages`, ending with a continuation to the
+/// next page unless it is the last.
+fn page_rows(page: usize, pages: usize) -> Value {
+    let mut rows: Vec<Value> = (page * PAGE..(page + 1) * PAGE)
+        .map(|i| {
+            let video_id = format!("v{i:05}");
+            json!({
+                "musicResponsiveListItemRenderer": {
+                    "playlistItemData": { "videoId": video_id },
+                    "flexColumns": [{
+                        "musicResponsiveListItemFlexColumnRenderer": {
+                            "text": { "runs": [{ "text": format!("Track {video_id}") }] }
+                        }
+                    }],
+                    "fixedColumns": [{
+                        "musicResponsiveListItemFixedColumnRenderer": {
+                            "text": { "simpleText": "3:42" }
+                        }
+                    }]
+                }
+            })
+        })
+        .collect();
+    if page + 1 < pages {
+        rows.push(json!({
+            "continuationItemRenderer": {
+                "continuationEndpoint": {
+                    "continuationCommand": { "token": format!("page{}", page + 1) }
+                }
+            }
+        }));
+    }
+    Value::Array(rows)
+}
+
+/// Set the `contents` of every playlist shelf in `value`.
+fn fill_playlist_shelf(value: &mut Value, contents: &Value) {
+    match value {
+        Value::Object(fields) => {
+            if let Some(shelf) = fields.get_mut("musicPlaylistShelfRenderer") {
+                shelf["contents"] = contents.clone();
+            }
+            fields
+                .values_mut()
+                .for_each(|field| fill_playlist_shelf(field, contents));
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| fill_playlist_shelf(item, contents)),
+        _ => {}
+    }
+}
+
+/// Record `PLlong` as a playlist of `pages` pages of tracks.
+fn record_playlist(recording: &Recording, pages: usize) {
+    let mut first: Value = serde_json::from_str(
+        &std::fs::read_to_string("tests/fixtures/playlist/empty.json").unwrap(),
+    )
+    .unwrap();
+    fill_playlist_shelf(&mut first, &page_rows(0, pages));
+    recording.write("browse/VLPLlong.json", &first);
+    for page in 1..pages {
+        recording.write(
+            &format!("browse/page{page}.json"),
+            &json!({
+                "onResponseReceivedActions": [{
+                    "appendContinuationItemsAction": {
+                        "continuationItems": page_rows(page, pages)
+                    }
+                }]
+            }),
+        );
+    }
+}
+
+/// Export a recorded playlist of `pages` pages to a discarding sink,
+/// returning the number of tracks written and the peak heap growth in bytes.
+async fn export_peak(pages: usize) -> (usize, usize) {
+    let recording = Recording::new(&format!("export-memory-{pages}"));
+    record_playlist(&recording, pages);
+    let client = YTMusicClient::builder()
+        .with_fixture_dir(recording.path())
+        .build()
+        .unwrap();
+
+    let baseline = LIVE.with(Cell::get);
+    PEAK.with(|peak| peak.set(baseline));
+    let written = client
+        .export_playlist("PLlong", ExportFormat::Csv, io::sink())
+        .await
+        .unwrap();
+    let peak = PEAK.with(Cell::get) - baseline;
+
+    (written, peak)
+}
+
+#[tokio::test]
+async fn export_memory_stays_flat_across_pages() {
+    let (small, small_peak) = export_peak(10).await;
+    // Well past the 5,000 tracks playlists used to be capped at.
+    let (large, large_peak) = export_peak(120).await;
+    assert_eq!((small, large), (1_000, 12_000));
+    // Twelve times the tracks must not need noticeably more memory: only
+    // the page in flight is held.
+    assert!(
+        large_peak < small_peak + small_peak / 2,
+        "peak grew from {small_peak} to {large_peak} bytes"
+    );
+}